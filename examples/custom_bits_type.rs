@@ -50,9 +50,9 @@ impl BitXor for CustomBits {
 
     fn bitxor(self, other: Self) -> Self {
         CustomBits([
-            self.0[0] & other.0[0],
-            self.0[1] & other.0[1],
-            self.0[2] & other.0[2],
+            self.0[0] ^ other.0[0],
+            self.0[1] ^ other.0[1],
+            self.0[2] ^ other.0[2],
         ])
     }
 }
@@ -6,7 +6,7 @@ use bitflags::bitflags;
 
 // Define a flags type outside of the `bitflags` macro as a newtype
 // It can accept custom derives for libaries `bitflags` doesn't support natively
-#[derive(zerocopy::AsBytes, zerocopy::FromBytes)]
+#[derive(zerocopy::IntoBytes, zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout)]
 #[repr(transparent)]
 pub struct ManualFlags(u32);
 
@@ -0,0 +1,260 @@
+/*!
+Sharing flags values between threads.
+
+An [`AtomicFlags`] stores a flags value in the atomic integer matching its bits type, like
+[`AtomicU32`](core::sync::atomic::AtomicU32) for a flags type with `u32` bits. It can be
+updated through a shared reference, so it can live in a `static`:
+
+```
+use core::sync::atomic::Ordering;
+
+use bitflags::{atomic::AtomicFlags, bitflags};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(atomic)]
+    struct Features: u32 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+static FEATURES: AtomicFlags<Features> = Features::A.into_atomic();
+
+FEATURES.insert(Features::B, Ordering::Relaxed);
+
+assert_eq!(Features::A | Features::B, FEATURES.load(Ordering::Relaxed));
+```
+
+[`AtomicFlags::new`] can't be `const`, because it's generic over the flags type. The `atomic` option
+generates a `const fn into_atomic` method on a flags type instead, like above. Without the option,
+[`AtomicFlags::from_atomic`] wraps an atomic integer that's already been created, like
+`AtomicFlags::from_atomic(AtomicU32::new(Features::A.bits()))`.
+
+This module requires the `atomic` feature, which needs Rust 1.61 or newer.
+Flags types with 128-bit bits types aren't supported, and neither are bits types
+without atomic operations on the target platform.
+*/
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{self, Ordering},
+};
+
+use crate::{Bits, Flags};
+
+/**
+A bits type that has a matching atomic integer type.
+*/
+pub trait AtomicBits: Bits {
+    /// The atomic integer type that stores these bits.
+    type Atomic: Send + Sync;
+
+    /// Create an atomic storing the given bits.
+    fn new_atomic(bits: Self) -> Self::Atomic;
+
+    /// Consume an atomic, returning the bits it stores.
+    fn into_bits(atomic: Self::Atomic) -> Self;
+
+    /// Load the bits stored in an atomic.
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    /// Store bits into an atomic.
+    fn store(atomic: &Self::Atomic, bits: Self, order: Ordering);
+
+    /// Store bits into an atomic, returning the previous bits.
+    fn swap(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self;
+
+    /// The bitwise or (`|`) of the bits stored in an atomic, returning the previous bits.
+    fn fetch_or(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self;
+
+    /// The bitwise and (`&`) of the bits stored in an atomic, returning the previous bits.
+    fn fetch_and(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self;
+
+    /// The bitwise exclusive-or (`^`) of the bits stored in an atomic, returning the previous bits.
+    fn fetch_xor(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self;
+}
+
+macro_rules! impl_atomic_bits {
+    ($($width:literal: $bits:ty => $atomic:ident,)*) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicBits for $bits {
+                type Atomic = atomic::$atomic;
+
+                fn new_atomic(bits: Self) -> Self::Atomic {
+                    atomic::$atomic::new(bits)
+                }
+
+                fn into_bits(atomic: Self::Atomic) -> Self {
+                    atomic.into_inner()
+                }
+
+                fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+
+                fn store(atomic: &Self::Atomic, bits: Self, order: Ordering) {
+                    atomic.store(bits, order)
+                }
+
+                fn swap(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self {
+                    atomic.swap(bits, order)
+                }
+
+                fn fetch_or(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self {
+                    atomic.fetch_or(bits, order)
+                }
+
+                fn fetch_and(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self {
+                    atomic.fetch_and(bits, order)
+                }
+
+                fn fetch_xor(atomic: &Self::Atomic, bits: Self, order: Ordering) -> Self {
+                    atomic.fetch_xor(bits, order)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_bits! {
+    "8": u8 => AtomicU8,
+    "8": i8 => AtomicI8,
+    "16": u16 => AtomicU16,
+    "16": i16 => AtomicI16,
+    "32": u32 => AtomicU32,
+    "32": i32 => AtomicI32,
+    "64": u64 => AtomicU64,
+    "64": i64 => AtomicI64,
+    "ptr": usize => AtomicUsize,
+    "ptr": isize => AtomicIsize,
+}
+
+/**
+A flags value that can be shared between threads.
+
+Each method takes an [`Ordering`] that's passed through to the underlying atomic integer.
+Methods that update the flags value return the value from before the update.
+*/
+pub struct AtomicFlags<B: Flags>
+where
+    B::Bits: AtomicBits,
+{
+    atomic: <B::Bits as AtomicBits>::Atomic,
+    _marker: PhantomData<fn() -> B>,
+}
+
+impl<B: Flags> AtomicFlags<B>
+where
+    B::Bits: AtomicBits,
+{
+    /**
+    Wrap an atomic integer that stores the bits of a flags value.
+
+    This function is `const`, so it can be used to initialize a `static`.
+    */
+    pub const fn from_atomic(atomic: <B::Bits as AtomicBits>::Atomic) -> Self {
+        AtomicFlags {
+            atomic,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create an atomic flags value.
+    pub fn new(flags: B) -> Self {
+        Self::from_atomic(<B::Bits as AtomicBits>::new_atomic(flags.bits()))
+    }
+
+    /// Consume the atomic flags value, returning the flags value it stores.
+    pub fn into_inner(self) -> B {
+        B::from_bits_retain(<B::Bits as AtomicBits>::into_bits(self.atomic))
+    }
+
+    /// Load the flags value.
+    pub fn load(&self, order: Ordering) -> B {
+        B::from_bits_retain(<B::Bits as AtomicBits>::load(&self.atomic, order))
+    }
+
+    /// Store a flags value.
+    pub fn store(&self, flags: B, order: Ordering) {
+        <B::Bits as AtomicBits>::store(&self.atomic, flags.bits(), order)
+    }
+
+    /// Store a flags value, returning the previous flags value.
+    pub fn swap(&self, flags: B, order: Ordering) -> B {
+        B::from_bits_retain(<B::Bits as AtomicBits>::swap(
+            &self.atomic,
+            flags.bits(),
+            order,
+        ))
+    }
+
+    /// The bitwise or (`|`) of the bits in the stored and given flags values.
+    pub fn insert(&self, flags: B, order: Ordering) -> B {
+        B::from_bits_retain(<B::Bits as AtomicBits>::fetch_or(
+            &self.atomic,
+            flags.bits(),
+            order,
+        ))
+    }
+
+    /// The intersection of the stored flags value with the complement of the given flags value (`&!`).
+    ///
+    /// Like [`Flags::remove`], this method won't truncate the given flags value.
+    pub fn remove(&self, flags: B, order: Ordering) -> B {
+        B::from_bits_retain(<B::Bits as AtomicBits>::fetch_and(
+            &self.atomic,
+            !flags.bits(),
+            order,
+        ))
+    }
+
+    /// The bitwise exclusive-or (`^`) of the bits in the stored and given flags values.
+    pub fn toggle(&self, flags: B, order: Ordering) -> B {
+        B::from_bits_retain(<B::Bits as AtomicBits>::fetch_xor(
+            &self.atomic,
+            flags.bits(),
+            order,
+        ))
+    }
+
+    /// Call [`AtomicFlags::insert`] when `value` is `true` or [`AtomicFlags::remove`] when `value` is `false`.
+    pub fn set(&self, flags: B, value: bool, order: Ordering) -> B {
+        if value {
+            self.insert(flags, order)
+        } else {
+            self.remove(flags, order)
+        }
+    }
+}
+
+impl<B: Flags> Default for AtomicFlags<B>
+where
+    B::Bits: AtomicBits,
+{
+    fn default() -> Self {
+        Self::new(B::empty())
+    }
+}
+
+impl<B: Flags> From<B> for AtomicFlags<B>
+where
+    B::Bits: AtomicBits,
+{
+    fn from(flags: B) -> Self {
+        Self::new(flags)
+    }
+}
+
+impl<B: Flags + fmt::Debug> fmt::Debug for AtomicFlags<B>
+where
+    B::Bits: AtomicBits,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AtomicFlags")
+            .field(&self.load(Ordering::Relaxed))
+            .finish()
+    }
+}
@@ -4,6 +4,8 @@
 //! Usually, when you call the `bitflags!` macro, only the `Flags` type would be visible. In this
 //! example, the `Field0`, `Iter`, and `IterRaw` types are also exposed so that you can explore
 //! their APIs. The `Field0` type can be accessed as `self.0` on an instance of `Flags`.
+//!
+//! The methods generated by the `inherent_methods` option are included, so the whole API is shown.
 
 __declare_public_bitflags! {
     /// This is the same `Flags` struct defined in the [crate level example](../index.html#example).
@@ -13,10 +15,12 @@ __declare_public_bitflags! {
 }
 
 __declare_internal_bitflags! {
+    options: [inherent_methods,],
     pub struct Field0: u32
 }
 
 __impl_internal_bitflags! {
+    options: [inherent_methods,],
     Field0: u32, Flags {
         // Field `A`.
         ///
@@ -35,18 +39,27 @@ __impl_internal_bitflags! {
 }
 
 __impl_public_bitflags_forward! {
+    options: [inherent_methods,],
     Flags: u32, Field0
 }
 
 __impl_public_bitflags_ops! {
+    options: [inherent_methods,],
     Flags
 }
 
 __impl_public_bitflags_iter! {
+    options: [inherent_methods,],
     Flags: u32, Flags
 }
 
+__impl_public_bitflags_io! {
+    options: [inherent_methods,],
+    Flags: u32
+}
+
 __impl_public_bitflags_consts! {
+    options: [],
     Flags: u32 {
         /// Field `A`.
         ///
@@ -74,6 +74,68 @@ pub(crate) mod __private {
 
     #[cfg(feature = "bytemuck")]
     pub use bytemuck;
+
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+
+    #[cfg(any(
+        feature = "schemars",
+        feature = "quickcheck",
+        feature = "proptest",
+        feature = "pyo3",
+        feature = "wasm-bindgen"
+    ))]
+    pub extern crate alloc;
+
+    #[cfg(feature = "zerocopy")]
+    pub use zerocopy;
+
+    /// The bits type of a flags type declared with the `zerocopy` feature.
+    #[cfg(feature = "zerocopy")]
+    pub trait ZerocopyBits {
+        type Bits;
+    }
+
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "bincode")]
+    pub use bincode;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+
+    #[cfg(feature = "rand")]
+    pub use rand;
+
+    #[cfg(feature = "clap")]
+    pub use clap;
+
+    #[cfg(feature = "postgres")]
+    pub use bytes;
+    #[cfg(feature = "postgres")]
+    pub use postgres_types;
+
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+
+    #[cfg(feature = "pyo3")]
+    pub use pyo3;
+
+    #[cfg(feature = "wasm-bindgen")]
+    pub use js_sys;
 }
 
 /// Implements traits from external libraries for the internal bitflags type.
@@ -118,6 +180,191 @@ macro_rules! __impl_external_bitflags {
                 )*
             }
         }
+
+        __impl_external_bitflags_schemars! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_rkyv! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_borsh! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_bincode! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_defmt! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_quickcheck! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_proptest! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_rand! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_clap! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_postgres! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_sqlx! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_diesel! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_pyo3! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        __impl_external_bitflags_wasm_bindgen! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+    };
+}
+
+/// Declare the internal bitflags type with any derives needed by external libraries.
+///
+/// Some libraries, like `zerocopy`, only support implementing their traits through derives,
+/// so they need to be applied where the internal bitflags type is declared.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "zerocopy")]
+macro_rules! __declare_external_bitflags_zerocopy {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $InternalBitFlags:ident($T:ty);
+    ) => {
+        // The `zerocopy` derives refer to `::zerocopy`, which the crate calling `bitflags!` might not
+        // depend on. They can be given another path, but only as a string, where `$crate` can't be
+        // used. Instead, the type is declared in a module that imports `zerocopy` from here, so the
+        // derives can find it through `self`.
+        //
+        // Code in that module can't name `$T` in the scope it was written in, so the type is generic
+        // over a marker that carries it. The marker is declared with the same attributes and
+        // visibility the type would have had, so the type's derives and visibility are unchanged.
+        $(#[$attr])*
+        #[doc(hidden)]
+        $vis struct InternalBitFlagsBits;
+
+        impl $crate::__private::ZerocopyBits for InternalBitFlagsBits {
+            type Bits = $T;
+        }
+
+        mod __bitflags_zerocopy {
+            use $crate::__private::zerocopy;
+
+            $(#[$attr])*
+            #[derive(
+                zerocopy::FromBytes,
+                zerocopy::IntoBytes,
+                zerocopy::Immutable,
+                zerocopy::KnownLayout
+            )]
+            #[zerocopy(crate = "self::zerocopy")]
+            pub struct InternalBitFlags<B: $crate::__private::ZerocopyBits>(pub B::Bits);
+        }
+
+        $vis type $InternalBitFlags = __bitflags_zerocopy::InternalBitFlags<InternalBitFlagsBits>;
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "zerocopy"))]
+macro_rules! __declare_external_bitflags_zerocopy {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $InternalBitFlags:ident($T:ty);
+    ) => {
+        $(#[$attr])*
+        $vis struct $InternalBitFlags($T);
     };
 }
 
@@ -200,6 +447,10 @@ macro_rules! __impl_external_bitflags_arbitrary {
             ) -> $crate::__private::arbitrary::Result<Self> {
                 $crate::arbitrary::arbitrary::<$PublicBitFlags>(u).map(|flags| flags.0)
             }
+
+            fn size_hint(depth: usize) -> (usize, $crate::__private::core::option::Option<usize>) {
+                $crate::arbitrary::size_hint::<$PublicBitFlags>(depth)
+            }
         }
     };
 }
@@ -260,3 +511,954 @@ macro_rules! __impl_external_bitflags_bytemuck {
         }
     ) => {};
 }
+
+#[cfg(feature = "schemars")]
+pub mod schemars;
+
+/// Implement `JsonSchema` for the internal bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "schemars")]
+macro_rules! __impl_external_bitflags_schemars {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::schemars::JsonSchema for $InternalBitFlags {
+            // The public flags type is the one that should appear in definitions
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> $crate::__private::alloc::string::String {
+                $crate::__private::alloc::string::String::from($crate::__private::core::stringify!(
+                    $PublicBitFlags
+                ))
+            }
+
+            fn json_schema(
+                generator: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::schema::Schema {
+                $crate::schemars::json_schema::<$PublicBitFlags>(generator)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "schemars"))]
+macro_rules! __impl_external_bitflags_schemars {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+/// Implement `Archive`, `Serialize`, and `Deserialize` for the internal bitflags type.
+///
+/// The internal bitflags type is archived as `ArchivedInternalBitFlags`, which is a
+/// transparent wrapper around the archived bits.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "rkyv")]
+macro_rules! __impl_external_bitflags_rkyv {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        #[repr(transparent)]
+        pub struct ArchivedInternalBitFlags($crate::__private::rkyv::Archived<$T>);
+
+        impl ArchivedInternalBitFlags {
+            /// Get the underlying bits value.
+            #[inline]
+            pub fn bits(&self) -> $T {
+                let mut context = ();
+                let deserializer = $crate::__private::rkyv::rancor::Strategy::<
+                    (),
+                    $crate::__private::rkyv::rancor::Panic,
+                >::wrap(&mut context);
+
+                match $crate::__private::rkyv::Deserialize::<$T, _>::deserialize(&self.0, deserializer) {
+                    $crate::__private::core::result::Result::Ok(bits) => bits,
+                    $crate::__private::core::result::Result::Err(never) => match never {},
+                }
+            }
+
+            /// Get the archived flags value, retaining any unknown bits.
+            #[inline]
+            pub fn to_native(&self) -> $PublicBitFlags {
+                $PublicBitFlags::from_bits_retain(self.bits())
+            }
+        }
+
+        impl $crate::__private::core::fmt::Debug for ArchivedInternalBitFlags {
+            fn fmt(&self, f: &mut $crate::__private::core::fmt::Formatter<'_>) -> $crate::__private::core::fmt::Result {
+                $crate::__private::core::fmt::Debug::fmt(&$InternalBitFlags::from_bits_retain(self.bits()), f)
+            }
+        }
+
+        impl $crate::__private::core::cmp::PartialEq for ArchivedInternalBitFlags {
+            fn eq(&self, other: &Self) -> bool {
+                self.bits() == other.bits()
+            }
+        }
+
+        impl $crate::__private::core::cmp::Eq for ArchivedInternalBitFlags {}
+
+        // SAFETY: ArchivedInternalBitFlags is `repr(transparent)` over the archived bits,
+        // which are themselves portable
+        unsafe impl $crate::__private::rkyv::Portable for ArchivedInternalBitFlags where
+            $crate::__private::rkyv::Archived<$T>: $crate::__private::rkyv::Portable
+        {
+        }
+
+        // SAFETY: ArchivedInternalBitFlags is `repr(transparent)` over the archived bits,
+        // so it's valid whenever the archived bits are, and any bits are valid for a flags value
+        unsafe impl<C: $crate::__private::rkyv::rancor::Fallible + ?Sized>
+            $crate::__private::rkyv::bytecheck::CheckBytes<C> for ArchivedInternalBitFlags
+        where
+            $crate::__private::rkyv::Archived<$T>: $crate::__private::rkyv::bytecheck::CheckBytes<C>,
+        {
+            unsafe fn check_bytes(
+                value: *const Self,
+                context: &mut C,
+            ) -> $crate::__private::core::result::Result<(), C::Error> {
+                <$crate::__private::rkyv::Archived<$T> as $crate::__private::rkyv::bytecheck::CheckBytes<C>>::check_bytes(
+                    value.cast(),
+                    context,
+                )
+            }
+        }
+
+        impl $crate::__private::rkyv::Archive for $InternalBitFlags {
+            type Archived = ArchivedInternalBitFlags;
+            type Resolver = $crate::__private::rkyv::Resolver<$T>;
+
+            fn resolve(
+                &self,
+                resolver: Self::Resolver,
+                out: $crate::__private::rkyv::Place<Self::Archived>,
+            ) {
+                // SAFETY: ArchivedInternalBitFlags is `repr(transparent)` over the archived bits
+                let out = unsafe { out.cast_unchecked::<$crate::__private::rkyv::Archived<$T>>() };
+
+                $crate::__private::rkyv::Archive::resolve(&self.0, resolver, out)
+            }
+        }
+
+        impl<S: $crate::__private::rkyv::rancor::Fallible + ?Sized> $crate::__private::rkyv::Serialize<S>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::rkyv::Serialize<S>,
+        {
+            fn serialize(
+                &self,
+                serializer: &mut S,
+            ) -> $crate::__private::core::result::Result<Self::Resolver, S::Error> {
+                $crate::__private::rkyv::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<D: $crate::__private::rkyv::rancor::Fallible + ?Sized>
+            $crate::__private::rkyv::Deserialize<$InternalBitFlags, D> for ArchivedInternalBitFlags
+        where
+            $crate::__private::rkyv::Archived<$T>: $crate::__private::rkyv::Deserialize<$T, D>,
+        {
+            fn deserialize(
+                &self,
+                deserializer: &mut D,
+            ) -> $crate::__private::core::result::Result<$InternalBitFlags, D::Error> {
+                $crate::__private::rkyv::Deserialize::<$T, D>::deserialize(&self.0, deserializer)
+                    .map($InternalBitFlags::from_bits_retain)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "rkyv"))]
+macro_rules! __impl_external_bitflags_rkyv {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+/// Implement `BorshSerialize` and `BorshDeserialize` for the internal bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "borsh")]
+macro_rules! __impl_external_bitflags_borsh {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::borsh::BorshSerialize for $InternalBitFlags {
+            fn serialize<W: $crate::__private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__private::borsh::io::Result<()> {
+                $crate::__private::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::__private::borsh::BorshDeserialize for $InternalBitFlags {
+            fn deserialize_reader<R: $crate::__private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::__private::borsh::io::Result<Self> {
+                let bits =
+                    <$T as $crate::__private::borsh::BorshDeserialize>::deserialize_reader(reader)?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "borsh"))]
+macro_rules! __impl_external_bitflags_borsh {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "bincode")]
+mod bincode;
+
+/// Implement `Encode`, `Decode`, and `BorrowDecode` for the internal bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "bincode")]
+macro_rules! __impl_external_bitflags_bincode {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::bincode::Encode for $InternalBitFlags {
+            fn encode<E: $crate::__private::bincode::enc::Encoder>(
+                &self,
+                encoder: &mut E,
+            ) -> $crate::__private::core::result::Result<
+                (),
+                $crate::__private::bincode::error::EncodeError,
+            > {
+                $crate::__private::bincode::Encode::encode(&self.0, encoder)
+            }
+        }
+
+        impl<Context> $crate::__private::bincode::Decode<Context> for $InternalBitFlags
+        where
+            $T: $crate::__private::bincode::Decode<Context>,
+        {
+            fn decode<D: $crate::__private::bincode::de::Decoder<Context = Context>>(
+                decoder: &mut D,
+            ) -> $crate::__private::core::result::Result<
+                Self,
+                $crate::__private::bincode::error::DecodeError,
+            > {
+                let bits = <$T as $crate::__private::bincode::Decode<Context>>::decode(decoder)?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+        }
+
+        impl<'de, Context> $crate::__private::bincode::BorrowDecode<'de, Context>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::bincode::BorrowDecode<'de, Context>,
+        {
+            fn borrow_decode<
+                D: $crate::__private::bincode::de::BorrowDecoder<'de, Context = Context>,
+            >(
+                decoder: &mut D,
+            ) -> $crate::__private::core::result::Result<
+                Self,
+                $crate::__private::bincode::error::DecodeError,
+            > {
+                let bits =
+                    <$T as $crate::__private::bincode::BorrowDecode<'de, Context>>::borrow_decode(
+                        decoder,
+                    )?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "bincode"))]
+macro_rules! __impl_external_bitflags_bincode {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "defmt")]
+pub mod defmt;
+
+/// Implement `Format` for the internal bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "defmt")]
+macro_rules! __impl_external_bitflags_defmt {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::defmt::Format for $InternalBitFlags {
+            fn format(&self, f: $crate::__private::defmt::Formatter<'_>) {
+                $crate::defmt::format(&$PublicBitFlags::from_bits_retain(self.bits()), f)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "defmt"))]
+macro_rules! __impl_external_bitflags_defmt {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+
+/// Implement `Arbitrary` from `quickcheck` for the public bitflags type.
+///
+/// `quickcheck` doesn't have a derive, so unlike other libraries its trait is implemented
+/// directly on the public bitflags type, as long as it's `Clone`.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "quickcheck")]
+macro_rules! __impl_external_bitflags_quickcheck {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        // The higher-ranked bound stops this impl from failing to compile
+        // when the public bitflags type isn't `Clone`
+        impl $crate::__private::quickcheck::Arbitrary for $PublicBitFlags
+        where
+            for<'a> $PublicBitFlags: $crate::__private::core::clone::Clone,
+        {
+            fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                $crate::quickcheck::arbitrary(g)
+            }
+
+            fn shrink(
+                &self,
+            ) -> $crate::__private::alloc::boxed::Box<
+                dyn $crate::__private::core::iter::Iterator<Item = Self>,
+            > {
+                $crate::quickcheck::shrink(self)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "quickcheck"))]
+macro_rules! __impl_external_bitflags_quickcheck {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+/// Implement `Arbitrary` from `proptest` for the public bitflags type.
+///
+/// Like `quickcheck`, this trait is implemented directly on the public bitflags type,
+/// as long as it's `Debug`.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "proptest")]
+macro_rules! __impl_external_bitflags_proptest {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        // The higher-ranked bound stops this impl from failing to compile
+        // when the public bitflags type isn't `Debug`
+        impl $crate::__private::proptest::arbitrary::Arbitrary for $PublicBitFlags
+        where
+            for<'a> $PublicBitFlags: $crate::__private::core::fmt::Debug,
+        {
+            type Parameters = $crate::__private::core::option::Option<$PublicBitFlags>;
+            type Strategy = $crate::proptest::FlagsStrategy<$PublicBitFlags>;
+
+            fn arbitrary_with(mask: Self::Parameters) -> Self::Strategy {
+                $crate::proptest::flags(mask)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "proptest"))]
+macro_rules! __impl_external_bitflags_proptest {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "rand")]
+pub mod rand;
+
+/// Implement `Distribution` from `rand` for the public bitflags type.
+///
+/// Values are sampled from the `StandardUniform` distribution, so they can be generated
+/// with `rng.random()`.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "rand")]
+macro_rules! __impl_external_bitflags_rand {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        // The higher-ranked bound stops this impl from failing to compile
+        // when `rand` can't sample the bits type, like `usize`
+        impl $crate::__private::rand::distr::Distribution<$PublicBitFlags>
+            for $crate::__private::rand::distr::StandardUniform
+        where
+            for<'a> $crate::__private::rand::distr::StandardUniform:
+                $crate::__private::rand::distr::Distribution<$T>,
+        {
+            fn sample<R: $crate::__private::rand::Rng + ?Sized>(
+                &self,
+                rng: &mut R,
+            ) -> $PublicBitFlags {
+                $crate::rand::sample(rng)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "rand"))]
+macro_rules! __impl_external_bitflags_rand {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
+/// Implement `ValueParserFactory` from `clap` for the public bitflags type.
+///
+/// This lets flags types be used with `clap::value_parser!` and `#[arg]` in `clap`'s derive.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "clap")]
+macro_rules! __impl_external_bitflags_clap {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::clap::builder::ValueParserFactory for $PublicBitFlags {
+            type Parser = $crate::clap::FlagsValueParser<$PublicBitFlags>;
+
+            fn value_parser() -> Self::Parser {
+                $crate::clap::FlagsValueParser::new()
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "clap"))]
+macro_rules! __impl_external_bitflags_clap {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+/// Implement `ToSql` and `FromSql` from `postgres-types` for the internal bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "postgres")]
+macro_rules! __impl_external_bitflags_postgres {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        // The higher-ranked bounds stop these impls from failing to compile
+        // when the bits type can't be stored in Postgres, like `u128`
+        impl $crate::__private::postgres_types::ToSql for $InternalBitFlags
+        where
+            for<'a> $T: $crate::postgres::PostgresBits,
+        {
+            fn to_sql(
+                &self,
+                ty: &$crate::__private::postgres_types::Type,
+                out: &mut $crate::__private::bytes::BytesMut,
+            ) -> $crate::__private::core::result::Result<
+                $crate::__private::postgres_types::IsNull,
+                $crate::postgres::BoxError,
+            > {
+                $crate::postgres::to_sql(self.0, ty, out)
+            }
+
+            fn accepts(ty: &$crate::__private::postgres_types::Type) -> bool {
+                $crate::postgres::accepts::<$T>(ty)
+            }
+
+            fn to_sql_checked(
+                &self,
+                ty: &$crate::__private::postgres_types::Type,
+                out: &mut $crate::__private::bytes::BytesMut,
+            ) -> $crate::__private::core::result::Result<
+                $crate::__private::postgres_types::IsNull,
+                $crate::postgres::BoxError,
+            > {
+                $crate::postgres::to_sql_checked(self.0, ty, out)
+            }
+        }
+
+        impl<'a> $crate::__private::postgres_types::FromSql<'a> for $InternalBitFlags
+        where
+            for<'b> $T: $crate::postgres::PostgresBits,
+        {
+            fn from_sql(
+                ty: &$crate::__private::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> $crate::__private::core::result::Result<Self, $crate::postgres::BoxError> {
+                let bits = $crate::postgres::from_sql::<$T>(ty, raw)?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+
+            fn accepts(ty: &$crate::__private::postgres_types::Type) -> bool {
+                $crate::postgres::accepts::<$T>(ty)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "postgres"))]
+macro_rules! __impl_external_bitflags_postgres {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+/// Implement `Type`, `Encode`, and `Decode` from `sqlx` for the internal bitflags type.
+///
+/// These are implemented for any database that supports the bits type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "sqlx")]
+macro_rules! __impl_external_bitflags_sqlx {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl<DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<DB>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$T as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$T as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Encode<'q, DB>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> $crate::__private::core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                <$T as $crate::__private::sqlx::Encode<'q, DB>>::encode_by_ref(&self.0, buf)
+            }
+
+            fn produces(&self) -> $crate::__private::core::option::Option<DB::TypeInfo> {
+                <$T as $crate::__private::sqlx::Encode<'q, DB>>::produces(&self.0)
+            }
+
+            fn size_hint(&self) -> usize {
+                <$T as $crate::__private::sqlx::Encode<'q, DB>>::size_hint(&self.0)
+            }
+        }
+
+        impl<'r, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Decode<'r, DB>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as $crate::__private::sqlx::Database>::ValueRef<'r>,
+            ) -> $crate::__private::core::result::Result<
+                Self,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                let bits = <$T as $crate::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "sqlx"))]
+macro_rules! __impl_external_bitflags_sqlx {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+/// Implement `ToSql` and `FromSql` from `diesel` for the public bitflags type.
+///
+/// `diesel` doesn't have a way to derive these traits for newtypes, so they're implemented
+/// directly on the public bitflags type for each integer SQL type the bits type supports.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "diesel")]
+macro_rules! __impl_external_bitflags_diesel {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        __impl_external_bitflags_diesel_sql_type!($PublicBitFlags: $T, TinyInt);
+        __impl_external_bitflags_diesel_sql_type!($PublicBitFlags: $T, SmallInt);
+        __impl_external_bitflags_diesel_sql_type!($PublicBitFlags: $T, Integer);
+        __impl_external_bitflags_diesel_sql_type!($PublicBitFlags: $T, BigInt);
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "diesel")]
+macro_rules! __impl_external_bitflags_diesel_sql_type {
+    ($PublicBitFlags:ident: $T:ty, $SqlType:ident) => {
+        // The higher-ranked bound stops this impl from failing to compile
+        // when the public bitflags type isn't `Debug`
+        impl<DB>
+            $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::$SqlType,
+                DB,
+            > for $PublicBitFlags
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            $T: $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::$SqlType,
+                DB,
+            >,
+            for<'a> $PublicBitFlags: $crate::__private::core::fmt::Debug,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                <$T as $crate::__private::diesel::serialize::ToSql<
+                    $crate::__private::diesel::sql_types::$SqlType,
+                    DB,
+                >>::to_sql(&self.0 .0, out)
+            }
+        }
+
+        impl<DB>
+            $crate::__private::diesel::deserialize::FromSql<
+                $crate::__private::diesel::sql_types::$SqlType,
+                DB,
+            > for $PublicBitFlags
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            $T: $crate::__private::diesel::deserialize::FromSql<
+                $crate::__private::diesel::sql_types::$SqlType,
+                DB,
+            >,
+        {
+            fn from_sql(
+                bytes: <DB as $crate::__private::diesel::backend::Backend>::RawValue<'_>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                let bits = <$T as $crate::__private::diesel::deserialize::FromSql<
+                    $crate::__private::diesel::sql_types::$SqlType,
+                    DB,
+                >>::from_sql(bytes)?;
+
+                $crate::__private::core::result::Result::Ok($PublicBitFlags::from_bits_retain(bits))
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "diesel"))]
+macro_rules! __impl_external_bitflags_diesel {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+
+/// Implement `FromPyObject` and `IntoPyObject` from `pyo3` for the public bitflags type.
+///
+/// `pyo3`'s derives can't be used on types declared in `macro_rules!`, so these traits are
+/// implemented directly on the public bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "pyo3")]
+macro_rules! __impl_external_bitflags_pyo3 {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl<'py> $crate::__private::pyo3::FromPyObject<'py> for $PublicBitFlags {
+            fn extract_bound(
+                ob: &$crate::__private::pyo3::Bound<'py, $crate::__private::pyo3::PyAny>,
+            ) -> $crate::__private::pyo3::PyResult<Self> {
+                $crate::pyo3::extract(ob)
+            }
+        }
+
+        impl<'py> $crate::__private::pyo3::IntoPyObject<'py> for $PublicBitFlags {
+            type Target = <$T as $crate::__private::pyo3::IntoPyObject<'py>>::Target;
+            type Output = <$T as $crate::__private::pyo3::IntoPyObject<'py>>::Output;
+            type Error = <$T as $crate::__private::pyo3::IntoPyObject<'py>>::Error;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> $crate::__private::core::result::Result<Self::Output, Self::Error> {
+                <$T as $crate::__private::pyo3::IntoPyObject<'py>>::into_pyobject(self.bits(), py)
+            }
+        }
+
+        impl<'a, 'py> $crate::__private::pyo3::IntoPyObject<'py> for &'a $PublicBitFlags {
+            type Target = <$T as $crate::__private::pyo3::IntoPyObject<'py>>::Target;
+            type Output = <$T as $crate::__private::pyo3::IntoPyObject<'py>>::Output;
+            type Error = <$T as $crate::__private::pyo3::IntoPyObject<'py>>::Error;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> $crate::__private::core::result::Result<Self::Output, Self::Error> {
+                <$T as $crate::__private::pyo3::IntoPyObject<'py>>::into_pyobject(self.bits(), py)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "pyo3"))]
+macro_rules! __impl_external_bitflags_pyo3 {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm_bindgen;
+
+/// Implement conversions to and from `JsValue` from `wasm-bindgen` for the public bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "wasm-bindgen")]
+macro_rules! __impl_external_bitflags_wasm_bindgen {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::core::convert::From<$PublicBitFlags>
+            for $crate::__private::js_sys::wasm_bindgen::JsValue
+        {
+            fn from(flags: $PublicBitFlags) -> Self {
+                $crate::wasm_bindgen::to_js(&flags)
+            }
+        }
+
+        impl
+            $crate::__private::core::convert::TryFrom<
+                $crate::__private::js_sys::wasm_bindgen::JsValue,
+            > for $PublicBitFlags
+        {
+            type Error = $crate::__private::js_sys::wasm_bindgen::JsValue;
+
+            fn try_from(
+                value: $crate::__private::js_sys::wasm_bindgen::JsValue,
+            ) -> $crate::__private::core::result::Result<Self, Self::Error> {
+                $crate::wasm_bindgen::from_js(&value)
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "wasm-bindgen"))]
+macro_rules! __impl_external_bitflags_wasm_bindgen {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
@@ -4,20 +4,48 @@ use crate::Flags;
 
 /**
 Generate some arbitrary flags value with only known bits set.
+
+The underlying bits are drawn from the input and then truncated, so this never fails
+because of unknown bits.
 */
 pub fn arbitrary<'a, B: Flags>(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<B>
 where
     B::Bits: arbitrary::Arbitrary<'a>,
 {
-    B::from_bits(u.arbitrary()?).ok_or_else(|| arbitrary::Error::IncorrectFormat)
+    Ok(B::from_bits_truncate(u.arbitrary()?))
+}
+
+/**
+Generate some arbitrary flags value, retaining any unknown bits.
+
+This can be used with `#[arbitrary(with = bitflags::arbitrary::arbitrary_retain)]` on a field
+of a flags type to also generate values with bits that don't correspond to any defined flag.
+*/
+pub fn arbitrary_retain<'a, B: Flags>(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<B>
+where
+    B::Bits: arbitrary::Arbitrary<'a>,
+{
+    Ok(B::from_bits_retain(u.arbitrary()?))
+}
+
+/**
+Get the size hint for generating an arbitrary flags value.
+
+This is the same as the size hint of the underlying bits type.
+*/
+pub fn size_hint<'a, B: Flags>(depth: usize) -> (usize, Option<usize>)
+where
+    B::Bits: arbitrary::Arbitrary<'a>,
+{
+    <B::Bits as arbitrary::Arbitrary<'a>>::size_hint(depth)
 }
 
 #[cfg(test)]
 mod tests {
-    use arbitrary::Arbitrary;
+    use arbitrary::{Arbitrary, Unstructured};
 
     bitflags! {
-        #[derive(Arbitrary)]
+        #[derive(Arbitrary, Debug, PartialEq, Eq)]
         struct Color: u32 {
             const RED = 0x1;
             const GREEN = 0x2;
@@ -25,9 +53,51 @@ mod tests {
         }
     }
 
+    #[derive(Arbitrary, Debug, PartialEq, Eq)]
+    struct Retained {
+        #[arbitrary(with = crate::arbitrary::arbitrary_retain)]
+        color: Color,
+    }
+
     #[test]
     fn test_arbitrary() {
-        let mut unstructured = arbitrary::Unstructured::new(&[0_u8; 256]);
+        let mut unstructured = Unstructured::new(&[0_u8; 256]);
         let _color = Color::arbitrary(&mut unstructured);
     }
+
+    #[test]
+    fn test_arbitrary_truncate() {
+        let mut unstructured = Unstructured::new(&[0xff; 4]);
+        assert_eq!(Color::all(), Color::arbitrary(&mut unstructured).unwrap());
+
+        let mut unstructured = Unstructured::new(&[0x05, 0x00, 0x00, 0x05]);
+        assert_eq!(
+            Color::RED | Color::BLUE,
+            Color::arbitrary(&mut unstructured).unwrap()
+        );
+
+        let mut unstructured = Unstructured::new(&[0x00; 4]);
+        assert_eq!(Color::empty(), Color::arbitrary(&mut unstructured).unwrap());
+    }
+
+    #[test]
+    fn test_arbitrary_retain() {
+        let mut unstructured = Unstructured::new(&[0xff; 4]);
+        assert_eq!(
+            Color::from_bits_retain(u32::MAX),
+            Retained::arbitrary(&mut unstructured).unwrap().color
+        );
+
+        let mut unstructured = Unstructured::new(&[0x05, 0x00, 0x00, 0x05]);
+        assert_eq!(
+            Color::from_bits_retain(0x05000005),
+            Retained::arbitrary(&mut unstructured).unwrap().color
+        );
+    }
+
+    #[test]
+    fn test_size_hint() {
+        assert_eq!(<u32 as Arbitrary>::size_hint(0), Color::size_hint(0));
+        assert_eq!((4, Some(4)), Color::size_hint(0));
+    }
 }
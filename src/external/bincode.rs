@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use bincode::{config, BorrowDecode, Decode, Encode};
+
+    bitflags! {
+        // NOTE: `bincode`'s derives don't support types without a visibility
+        #[derive(Encode, Decode, Debug, PartialEq, Eq)]
+        pub struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    #[derive(Encode, BorrowDecode, Debug, PartialEq, Eq)]
+    struct Pixel<'a> {
+        name: &'a str,
+        color: Color,
+    }
+
+    #[test]
+    fn test_bincode_standard() {
+        let color = Color::RED | Color::BLUE | Color::from_bits_retain(0x100);
+
+        let bytes = bincode::encode_to_vec(&color, config::standard()).unwrap();
+        assert_eq!(
+            bincode::encode_to_vec(color.bits(), config::standard()).unwrap(),
+            bytes
+        );
+
+        let (decoded, len) =
+            bincode::decode_from_slice::<Color, _>(&bytes, config::standard()).unwrap();
+        assert_eq!(color, decoded);
+        assert_eq!(bytes.len(), len);
+    }
+
+    #[test]
+    fn test_bincode_fixed_int() {
+        let config = config::standard().with_fixed_int_encoding();
+        let color = Color::RED | Color::BLUE | Color::from_bits_retain(0x100);
+
+        let bytes = bincode::encode_to_vec(&color, config).unwrap();
+        assert_eq!(vec![0x05, 0x01, 0x00, 0x00], bytes);
+
+        let (decoded, _) = bincode::decode_from_slice::<Color, _>(&bytes, config).unwrap();
+        assert_eq!(color, decoded);
+    }
+
+    #[test]
+    fn test_bincode_borrow_decode() {
+        let pixel = Pixel {
+            name: "pixel",
+            color: Color::GREEN | Color::from_bits_retain(1 << 31),
+        };
+
+        let bytes = bincode::encode_to_vec(&pixel, config::standard()).unwrap();
+
+        let (decoded, _) =
+            bincode::borrow_decode_from_slice::<Pixel, _>(&bytes, config::standard()).unwrap();
+        assert_eq!(pixel, decoded);
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    macro_rules! roundtrip {
+        ($($name:ident: $T:ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    bitflags! {
+                        #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+                        struct Flags: $T {
+                            const A = 1;
+                            const B = 1 << 1;
+                        }
+                    }
+
+                    for flags in [
+                        Flags::empty(),
+                        Flags::A | Flags::B,
+                        Flags::A | Flags::from_bits_retain(1 << 4),
+                        Flags::from_bits_retain(<$T>::MAX),
+                    ] {
+                        let bytes = borsh::to_vec(&flags).unwrap();
+
+                        assert_eq!(borsh::to_vec(&flags.bits()).unwrap(), bytes);
+                        assert_eq!(flags, borsh::from_slice::<Flags>(&bytes).unwrap());
+                    }
+                }
+            )*
+        };
+    }
+
+    roundtrip! {
+        test_u8: u8,
+        test_u16: u16,
+        test_u32: u32,
+        test_u64: u64,
+        test_u128: u128,
+        test_i8: i8,
+        test_i16: i16,
+        test_i32: i32,
+        test_i64: i64,
+        test_i128: i128,
+    }
+
+    #[test]
+    fn test_borsh_wire_format() {
+        bitflags! {
+            #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+            struct Color: u32 {
+                const RED = 0x1;
+                const GREEN = 0x2;
+                const BLUE = 0x4;
+            }
+        }
+
+        let color = Color::RED | Color::BLUE | Color::from_bits_retain(0x100);
+
+        assert_eq!(vec![0x05, 0x01, 0x00, 0x00], borsh::to_vec(&color).unwrap());
+        assert_eq!(
+            color,
+            borsh::from_slice::<Color>(&[0x05, 0x01, 0x00, 0x00]).unwrap()
+        );
+        assert!(borsh::from_slice::<Color>(&[0x05, 0x01]).is_err());
+    }
+}
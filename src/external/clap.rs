@@ -0,0 +1,301 @@
+//! Parsing command-line arguments into flags types using `clap`.
+
+extern crate std;
+
+use core::marker::PhantomData;
+use std::{borrow::ToOwned, boxed::Box, ffi::OsStr, string::ToString, vec::Vec};
+
+use crate::{
+    parser::{self, ParseHex},
+    Flags,
+};
+use clap::{
+    builder::{PossibleValue, TypedValueParser},
+    error::{ContextKind, ContextValue, ErrorKind},
+    Arg, Command, Error,
+};
+
+/**
+A `clap` value parser for flags types.
+
+Flags are separated by either `,` or `|`, like `A,B` or `A | B`, and may also be hex numbers
+like in [`parser::from_str`]. An empty value parses as an empty set of flags.
+
+The names of defined flags are reported as possible values, so they show up in `--help`
+and shell completions.
+
+```
+# use clap::{Arg, Command};
+# bitflags::bitflags! {
+#     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#     struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#         const C = 1 << 2;
+#     }
+# }
+let cmd = Command::new("app").arg(
+    Arg::new("features")
+        .long("features")
+        .value_parser(bitflags::clap::FlagsValueParser::<Flags>::new().ignore_case(true)),
+);
+
+let matches = cmd.try_get_matches_from(["app", "--features", "a,C"])?;
+
+assert_eq!(Some(&(Flags::A | Flags::C)), matches.get_one::<Flags>("features"));
+# Ok::<(), clap::Error>(())
+```
+*/
+pub struct FlagsValueParser<B> {
+    ignore_case: bool,
+    _marker: PhantomData<fn() -> B>,
+}
+
+impl<B> FlagsValueParser<B> {
+    /// Create a value parser that matches flag names exactly.
+    pub fn new() -> Self {
+        FlagsValueParser {
+            ignore_case: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /**
+    Whether to match flag names without regard to ASCII case.
+
+    Names are matched like [`parser::from_str_ignore_case`]. Case is also ignored
+    if the argument itself has `Arg::ignore_case` set.
+    */
+    pub fn ignore_case(mut self, yes: bool) -> Self {
+        self.ignore_case = yes;
+        self
+    }
+}
+
+impl<B> Default for FlagsValueParser<B> {
+    fn default() -> Self {
+        FlagsValueParser::new()
+    }
+}
+
+impl<B> Clone for FlagsValueParser<B> {
+    fn clone(&self) -> Self {
+        FlagsValueParser {
+            ignore_case: self.ignore_case,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<B> core::fmt::Debug for FlagsValueParser<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlagsValueParser")
+            .field("ignore_case", &self.ignore_case)
+            .finish()
+    }
+}
+
+impl<B> TypedValueParser for FlagsValueParser<B>
+where
+    B: Flags + Clone + Send + Sync + 'static,
+    B::Bits: ParseHex,
+{
+    type Value = B;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<B, Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+
+        let ignore_case = self.ignore_case || arg.map_or(false, |arg| arg.is_ignore_case_set());
+
+        let mut parsed_flags = B::empty();
+
+        // If the input is empty then return an empty set of flags
+        if value.trim().is_empty() {
+            return Ok(parsed_flags);
+        }
+
+        for flag in value.split([',', '|']) {
+            let flag = flag.trim();
+
+            // Each flag is parsed on its own, so any separators have already been removed
+            let parsed_flag = if flag.is_empty() {
+                Err(parser::ParseError::empty_flag())
+            } else if ignore_case {
+                parser::from_str_ignore_case::<B>(flag)
+            } else {
+                parser::from_str::<B>(flag)
+            };
+
+            match parsed_flag {
+                Ok(parsed_flag) => parsed_flags.insert(parsed_flag),
+                Err(_) if flag.is_empty() => return Err(invalid_value::<B>(cmd, arg, value)),
+                Err(_) => return Err(invalid_value::<B>(cmd, arg, flag)),
+            }
+        }
+
+        Ok(parsed_flags)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            B::FLAGS
+                .iter()
+                .filter(|flag| !flag.is_unnamed())
+                .map(|flag| PossibleValue::new(flag.name())),
+        ))
+    }
+}
+
+fn invalid_value<B: Flags>(cmd: &Command, arg: Option<&Arg>, value: &str) -> Error {
+    let mut err = Error::new(ErrorKind::InvalidValue).with_cmd(cmd);
+
+    let arg = arg.map_or_else(|| "...".to_owned(), |arg| arg.to_string());
+    let valid = B::FLAGS
+        .iter()
+        .filter(|flag| !flag.is_unnamed())
+        .map(|flag| flag.name().to_owned())
+        .collect::<Vec<_>>();
+
+    err.insert(ContextKind::InvalidArg, ContextValue::String(arg));
+    err.insert(
+        ContextKind::InvalidValue,
+        ContextValue::String(value.to_owned()),
+    );
+    err.insert(ContextKind::ValidValue, ContextValue::Strings(valid));
+
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{error::ErrorKind, Arg, ArgAction, Command};
+
+    use super::FlagsValueParser;
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Features: u8 {
+            const FAST = 1;
+            const SAFE = 1 << 1;
+            const SMALL = 1 << 2;
+        }
+    }
+
+    fn cmd(parser: FlagsValueParser<Features>) -> Command {
+        Command::new("app").arg(Arg::new("features").long("features").value_parser(parser))
+    }
+
+    fn parse(parser: FlagsValueParser<Features>, value: &str) -> Result<Features, clap::Error> {
+        let matches = cmd(parser).try_get_matches_from(["app", "--features", value])?;
+
+        Ok(*matches.get_one::<Features>("features").unwrap())
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            Features::FAST,
+            parse(FlagsValueParser::new(), "FAST").unwrap()
+        );
+        assert_eq!(
+            Features::FAST | Features::SMALL,
+            parse(FlagsValueParser::new(), "FAST,SMALL").unwrap()
+        );
+        assert_eq!(
+            Features::FAST | Features::SMALL,
+            parse(FlagsValueParser::new(), "FAST | SMALL").unwrap()
+        );
+        assert_eq!(
+            Features::all(),
+            parse(FlagsValueParser::new(), " FAST, SAFE|SMALL ").unwrap()
+        );
+        assert_eq!(
+            Features::SAFE | Features::from_bits_retain(0x80),
+            parse(FlagsValueParser::new(), "SAFE,0x80").unwrap()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            Features::empty(),
+            parse(FlagsValueParser::new(), "").unwrap()
+        );
+        assert_eq!(
+            Features::empty(),
+            parse(FlagsValueParser::new(), " ").unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for value in ["FAST,SLOW", "fast", "FAST,,SAFE", "FAST,", "0xg"] {
+            let err = parse(FlagsValueParser::new(), value).unwrap_err();
+
+            assert_eq!(ErrorKind::InvalidValue, err.kind(), "{}", value);
+        }
+
+        let err = parse(FlagsValueParser::new(), "FAST,SLOW")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("'SLOW'"), "{}", err);
+        assert!(err.contains("FAST, SAFE, SMALL"), "{}", err);
+    }
+
+    #[test]
+    fn ignore_case() {
+        assert_eq!(
+            Features::FAST | Features::SAFE,
+            parse(FlagsValueParser::new().ignore_case(true), "fast,Safe").unwrap()
+        );
+
+        let cmd = Command::new("app").arg(
+            Arg::new("features")
+                .long("features")
+                .ignore_case(true)
+                .value_parser(FlagsValueParser::<Features>::new()),
+        );
+
+        let matches = cmd
+            .try_get_matches_from(["app", "--features", "small"])
+            .unwrap();
+
+        assert_eq!(
+            Some(&Features::SMALL),
+            matches.get_one::<Features>("features")
+        );
+    }
+
+    #[test]
+    fn value_parser_factory() {
+        let cmd = Command::new("app").arg(
+            Arg::new("features")
+                .long("features")
+                .action(ArgAction::Set)
+                .value_parser(clap::value_parser!(Features)),
+        );
+
+        let matches = cmd
+            .try_get_matches_from(["app", "--features", "SAFE|SMALL"])
+            .unwrap();
+
+        assert_eq!(
+            Some(&(Features::SAFE | Features::SMALL)),
+            matches.get_one::<Features>("features")
+        );
+    }
+
+    #[test]
+    fn possible_values() {
+        let help = cmd(FlagsValueParser::new()).render_help().to_string();
+
+        assert!(
+            help.contains("[possible values: FAST, SAFE, SMALL]"),
+            "{}",
+            help
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Specialized logging for flags types using `defmt`.
+
+use crate::{Bits, Flags};
+
+/**
+Format a set of flags for logging with `defmt`.
+
+Flags are written as text like `A | B | 0xf0`, with any unknown bits written as a hex number.
+An empty set of flags is written as `(empty)`.
+*/
+pub fn format<B: Flags>(flags: &B, f: defmt::Formatter<'_>)
+where
+    B::Bits: defmt::Format,
+{
+    if flags.bits() == B::Bits::EMPTY {
+        defmt::write!(f, "(empty)");
+        return;
+    }
+
+    // Iterate over known flag values
+    let mut first = true;
+    let mut iter = flags.iter_names();
+    for (name, _) in &mut iter {
+        if !first {
+            defmt::write!(f, " | ");
+        }
+
+        first = false;
+        defmt::write!(f, "{=str}", name);
+    }
+
+    // Append any extra bits that correspond to flags to the end of the format
+    let remaining = iter.remaining().bits();
+    if remaining != B::Bits::EMPTY {
+        if !first {
+            defmt::write!(f, " | ");
+        }
+
+        defmt::write!(f, "{:#x}", remaining);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // `defmt` needs a global logger to actually write anything,
+    // so these tests only check that flags types can derive `Format`
+
+    bitflags! {
+        #[derive(defmt::Format)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    bitflags! {
+        #[derive(defmt::Format)]
+        struct Wide: i128 {
+            const A = 1;
+        }
+    }
+
+    #[derive(defmt::Format)]
+    struct Pixel {
+        x: u16,
+        color: Color,
+    }
+
+    #[test]
+    fn test_defmt() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<Color>();
+        assert_format::<Wide>();
+        assert_format::<Pixel>();
+    }
+}
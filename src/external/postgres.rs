@@ -0,0 +1,238 @@
+//! Specialized storage for flags types using `postgres-types`.
+
+extern crate std;
+
+use std::{boxed::Box, error::Error};
+
+use crate::Bits;
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+/// The error type used by `ToSql` and `FromSql`.
+pub type BoxError = Box<dyn Error + Sync + Send>;
+
+/**
+A bits type that can be stored in Postgres.
+
+Bits are stored as the Postgres integer type with the same width:
+
+| Bits          | Postgres type |
+| ------------- | ------------- |
+| `i8`, `u8`    | `"char"`      |
+| `i16`, `u16`  | `INT2`        |
+| `i32`, `u32`  | `INT4`        |
+| `i64`, `u64`  | `INT8`        |
+
+Postgres doesn't have unsigned integers, so unsigned bits are stored by reinterpreting
+them as the signed integer of the same width. No bits are lost, but a value with the top
+bit set, like `0x8000_0000u32`, is stored as a negative number, like `-2147483648`.
+Keep this in mind when comparing flags columns numerically in SQL.
+Reading the value back into the same flags type restores the original bits.
+
+Other bits types, like `u128` and `usize`, can't be stored in Postgres.
+*/
+pub trait PostgresBits: Bits {
+    /// The signed integer type the bits are stored as.
+    type Repr: ToSql + for<'a> FromSql<'a>;
+
+    /// Convert the bits into the stored integer, reinterpreting unsigned bits as signed.
+    fn to_repr(self) -> Self::Repr;
+
+    /// Convert the stored integer back into bits, reinterpreting signed bits as unsigned.
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+macro_rules! impl_postgres_bits {
+    ($($bits:ty => $repr:ty,)*) => {
+        $(
+            impl PostgresBits for $bits {
+                type Repr = $repr;
+
+                fn to_repr(self) -> $repr {
+                    self as $repr
+                }
+
+                fn from_repr(repr: $repr) -> Self {
+                    repr as $bits
+                }
+            }
+        )*
+    };
+}
+
+impl_postgres_bits! {
+    i8 => i8,
+    u8 => i8,
+    i16 => i16,
+    u16 => i16,
+    i32 => i32,
+    u32 => i32,
+    i64 => i64,
+    u64 => i64,
+}
+
+/**
+Write the bits of a flags value to Postgres.
+
+Unknown bits are written along with known ones.
+*/
+pub fn to_sql<T: PostgresBits>(bits: T, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+    bits.to_repr().to_sql(ty, out)
+}
+
+/**
+Write the bits of a flags value to Postgres, checking the Postgres type is accepted first.
+*/
+pub fn to_sql_checked<T: PostgresBits>(
+    bits: T,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, BoxError> {
+    bits.to_repr().to_sql_checked(ty, out)
+}
+
+/**
+Read the bits of a flags value from Postgres.
+
+Unknown bits are retained.
+*/
+pub fn from_sql<T: PostgresBits>(ty: &Type, raw: &[u8]) -> Result<T, BoxError> {
+    let repr = <T::Repr as FromSql>::from_sql(ty, raw)?;
+
+    Ok(T::from_repr(repr))
+}
+
+/**
+Whether the bits of a flags value can be stored as the given Postgres type.
+*/
+pub fn accepts<T: PostgresBits>(ty: &Type) -> bool {
+    <T::Repr as ToSql>::accepts(ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, ToSql, FromSql)]
+        #[postgres(transparent)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+            const ALPHA = 0x8000_0000;
+        }
+    }
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, ToSql, FromSql)]
+        #[postgres(transparent)]
+        struct Signed: i16 {
+            const A = 0x1;
+            const SIGN = i16::MIN;
+        }
+    }
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, ToSql, FromSql)]
+        #[postgres(transparent)]
+        struct Byte: u8 {
+            const A = 0x1;
+            const B = 0x80;
+        }
+    }
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, ToSql, FromSql)]
+        #[postgres(transparent)]
+        struct Wide: u64 {
+            const A = 0x1;
+            const B = 1 << 63;
+        }
+    }
+
+    fn roundtrip<T>(ty: &Type, value: &T) -> (Vec<u8>, T)
+    where
+        T: ToSql + for<'a> FromSql<'a>,
+    {
+        let mut out = BytesMut::new();
+        assert!(matches!(
+            value.to_sql_checked(ty, &mut out).unwrap(),
+            IsNull::No
+        ));
+
+        let decoded = T::from_sql(ty, &out).unwrap();
+
+        (out.to_vec(), decoded)
+    }
+
+    #[test]
+    fn test_accepts() {
+        assert!(<Color as ToSql>::accepts(&Type::INT4));
+        assert!(<Color as FromSql>::accepts(&Type::INT4));
+        assert!(!<Color as ToSql>::accepts(&Type::INT8));
+        assert!(!<Color as ToSql>::accepts(&Type::OID));
+
+        assert!(<Signed as ToSql>::accepts(&Type::INT2));
+        assert!(<Byte as ToSql>::accepts(&Type::CHAR));
+        assert!(<Wide as ToSql>::accepts(&Type::INT8));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        assert_eq!(
+            (vec![0, 0, 0, 0], Color::empty()),
+            roundtrip(&Type::INT4, &Color::empty())
+        );
+        assert_eq!(
+            (vec![0, 0, 0, 5], Color::RED | Color::BLUE),
+            roundtrip(&Type::INT4, &(Color::RED | Color::BLUE))
+        );
+        assert_eq!(
+            (vec![0x80, 0, 0, 7], Color::all()),
+            roundtrip(&Type::INT4, &Color::all())
+        );
+        assert_eq!(
+            (vec![0, 0, 0, 0xf1], Color::from_bits_retain(0xf1)),
+            roundtrip(&Type::INT4, &Color::from_bits_retain(0xf1))
+        );
+
+        assert_eq!(
+            (vec![0x80, 1], Signed::all()),
+            roundtrip(&Type::INT2, &Signed::all())
+        );
+        assert_eq!(
+            (vec![0x81], Byte::all()),
+            roundtrip(&Type::CHAR, &Byte::all())
+        );
+        assert_eq!(
+            (vec![0x80, 0, 0, 0, 0, 0, 0, 1], Wide::all()),
+            roundtrip(&Type::INT8, &Wide::all())
+        );
+    }
+
+    #[test]
+    fn test_top_bit_is_negative() {
+        let mut out = BytesMut::new();
+        Color::ALPHA.to_sql(&Type::INT4, &mut out).unwrap();
+
+        assert_eq!(i32::MIN, i32::from_sql(&Type::INT4, &out).unwrap());
+
+        let mut out = BytesMut::new();
+        (-1i32).to_sql(&Type::INT4, &mut out).unwrap();
+
+        assert_eq!(
+            Color::from_bits_retain(u32::MAX),
+            Color::from_sql(&Type::INT4, &out).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let mut out = BytesMut::new();
+
+        assert!(Color::RED.to_sql_checked(&Type::INT8, &mut out).is_err());
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+//! Specialized property testing for flags types using `proptest`.
+
+use super::__private::alloc::vec::Vec;
+use crate::Flags;
+use proptest::{
+    sample::{subsequence, Subsequence},
+    strategy::{Map, Strategy},
+};
+
+/**
+The strategy used to generate arbitrary flags values.
+*/
+pub type FlagsStrategy<B> = Map<Subsequence<usize>, fn(Vec<usize>) -> B>;
+
+/**
+Generate flags values from subsets of the defined flags.
+
+If a `mask` is given, then only defined flags that are contained in it will be generated.
+Generated values shrink towards `empty()`.
+
+This is the strategy used by `any::<Flags>()`, and `any_with::<Flags>(Some(mask))`.
+*/
+pub fn flags<B: Flags + core::fmt::Debug>(mask: Option<B>) -> FlagsStrategy<B> {
+    // Sample indexes into the defined flags, so the flags type doesn't need to be `Clone`
+    let candidates = B::FLAGS
+        .iter()
+        .enumerate()
+        .filter(|(_, flag)| match mask {
+            Some(ref mask) => mask.contains(B::from_bits_retain(flag.value().bits())),
+            None => true,
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let len = candidates.len();
+
+    subsequence(candidates, 0..=len).prop_map(from_indexes::<B>)
+}
+
+fn from_indexes<B: Flags>(indexes: Vec<usize>) -> B {
+    let mut flags = B::empty();
+
+    for i in indexes {
+        flags.insert(B::from_bits_retain(B::FLAGS[i].value().bits()));
+    }
+
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{
+        prelude::*,
+        strategy::ValueTree,
+        test_runner::{TestCaseError, TestRunner},
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_any(color in any::<Color>()) {
+            prop_assert!(Color::all().contains(color));
+        }
+
+        #[test]
+        fn test_any_with_mask(color in any_with::<Color>(Some(Color::RED | Color::BLUE))) {
+            prop_assert!((Color::RED | Color::BLUE).contains(color));
+        }
+    }
+
+    #[test]
+    fn test_generates_all() {
+        let mut runner = TestRunner::deterministic();
+        let mut seen = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            let color = any::<Color>().new_tree(&mut runner).unwrap().current();
+
+            seen.insert(color.bits());
+        }
+
+        assert_eq!(8, seen.len());
+    }
+
+    #[test]
+    fn test_shrink() {
+        let mut runner = TestRunner::deterministic();
+
+        let err = runner
+            .run(&any::<Color>(), |color| {
+                if color.contains(Color::GREEN) {
+                    Err(TestCaseError::fail("contains GREEN"))
+                } else {
+                    Ok(())
+                }
+            })
+            .unwrap_err();
+
+        match err {
+            proptest::test_runner::TestError::Fail(_, color) => assert_eq!(Color::GREEN, color),
+            err => panic!("unexpected error {:?}", err),
+        }
+
+        let mut tree = any::<Color>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(Color::empty(), tree.current());
+    }
+}
@@ -0,0 +1,63 @@
+//! Converting flags types to and from Python objects using `pyo3`.
+
+use super::__private::alloc::{format, string::ToString, vec::Vec};
+use crate::{
+    parser::{self, ParseErrorKind, ParseHex, WriteHex},
+    Flags,
+};
+use pyo3::{
+    exceptions::PyValueError,
+    types::{PyAnyMethods, PyString, PyStringMethods},
+    Bound, FromPyObject, PyAny, PyResult, Python,
+};
+
+/**
+Extract a flags value from a Python `int`, or a `str` of flag names like `"A | B"`.
+
+Unknown bits in an `int` are retained. An unknown flag name raises a `ValueError`
+that lists the names of the defined flags.
+*/
+pub fn extract<'py, B: Flags>(ob: &Bound<'py, PyAny>) -> PyResult<B>
+where
+    B::Bits: FromPyObject<'py> + ParseHex,
+{
+    if let Ok(s) = ob.downcast::<PyString>() {
+        let s = s.to_cow()?;
+
+        return parser::from_str::<B>(&s).map_err(|e| match e.kind() {
+            ParseErrorKind::InvalidNamedFlag | ParseErrorKind::AmbiguousNamedFlag => {
+                let names = B::FLAGS
+                    .iter()
+                    .filter(|flag| !flag.is_unnamed())
+                    .map(|flag| flag.name())
+                    .collect::<Vec<_>>();
+
+                PyValueError::new_err(format!(
+                    "{}, expected one of: {}",
+                    e.display_in(&s),
+                    names.join(", ")
+                ))
+            }
+            _ => PyValueError::new_err(e.display_in(&s).to_string()),
+        });
+    }
+
+    Ok(B::from_bits_retain(ob.extract::<B::Bits>()?))
+}
+
+/**
+Convert a flags value into a Python `str` of flag names, like `"A | B"`.
+
+This uses the same format as [`parser::to_writer`].
+*/
+pub fn to_py_string<'py, B: Flags>(py: Python<'py>, flags: &B) -> Bound<'py, PyString>
+where
+    B::Bits: WriteHex,
+{
+    let mut s = super::__private::alloc::string::String::new();
+
+    // Writing to a `String` can't fail
+    let _ = parser::to_writer(flags, &mut s);
+
+    PyString::new(py, &s)
+}
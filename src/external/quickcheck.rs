@@ -0,0 +1,140 @@
+//! Specialized property testing for flags types using `quickcheck`.
+
+use super::__private::alloc::boxed::Box;
+use crate::Flags;
+use quickcheck::{Arbitrary, Gen};
+
+/**
+Generate some arbitrary flags value with only known bits set.
+
+Each defined flag is independently included or not.
+*/
+pub fn arbitrary<B: Flags>(g: &mut Gen) -> B {
+    let mut flags = B::empty();
+
+    for flag in B::FLAGS {
+        if bool::arbitrary(g) {
+            flags.insert(B::from_bits_retain(flag.value().bits()));
+        }
+    }
+
+    flags
+}
+
+/**
+Shrink a flags value towards having fewer flags set.
+
+The first candidate is always the empty set of flags, followed by the value
+with each of its contained flags removed in turn. Every candidate has strictly
+fewer bits set than the original value, so shrinking always terminates.
+*/
+pub fn shrink<B: Flags + 'static>(flags: &B) -> Box<dyn Iterator<Item = B>> {
+    if flags.is_empty() {
+        return Box::new(core::iter::empty());
+    }
+
+    let bits = flags.bits();
+
+    let removed = flags.iter().filter_map(move |flag| {
+        let candidate = B::from_bits_retain(bits).difference(flag);
+
+        // The empty set has already been yielded
+        if candidate.is_empty() {
+            None
+        } else {
+            Some(candidate)
+        }
+    });
+
+    Box::new(core::iter::once(B::empty()).chain(removed))
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    bitflags! {
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    #[test]
+    fn test_arbitrary() {
+        let mut g = Gen::new(10);
+
+        for _ in 0..100 {
+            let color = Color::arbitrary(&mut g);
+
+            assert!(Color::all().contains(color));
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(0, Color::empty().shrink().count());
+
+        assert_eq!(
+            vec![Color::empty()],
+            Color::RED.shrink().collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                Color::empty(),
+                Color::GREEN | Color::BLUE,
+                Color::RED | Color::BLUE,
+                Color::RED | Color::GREEN
+            ],
+            Color::all().shrink().collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![Color::empty(), Color::from_bits_retain(0xf0), Color::RED],
+            (Color::RED | Color::from_bits_retain(0xf0))
+                .shrink()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_shrink_minimizes() {
+        fn prop(color: Color) -> bool {
+            !color.contains(Color::GREEN)
+        }
+
+        // Shrink the same way `quickcheck` does, by switching to the first failing candidate
+        let mut color = Color::all() | Color::from_bits_retain(0xf0);
+        let mut steps = 0;
+
+        'shrink: loop {
+            for candidate in color.shrink() {
+                assert!(color.contains(candidate.clone()) && color != candidate);
+
+                if !prop(candidate.clone()) {
+                    color = candidate;
+                    steps += 1;
+
+                    continue 'shrink;
+                }
+            }
+
+            break;
+        }
+
+        assert_eq!(Color::GREEN, color);
+        assert_eq!(3, steps);
+    }
+
+    #[test]
+    fn test_quickcheck() {
+        fn prop(color: Color) -> bool {
+            Color::all().contains(color)
+        }
+
+        QuickCheck::new().quickcheck(prop as fn(Color) -> bool);
+    }
+}
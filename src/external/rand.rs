@@ -0,0 +1,136 @@
+//! Sampling random flags values using `rand`.
+
+use crate::Flags;
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+/**
+Sample a random flags value with only known bits set.
+
+Every subset of the bits in `B::all()` is equally likely.
+This is what `rng.random()` uses for flags types.
+*/
+pub fn sample<B: Flags, R: Rng + ?Sized>(rng: &mut R) -> B
+where
+    StandardUniform: Distribution<B::Bits>,
+{
+    sample_with(rng, B::all())
+}
+
+/**
+Sample a random flags value with only bits in `mask` set.
+
+Every subset of the known bits in `mask` is equally likely.
+Any unknown bits in `mask` are ignored.
+
+```
+# use rand::{rngs::StdRng, SeedableRng};
+# bitflags::bitflags! {
+#     struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#         const C = 1 << 2;
+#     }
+# }
+let mut rng = StdRng::seed_from_u64(42);
+let flags: Flags = bitflags::rand::sample_with(&mut rng, Flags::A | Flags::B);
+
+assert!((Flags::A | Flags::B).contains(flags));
+```
+*/
+pub fn sample_with<B: Flags, R: Rng + ?Sized>(rng: &mut R, mask: B) -> B
+where
+    StandardUniform: Distribution<B::Bits>,
+{
+    // Sample the full width of the bits type and mask it down,
+    // so every bit within the mask is set independently
+    let bits: B::Bits = StandardUniform.sample(rng);
+
+    B::from_bits_retain(bits & mask.bits() & B::all().bits())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, RngExt, SeedableRng};
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Wide: u128 {
+            const LOW = 1;
+            const HIGH = 1 << 127;
+        }
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut seen = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            let color: Color = rng.random();
+
+            assert!(Color::all().contains(color));
+            seen.insert(color.bits());
+        }
+
+        assert_eq!(8, seen.len());
+    }
+
+    #[test]
+    fn test_sample_with() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut seen = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            let color = super::sample_with(&mut rng, Color::RED | Color::BLUE);
+
+            assert!((Color::RED | Color::BLUE).contains(color));
+            seen.insert(color.bits());
+        }
+
+        assert_eq!(4, seen.len());
+
+        for _ in 0..100 {
+            let color = super::sample_with(&mut rng, Color::from_bits_retain(0xf0));
+
+            assert_eq!(Color::empty(), color);
+        }
+    }
+
+    #[test]
+    fn test_random_wide() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut seen = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            let wide: Wide = rng.random();
+
+            assert!(Wide::all().contains(wide));
+            seen.insert(wide.bits());
+        }
+
+        assert_eq!(4, seen.len());
+    }
+
+    #[test]
+    fn test_seeded() {
+        let sample = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            (0..8).map(|_| rng.random::<Color>()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(sample(42), sample(42));
+    }
+}
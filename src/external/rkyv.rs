@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use rkyv::{rancor::Error, Archive, Deserialize, Serialize};
+
+    bitflags! {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq, Eq)]
+        #[rkyv(derive(Debug, PartialEq, Eq))]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    bitflags! {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq, Eq)]
+        struct Small: u8 {
+            const A = 0x1;
+        }
+    }
+
+    bitflags! {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq, Eq)]
+        struct Signed: i64 {
+            const A = 0x1;
+        }
+    }
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Pixel {
+        x: u16,
+        color: Color,
+    }
+
+    #[test]
+    fn test_rkyv() {
+        let color = Color::RED | Color::BLUE | Color::from_bits_retain(0xf0);
+
+        let bytes = rkyv::to_bytes::<Error>(&color).unwrap();
+        let archived = rkyv::access::<ArchivedColor, Error>(&bytes).unwrap();
+
+        assert_eq!(color, archived.0.to_native());
+        assert_eq!(0xf5, archived.0.bits());
+        assert_eq!(color, rkyv::deserialize::<Color, Error>(archived).unwrap());
+    }
+
+    #[test]
+    fn test_rkyv_widths() {
+        let small = Small::from_bits_retain(u8::MAX);
+        let bytes = rkyv::to_bytes::<Error>(&small).unwrap();
+        assert_eq!(small, rkyv::from_bytes::<Small, Error>(&bytes).unwrap());
+
+        let signed = Signed::A | Signed::from_bits_retain(i64::MIN);
+        let bytes = rkyv::to_bytes::<Error>(&signed).unwrap();
+        assert_eq!(signed, rkyv::from_bytes::<Signed, Error>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_rkyv_field() {
+        let pixel = Pixel {
+            x: 3,
+            color: Color::GREEN,
+        };
+
+        let bytes = rkyv::to_bytes::<Error>(&pixel).unwrap();
+        let archived = rkyv::access::<ArchivedPixel, Error>(&bytes).unwrap();
+
+        assert_eq!(Color::GREEN, archived.color.0.to_native());
+        assert_eq!(pixel, rkyv::deserialize::<Pixel, Error>(archived).unwrap());
+    }
+}
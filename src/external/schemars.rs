@@ -0,0 +1,114 @@
+//! Specialized JSON schemas for flags types using `schemars`.
+
+use super::__private::alloc::{boxed::Box, format, string::String, vec::Vec};
+use crate::Flags;
+use schemars::{
+    schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation},
+    SchemaGenerator,
+};
+
+/**
+Generate a JSON schema for a set of flags as a human-readable string like `"A | B"`.
+
+This matches the text format used by the default `serde` support. The schema's pattern only
+accepts the names of defined flags and hex numbers, and each defined flag's name is included
+as an example value.
+*/
+pub fn json_schema<B: Flags>(_generator: &mut SchemaGenerator) -> Schema {
+    let names = B::FLAGS
+        .iter()
+        .filter(|flag| flag.is_named())
+        .map(|flag| flag.name())
+        .collect::<Vec<_>>();
+
+    // Flag names are identifiers, so they don't contain any characters that need escaping
+    let mut flag = String::new();
+    for name in &names {
+        flag.push_str(name);
+        flag.push('|');
+    }
+    flag.push_str("0[xX][0-9a-fA-F]+");
+
+    let pattern = format!(r"^\s*(?:(?:{0})\s*(?:\|\s*(?:{0})\s*)*)?$", flag);
+
+    let description = if names.is_empty() {
+        String::from("A `|` separated list of hex numbers.")
+    } else {
+        format!(
+            "A `|` separated list of flag names or hex numbers. Valid flag names are: {}.",
+            names.join(", ")
+        )
+    };
+
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        metadata: Some(Box::new(Metadata {
+            description: Some(description),
+            examples: names
+                .iter()
+                .map(|name| schemars::_serde_json::Value::String(String::from(*name)))
+                .collect(),
+            ..Default::default()
+        })),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(pattern),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+    use serde_json::json;
+
+    bitflags! {
+        #[derive(schemars::JsonSchema)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+            const C = 1 << 2;
+        }
+    }
+
+    bitflags! {
+        #[derive(schemars::JsonSchema)]
+        struct Unnamed: u8 {
+            const _ = !0;
+        }
+    }
+
+    #[test]
+    fn schema() {
+        let schema = serde_json::to_value(schema_for!(Flags)).unwrap();
+
+        assert_eq!(json!("Flags"), schema["title"]);
+        assert_eq!(json!("string"), schema["type"]);
+        assert_eq!(json!(["A", "B", "C"]), schema["examples"]);
+        assert_eq!(
+            json!(
+                "A `|` separated list of flag names or hex numbers. Valid flag names are: A, B, C."
+            ),
+            schema["description"]
+        );
+        assert_eq!(
+            json!(
+                r"^\s*(?:(?:A|B|C|0[xX][0-9a-fA-F]+)\s*(?:\|\s*(?:A|B|C|0[xX][0-9a-fA-F]+)\s*)*)?$"
+            ),
+            schema["pattern"]
+        );
+
+        let schema = serde_json::to_value(schema_for!(Unnamed)).unwrap();
+
+        assert_eq!(
+            json!("A `|` separated list of hex numbers."),
+            schema["description"]
+        );
+        assert_eq!(
+            json!(r"^\s*(?:(?:0[xX][0-9a-fA-F]+)\s*(?:\|\s*(?:0[xX][0-9a-fA-F]+)\s*)*)?$"),
+            schema["pattern"]
+        );
+    }
+}
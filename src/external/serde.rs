@@ -1,19 +1,44 @@
 //! Specialized serialization for flags types using `serde`.
 
 use crate::{
-    parser::{self, ParseHex, WriteHex},
+    parser::{ParseHex, WriteHex},
     Flags,
 };
-use core::{fmt, str};
-use serde::{
-    de::{Error, Visitor},
-    Deserialize, Deserializer, Serialize, Serializer,
-};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub mod as_bits;
+pub mod as_map;
+pub mod as_names;
+pub mod as_string;
+pub mod catalog;
+pub mod compat_numeric;
+pub mod ignore_case;
+pub mod legacy_format;
+pub mod lenient;
+pub mod option_empty_as_none;
+pub mod strict;
+
+mod wide;
+
+/// Serialize flags as a plain number, even in human-readable formats.
+///
+/// This is another name for the [`as_bits`] module.
+pub use self::as_bits as as_number;
 
 /**
 Serialize a set of flags as a human-readable string or their underlying bits.
 
 Any unknown bits will be retained.
+
+Use the [`as_string`] or [`as_bits`] modules to pick one of these representations regardless of
+whether or not the format is human-readable.
+
+Flags types with `u128` or `i128` bits need a format that supports 128-bit numbers for their
+underlying bits. Other formats will return an error instead of truncating them.
+
+Flags types with `usize` or `isize` bits are serialized as a `u64` or `i64`, so they're portable
+between platforms with different pointer widths. Deserializing them will return an error if the
+number doesn't fit in `usize` or `isize` on the current platform.
 */
 pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -21,11 +46,11 @@ where
 {
     // Serialize human-readable flags as a string like `"A | B"`
     if serializer.is_human_readable() {
-        serializer.collect_str(&parser::AsDisplay(flags))
+        as_string::serialize(flags, serializer)
     }
     // Serialize non-human-readable flags directly as the underlying bits
     else {
-        flags.bits().serialize(serializer)
+        as_bits::serialize(flags, serializer)
     }
 }
 
@@ -38,37 +63,22 @@ pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Resu
 where
     B::Bits: ParseHex + Deserialize<'de>,
 {
+    // Deserialize human-readable flags by parsing them from strings like `"A | B"`
     if deserializer.is_human_readable() {
-        // Deserialize human-readable flags by parsing them from strings like `"A | B"`
-        struct FlagsVisitor<B>(core::marker::PhantomData<B>);
-
-        impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
-        where
-            B::Bits: ParseHex,
-        {
-            type Value = B;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("a string value of `|` separated flags")
-            }
-
-            fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
-                parser::from_str(flags).map_err(|e| E::custom(e))
-            }
-        }
-
-        deserializer.deserialize_str(FlagsVisitor(Default::default()))
-    } else {
-        // Deserialize non-human-readable flags directly from the underlying bits
-        let bits = B::Bits::deserialize(deserializer)?;
-
-        Ok(B::from_bits_retain(bits))
+        as_string::deserialize(deserializer)
+    }
+    // Deserialize non-human-readable flags directly from the underlying bits
+    else {
+        as_bits::deserialize(deserializer)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use serde_test::{assert_tokens, Configure, Token::*};
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Compact,
+        Configure, Token::*,
+    };
     bitflags! {
         #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
         #[serde(transparent)]
@@ -80,6 +90,31 @@ mod tests {
         }
     }
 
+    bitflags! {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        #[serde(transparent)]
+        struct WideSerdeFlags: u128 {
+            const A = 1;
+            const TOP = 1 << 127;
+        }
+    }
+
+    bitflags! {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        #[serde(transparent)]
+        struct UsizeSerdeFlags: usize {
+            const A = 1;
+            const B = 1 << 31;
+        }
+
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        #[serde(transparent)]
+        struct U32SerdeFlags: u32 {
+            const A = 1;
+            const B = 1 << 31;
+        }
+    }
+
     #[test]
     fn test_serde_bitflags_default() {
         assert_tokens(&SerdeFlags::empty().readable(), &[Str("")]);
@@ -90,4 +125,156 @@ mod tests {
 
         assert_tokens(&(SerdeFlags::A | SerdeFlags::B).compact(), &[U32(1 | 2)]);
     }
+
+    #[test]
+    fn test_serde_bitflags_aliases() {
+        bitflags! {
+            #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+            #[serde(transparent)]
+            struct AliasedSerdeFlags: u32 {
+                const READ = 1;
+                #[bitflags(alias = "WRITE")]
+                const MODIFY = 1 << 1;
+            }
+        }
+
+        assert_de_tokens(
+            &(AliasedSerdeFlags::READ | AliasedSerdeFlags::MODIFY).readable(),
+            &[Str("READ | WRITE")],
+        );
+
+        // Only the name of the flag is serialized
+        assert_tokens(
+            &(AliasedSerdeFlags::READ | AliasedSerdeFlags::MODIFY).readable(),
+            &[Str("READ | MODIFY")],
+        );
+    }
+
+    #[test]
+    fn test_serde_bitflags_rename() {
+        bitflags! {
+            #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+            #[serde(transparent)]
+            struct RenamedSerdeFlags: u32 {
+                #[bitflags(rename = "allow-read")]
+                const ALLOW_READ = 1;
+                const EXEC = 1 << 1;
+                #[bitflags(rename = "allow-write")]
+                const ALLOW_WRITE = 1 << 2;
+            }
+        }
+
+        for (flags, json) in [
+            (RenamedSerdeFlags::ALLOW_READ, r#""allow-read""#),
+            (RenamedSerdeFlags::EXEC, r#""EXEC""#),
+            (
+                RenamedSerdeFlags::ALLOW_READ
+                    | RenamedSerdeFlags::EXEC
+                    | RenamedSerdeFlags::ALLOW_WRITE,
+                r#""allow-read | EXEC | allow-write""#,
+            ),
+            (
+                RenamedSerdeFlags::ALLOW_WRITE | RenamedSerdeFlags::from_bits_retain(1 << 8),
+                r#""allow-write | 0x100""#,
+            ),
+        ] {
+            assert_eq!(json, serde_json::to_string(&flags).unwrap());
+            assert_eq!(
+                flags,
+                serde_json::from_str::<RenamedSerdeFlags>(json).unwrap()
+            );
+        }
+
+        // The identifier of a renamed flag isn't accepted
+        assert!(serde_json::from_str::<RenamedSerdeFlags>(r#""ALLOW_READ""#).is_err());
+    }
+
+    #[test]
+    fn test_serde_bitflags_empty() {
+        assert_eq!(
+            r#""""#,
+            serde_json::to_string(&SerdeFlags::empty()).unwrap()
+        );
+        assert_eq!(
+            SerdeFlags::empty(),
+            serde_json::from_str::<SerdeFlags>(r#""""#).unwrap()
+        );
+
+        // The `Debug` form of an empty flags value is also accepted
+        assert_eq!(
+            SerdeFlags::empty(),
+            serde_json::from_str::<SerdeFlags>(r#""0x0""#).unwrap()
+        );
+
+        bitflags! {
+            #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+            #[serde(transparent)]
+            #[bitflags(empty = "none")]
+            struct EmptyNameSerdeFlags: u32 {
+                const A = 1;
+            }
+        }
+
+        for (flags, json) in [
+            (EmptyNameSerdeFlags::empty(), r#""none""#),
+            (EmptyNameSerdeFlags::A, r#""A""#),
+        ] {
+            assert_eq!(json, serde_json::to_string(&flags).unwrap());
+            assert_eq!(
+                flags,
+                serde_json::from_str::<EmptyNameSerdeFlags>(json).unwrap()
+            );
+        }
+
+        assert_eq!(
+            EmptyNameSerdeFlags::empty(),
+            serde_json::from_str::<EmptyNameSerdeFlags>(r#""""#).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_serde_bitflags_wide() {
+        assert_tokens(
+            &(WideSerdeFlags::A | WideSerdeFlags::TOP).readable(),
+            &[Str("A | TOP")],
+        );
+
+        assert_tokens(
+            &WideSerdeFlags::from_bits_retain(1 << 100).readable(),
+            &[Str("0x10000000000000000000000000")],
+        );
+
+        // `serde_test` doesn't support 128-bit numbers, so binary formats
+        // that don't either should return an error instead of truncating
+        assert_ser_tokens_error(&WideSerdeFlags::TOP.compact(), &[], "u128 is not supported");
+
+        // Smaller numbers can still be deserialized
+        assert_de_tokens(&WideSerdeFlags::A.compact(), &[U64(1)]);
+    }
+
+    #[test]
+    fn test_serde_bitflags_pointer_width() {
+        assert_tokens(
+            &(UsizeSerdeFlags::A | UsizeSerdeFlags::B).readable(),
+            &[Str("A | B")],
+        );
+
+        // `usize` bits are always serialized as a `u64`
+        assert_tokens(
+            &(UsizeSerdeFlags::A | UsizeSerdeFlags::B).compact(),
+            &[U64(1 | 1 << 31)],
+        );
+
+        // The same data can be read by a narrower type, like `usize` on a 32-bit platform
+        assert_de_tokens(
+            &(U32SerdeFlags::A | U32SerdeFlags::B).compact(),
+            &[U64(1 | 1 << 31)],
+        );
+
+        // Numbers that don't fit in the narrower type are an error instead of being truncated
+        assert_de_tokens_error::<Compact<U32SerdeFlags>>(
+            &[U64(1 << 32)],
+            "invalid value: integer `4294967296`, expected u32",
+        );
+    }
 }
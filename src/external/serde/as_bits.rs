@@ -0,0 +1,149 @@
+/*!
+Serialize flags as their underlying bits.
+
+This module can be used with `#[serde(with = "bitflags::serde::as_bits")]` on a field of a
+flags type to always represent it as a raw number like `3`, even in human-readable formats
+that would otherwise use a string like `"A | B"`.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::as_bits")]
+    flags: Flags,
+}
+
+let config = Config { flags: Flags::A | Flags::B };
+
+assert_eq!(r#"{"flags":3}"#, serde_json::to_string(&config)?);
+# Ok::<(), serde_json::Error>(())
+```
+
+The bits are always written as an integer of the bits type, never as a string or a float. Formats like
+`serde_json` represent every `u64` exactly, but some consumers of JSON, like JavaScript, read numbers as
+floats and silently lose precision above 2<sup>53</sup>. If a flags type with wide bits needs to be read
+by them then use [`as_string`](crate::serde::as_string) instead.
+
+Deserializing fails if the number doesn't fit in the bits type, instead of truncating it.
+
+This module is also available as [`as_number`](crate::serde::as_number).
+*/
+
+use crate::Flags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/**
+Serialize a set of flags as their underlying bits.
+
+Any unknown bits will be retained.
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: Serialize,
+{
+    flags.bits().serialize(serializer)
+}
+
+/**
+Deserialize a set of flags from their underlying bits.
+
+Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: Deserialize<'de>,
+{
+    let bits = B::Bits::deserialize(deserializer)?;
+
+    Ok(B::from_bits_retain(bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_tokens, Configure, Token::*};
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct AsBits(#[serde(with = "crate::serde::as_bits")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&AsBits(Flags::empty()).readable(), &[U8(0)]);
+        assert_tokens(&AsBits(Flags::empty()).compact(), &[U8(0)]);
+
+        assert_tokens(&AsBits(Flags::A | Flags::B).readable(), &[U8(3)]);
+        assert_tokens(&AsBits(Flags::A | Flags::B).compact(), &[U8(3)]);
+
+        assert_tokens(
+            &AsBits(Flags::from_bits_retain(0xf1)).readable(),
+            &[U8(0xf1)],
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            "3",
+            serde_json::to_string(&AsBits(Flags::A | Flags::B)).unwrap()
+        );
+        assert_eq!(AsBits(Flags::B), serde_json::from_str("2").unwrap());
+        assert!(serde_json::from_str::<AsBits>(r#""B""#).is_err());
+
+        // Numbers that don't fit in the bits type aren't truncated
+        assert!(serde_json::from_str::<AsBits>("256").is_err());
+        assert!(serde_json::from_str::<AsBits>("-1").is_err());
+        assert!(serde_json::from_str::<AsBits>("1.5").is_err());
+    }
+
+    #[test]
+    fn json_wide() {
+        bitflags! {
+            #[derive(Debug, PartialEq, Eq)]
+            struct WideFlags: u64 {
+                const A = 1;
+                const TOP = 1 << 63;
+            }
+        }
+
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        #[serde(transparent)]
+        struct AsBits(#[serde(with = "crate::serde::as_bits")] WideFlags);
+
+        // Bits above 2^53 are written exactly, even though they can't be represented by a float
+        let flags = AsBits(WideFlags::A | WideFlags::TOP | WideFlags::from_bits_retain(1 << 53));
+        let json = serde_json::to_string(&flags).unwrap();
+
+        assert_eq!("9232379236109516801", json);
+        assert_eq!(flags, serde_json::from_str(&json).unwrap());
+
+        assert!(serde_json::from_str::<AsBits>("18446744073709551616").is_err());
+    }
+
+    #[test]
+    fn as_number() {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        #[serde(transparent)]
+        struct AsNumber(#[serde(with = "crate::serde::as_number")] Flags);
+
+        assert_eq!(
+            "3",
+            serde_json::to_string(&AsNumber(Flags::A | Flags::B)).unwrap()
+        );
+        assert_eq!(AsNumber(Flags::B), serde_json::from_str("2").unwrap());
+    }
+}
@@ -0,0 +1,283 @@
+/*!
+Serialize flags as a map of flag names to whether or not they're set.
+
+This module can be used with `#[serde(with = "bitflags::serde::as_map")]` on a field of a
+flags type to represent it as a map containing every named flag, like `{"A": true, "B": false}`.
+This makes it possible for consumers to see all the flags that are defined, even if they're unset.
+
+The same representation is used for both human-readable and non-human-readable formats.
+
+Unknown bits can't be represented in a map of named flags, so they're dropped when serializing.
+When deserializing, flags missing from the map are considered unset, and unrecognized names
+will fail to deserialize. Use the [`lenient`] module instead to ignore unrecognized names.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::as_map")]
+    flags: Flags,
+}
+
+let config = Config { flags: Flags::A };
+
+assert_eq!(r#"{"flags":{"A":true,"B":false}}"#, serde_json::to_string(&config)?);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::Flags;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Unexpected, Visitor},
+    ser::SerializeMap,
+    Deserializer, Serializer,
+};
+
+/**
+Serialize a set of flags as a map of every named flag to whether or not it's set.
+
+Any unknown bits will be dropped.
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error> {
+    let len = B::FLAGS.iter().filter(|flag| flag.is_named()).count();
+
+    let mut map = serializer.serialize_map(Some(len))?;
+
+    for flag in B::FLAGS.iter().filter(|flag| flag.is_named()) {
+        let set = flags.contains(B::from_bits_retain(flag.value().bits()));
+
+        map.serialize_entry(flag.name(), &set)?;
+    }
+
+    map.end()
+}
+
+/**
+Deserialize a set of flags from a map of flag names to whether or not they're set.
+
+Flags that are missing from the map are unset. Names that don't correspond to any
+defined flag will fail to deserialize.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error> {
+    deserializer.deserialize_map(FlagsVisitor {
+        lenient: false,
+        _marker: PhantomData,
+    })
+}
+
+/**
+Serialize flags as a map of flag names, ignoring unrecognized names when deserializing.
+
+This module is like its parent [`as_map`](super) module, except names that don't correspond to any
+defined flag are skipped instead of failing. This can be useful when reading input produced by
+a newer version of a flags type that defines additional flags.
+*/
+pub mod lenient {
+    use super::*;
+
+    /**
+    Serialize a set of flags as a map of every named flag to whether or not it's set.
+
+    Any unknown bits will be dropped.
+    */
+    pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(flags, serializer)
+    }
+
+    /**
+    Deserialize a set of flags from a map of flag names to whether or not they're set.
+
+    Flags that are missing from the map are unset. Names that don't correspond to any
+    defined flag are ignored.
+    */
+    pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<B, D::Error> {
+        deserializer.deserialize_map(FlagsVisitor {
+            lenient: true,
+            _marker: PhantomData,
+        })
+    }
+}
+
+struct FlagsVisitor<B> {
+    lenient: bool,
+    _marker: PhantomData<B>,
+}
+
+impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B> {
+    type Value = B;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map of flag names to booleans")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut flags = B::empty();
+
+        while let Some(flag) = map.next_key_seed(NameSeed {
+            lenient: self.lenient,
+            _marker: PhantomData::<B>,
+        })? {
+            match flag {
+                Some(flag) => {
+                    if map.next_value::<bool>()? {
+                        flags.insert(flag);
+                    }
+                }
+                // Unrecognized names in lenient mode still need their value consumed
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(flags)
+    }
+}
+
+// Deserialize a single flag from its name without needing to allocate
+struct NameSeed<B> {
+    lenient: bool,
+    _marker: PhantomData<B>,
+}
+
+impl<'de, B: Flags> DeserializeSeed<'de> for NameSeed<B> {
+    type Value = Option<B>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, B: Flags> Visitor<'de> for NameSeed<B> {
+    type Value = Option<B>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the name of a defined flag")
+    }
+
+    fn visit_str<E: Error>(self, name: &str) -> Result<Self::Value, E> {
+        match B::from_name(name) {
+            Some(flag) => Ok(Some(flag)),
+            None if self.lenient => Ok(None),
+            None => Err(E::invalid_value(Unexpected::Str(name), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure,
+        Token::{Bool, Map, MapEnd, Str, U8},
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+            const C = 1 << 2;
+            const _ = !0;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct AsMap(#[serde(with = "crate::serde::as_map")] Flags);
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct Lenient(#[serde(with = "crate::serde::as_map::lenient")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(
+            &AsMap(Flags::empty()).readable(),
+            &[
+                Map { len: Some(3) },
+                Str("A"),
+                Bool(false),
+                Str("B"),
+                Bool(false),
+                Str("C"),
+                Bool(false),
+                MapEnd,
+            ],
+        );
+
+        assert_tokens(
+            &AsMap(Flags::A | Flags::C).compact(),
+            &[
+                Map { len: Some(3) },
+                Str("A"),
+                Bool(true),
+                Str("B"),
+                Bool(false),
+                Str("C"),
+                Bool(true),
+                MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn unknown_bits() {
+        assert_eq!(
+            r#"{"A":true,"B":false,"C":false}"#,
+            serde_json::to_string(&AsMap(Flags::A | Flags::from_bits_retain(1 << 4))).unwrap()
+        );
+    }
+
+    #[test]
+    fn missing() {
+        assert_de_tokens(
+            &AsMap(Flags::B).readable(),
+            &[Map { len: Some(1) }, Str("B"), Bool(true), MapEnd],
+        );
+
+        assert_eq!(
+            AsMap(Flags::A),
+            serde_json::from_str(r#"{"A":true,"C":false}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn unrecognized() {
+        assert_de_tokens_error::<AsMap>(
+            &[Map { len: Some(1) }, Str("D"), Bool(true)],
+            "invalid value: string \"D\", expected the name of a defined flag",
+        );
+
+        assert_de_tokens_error::<AsMap>(
+            &[Map { len: Some(1) }, Str("A"), U8(1)],
+            "invalid type: integer `1`, expected a boolean",
+        );
+
+        assert_de_tokens(
+            &Lenient(Flags::A).readable(),
+            &[
+                Map { len: Some(2) },
+                Str("D"),
+                Bool(true),
+                Str("A"),
+                Bool(true),
+                MapEnd,
+            ],
+        );
+
+        assert_eq!(
+            Lenient(Flags::C),
+            serde_json::from_str(r#"{"C":true,"D":{"nested":[1,2]}}"#).unwrap()
+        );
+    }
+}
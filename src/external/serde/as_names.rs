@@ -0,0 +1,235 @@
+/*!
+Serialize flags as a sequence of flag names.
+
+This module can be used with `#[serde(with = "bitflags::serde::as_names")]` on a field of a
+flags type to represent it as a list of the names of its contained flags, like `["A", "B"]`,
+instead of a single string like `"A | B"`.
+
+The same representation is used for both human-readable and non-human-readable formats.
+
+Any unknown bits are serialized as a single hex string like `"0xf0"` at the end of the sequence,
+so no information is lost. Hex strings are also accepted when deserializing.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::as_names")]
+    flags: Flags,
+}
+
+let config = Config { flags: Flags::A | Flags::B };
+
+assert_eq!(r#"{"flags":["A","B"]}"#, serde_json::to_string(&config)?);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::{
+    parser::{ParseHex, WriteHex},
+    Bits, Flags,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Unexpected, Visitor},
+    ser::SerializeSeq,
+    Deserializer, Serialize, Serializer,
+};
+
+/**
+Serialize a set of flags as a sequence of flag names.
+
+Any unknown bits will be serialized as a hex string at the end of the sequence.
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex,
+{
+    let mut iter = flags.iter_names();
+    let mut len = (&mut iter).count();
+
+    if iter.remaining().bits() != B::Bits::EMPTY {
+        len += 1;
+    }
+
+    let mut seq = serializer.serialize_seq(Some(len))?;
+
+    let mut iter = flags.iter_names();
+    for (name, _) in &mut iter {
+        seq.serialize_element(name)?;
+    }
+
+    let remaining = iter.remaining().bits();
+    if remaining != B::Bits::EMPTY {
+        seq.serialize_element(&AsHex(remaining))?;
+    }
+
+    seq.end()
+}
+
+/**
+Deserialize a set of flags from a sequence of flag names.
+
+Names that don't correspond to any defined flag will fail to deserialize.
+Hex strings like `"0xf0"` will be retained as unknown bits.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex,
+{
+    struct FlagsVisitor<B>(PhantomData<B>);
+
+    impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
+    where
+        B::Bits: ParseHex,
+    {
+        type Value = B;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a sequence of flag names")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut flags = B::empty();
+
+            while let Some(flag) = seq.next_element_seed(FlagSeed(PhantomData))? {
+                flags.insert(flag);
+            }
+
+            Ok(flags)
+        }
+    }
+
+    deserializer.deserialize_seq(FlagsVisitor(PhantomData))
+}
+
+struct AsHex<B>(B);
+
+impl<B: WriteHex> Serialize for AsHex<B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<B: WriteHex> fmt::Display for AsHex<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.write_hex(f)
+    }
+}
+
+// Deserialize a single flag from its name without needing to allocate
+struct FlagSeed<B>(PhantomData<B>);
+
+impl<'de, B: Flags> DeserializeSeed<'de> for FlagSeed<B>
+where
+    B::Bits: ParseHex,
+{
+    type Value = B;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, B: Flags> Visitor<'de> for FlagSeed<B>
+where
+    B::Bits: ParseHex,
+{
+    type Value = B;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the name of a defined flag or a hex number")
+    }
+
+    fn visit_str<E: Error>(self, flag: &str) -> Result<Self::Value, E> {
+        if let Some(hex) = flag.strip_prefix("0x").or_else(|| flag.strip_prefix("0X")) {
+            let bits = B::Bits::parse_hex(hex)
+                .map_err(|e| E::custom(e.with_span(0..flag.len()).display_in(flag)))?;
+
+            Ok(B::from_bits_retain(bits))
+        } else {
+            B::from_name(flag).ok_or_else(|| E::invalid_value(Unexpected::Str(flag), &self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure,
+        Token::{Seq, SeqEnd, Str},
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+            const C = 1 << 2;
+            const AB = Self::A.bits() | Self::B.bits();
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct AsNames(#[serde(with = "crate::serde::as_names")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(
+            &AsNames(Flags::empty()).readable(),
+            &[Seq { len: Some(0) }, SeqEnd],
+        );
+
+        assert_tokens(
+            &AsNames(Flags::A | Flags::C).readable(),
+            &[Seq { len: Some(2) }, Str("A"), Str("C"), SeqEnd],
+        );
+
+        assert_tokens(
+            &AsNames(Flags::A | Flags::C).compact(),
+            &[Seq { len: Some(2) }, Str("A"), Str("C"), SeqEnd],
+        );
+
+        assert_tokens(
+            &AsNames(Flags::AB).readable(),
+            &[Seq { len: Some(2) }, Str("A"), Str("B"), SeqEnd],
+        );
+
+        assert_de_tokens(
+            &AsNames(Flags::AB).readable(),
+            &[Seq { len: Some(1) }, Str("AB"), SeqEnd],
+        );
+
+        assert_tokens(
+            &AsNames(Flags::A | Flags::from_bits_retain(0xf0)).readable(),
+            &[Seq { len: Some(2) }, Str("A"), Str("0xf0"), SeqEnd],
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<AsNames>(
+            &[Seq { len: Some(1) }, Str("D")],
+            "invalid value: string \"D\", expected the name of a defined flag or a hex number",
+        );
+
+        assert_de_tokens_error::<AsNames>(
+            &[Seq { len: Some(1) }, Str("A | B")],
+            "invalid value: string \"A | B\", expected the name of a defined flag or a hex number",
+        );
+
+        assert_de_tokens_error::<AsNames>(
+            &[Seq { len: Some(1) }, Str("0x100")],
+            "overflowing hex flag `0x100`",
+        );
+    }
+}
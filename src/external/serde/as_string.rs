@@ -0,0 +1,130 @@
+/*!
+Serialize flags as text.
+
+This module can be used with `#[serde(with = "bitflags::serde::as_string")]` on a field of a
+flags type to always represent it as a string like `"A | B"`, even in non-human-readable formats
+that would otherwise use the underlying bits.
+
+The text format is described in the [`parser`](crate::parser) module.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::as_string")]
+    flags: Flags,
+}
+
+let config = Config { flags: Flags::A | Flags::B };
+
+assert_eq!(r#"{"flags":"A | B"}"#, serde_json::to_string(&config)?);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::{
+    parser::{self, ParseHex, WriteHex},
+    Flags,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, Visitor},
+    Deserializer, Serializer,
+};
+
+/**
+Serialize a set of flags as a string like `"A | B"`.
+
+Any unknown bits will be retained.
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex,
+{
+    serializer.collect_str(&parser::AsDisplay(flags))
+}
+
+/**
+Deserialize a set of flags from a string like `"A | B"`.
+
+Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex,
+{
+    struct FlagsVisitor<B>(PhantomData<B>);
+
+    impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
+    where
+        B::Bits: ParseHex,
+    {
+        type Value = B;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string value of `|` separated flags")
+        }
+
+        fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
+            parser::from_str(flags).map_err(|e| E::custom(e.display_in(flags)))
+        }
+    }
+
+    deserializer.deserialize_str(FlagsVisitor(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token::*};
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct AsString(#[serde(with = "crate::serde::as_string")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&AsString(Flags::empty()).readable(), &[Str("")]);
+        assert_tokens(&AsString(Flags::empty()).compact(), &[Str("")]);
+
+        assert_tokens(&AsString(Flags::A | Flags::B).readable(), &[Str("A | B")]);
+        assert_tokens(&AsString(Flags::A | Flags::B).compact(), &[Str("A | B")]);
+
+        assert_tokens(
+            &AsString(Flags::A | Flags::from_bits_retain(0xf0)).compact(),
+            &[Str("A | 0xf0")],
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<AsString>(
+            &[U8(1)],
+            "invalid type: integer `1`, expected a string value of `|` separated flags",
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            r#""A | B""#,
+            serde_json::to_string(&AsString(Flags::A | Flags::B)).unwrap()
+        );
+        assert_eq!(AsString(Flags::B), serde_json::from_str(r#""B""#).unwrap());
+        assert!(serde_json::from_str::<AsString>("2").is_err());
+    }
+}
@@ -0,0 +1,243 @@
+/*!
+Serialize the table of defined flags of a flags type.
+
+This module can be used to describe a flags type to consumers that can't see its definition,
+like a frontend building a picker for its flags. Call [`catalog`] to get a value that serializes
+as a sequence with an entry for each flag in [`Flags::FLAGS`], in the order they're defined:
+
+- `name`: The name of the flag, or `null` (`None`) if it's unnamed, like `const _ = !0`.
+- `bits`: The underlying bits of the flag, as a number like with [`as_bits`](crate::serde::as_bits).
+- `composite`: Whether the flag is composed of other defined flags, like [`Flag::is_composite`](crate::Flag::is_composite).
+
+Each entry is serialized as a struct called `Flag` with the fields in this order. This format is
+stable, so fields won't be renamed, reordered, or removed without a major version bump.
+
+```
+# bitflags::bitflags! {
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#         const AB = Self::A.bits() | Self::B.bits();
+#         const _ = !0;
+#     }
+# }
+let catalog = bitflags::serde::catalog::catalog::<Flags>();
+
+assert_eq!(
+    r#"[{"name":"A","bits":1,"composite":false},{"name":"B","bits":2,"composite":false},{"name":"AB","bits":3,"composite":true},{"name":null,"bits":255,"composite":false}]"#,
+    serde_json::to_string(&catalog)?,
+);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::{Flag, Flags};
+use core::marker::PhantomData;
+use serde::{
+    ser::{SerializeSeq, SerializeStruct},
+    Serialize, Serializer,
+};
+
+/**
+Get the table of defined flags of a flags type, which can be serialized.
+*/
+pub fn catalog<B: Flags>() -> Catalog<B> {
+    Catalog(PhantomData)
+}
+
+/**
+The table of defined flags of a flags type, returned by [`catalog`].
+
+See the [module docs](self) for the format it's serialized in.
+*/
+pub struct Catalog<B>(PhantomData<B>);
+
+impl<B: Flags> Serialize for Catalog<B>
+where
+    B::Bits: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializer = serializer.serialize_seq(Some(B::FLAGS.len()))?;
+
+        for flag in B::FLAGS {
+            serializer.serialize_element(&Entry(flag))?;
+        }
+
+        serializer.end()
+    }
+}
+
+struct Entry<'a, B: 'static>(&'a Flag<B>);
+
+impl<'a, B: Flags> Serialize for Entry<'a, B>
+where
+    B::Bits: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = if self.0.is_named() {
+            Some(self.0.name())
+        } else {
+            None
+        };
+
+        let mut serializer = serializer.serialize_struct("Flag", 3)?;
+        serializer.serialize_field("name", &name)?;
+        serializer.serialize_field("bits", &self.0.value().bits())?;
+        serializer.serialize_field("composite", &self.0.is_composite())?;
+        serializer.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token::*};
+
+    bitflags! {
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+            const C = 1 << 2;
+            const ABC = Self::A.bits() | Self::B.bits() | Self::C.bits();
+            #[bitflags(rename = "high")]
+            const HIGH = 1 << 7;
+            const _ = 0b0111_1000;
+        }
+    }
+
+    #[test]
+    fn tokens() {
+        assert_ser_tokens(
+            &super::catalog::<Flags>(),
+            &[
+                Seq {
+                    len: Option::Some(6),
+                },
+                Struct {
+                    name: "Flag",
+                    len: 3,
+                },
+                Str("name"),
+                Some,
+                Str("A"),
+                Str("bits"),
+                U8(1),
+                Str("composite"),
+                Bool(false),
+                StructEnd,
+                Struct {
+                    name: "Flag",
+                    len: 3,
+                },
+                Str("name"),
+                Some,
+                Str("B"),
+                Str("bits"),
+                U8(1 << 1),
+                Str("composite"),
+                Bool(false),
+                StructEnd,
+                Struct {
+                    name: "Flag",
+                    len: 3,
+                },
+                Str("name"),
+                Some,
+                Str("C"),
+                Str("bits"),
+                U8(1 << 2),
+                Str("composite"),
+                Bool(false),
+                StructEnd,
+                Struct {
+                    name: "Flag",
+                    len: 3,
+                },
+                Str("name"),
+                Some,
+                Str("ABC"),
+                Str("bits"),
+                U8(0b111),
+                Str("composite"),
+                Bool(true),
+                StructEnd,
+                Struct {
+                    name: "Flag",
+                    len: 3,
+                },
+                Str("name"),
+                Some,
+                Str("high"),
+                Str("bits"),
+                U8(1 << 7),
+                Str("composite"),
+                Bool(false),
+                StructEnd,
+                Struct {
+                    name: "Flag",
+                    len: 3,
+                },
+                Str("name"),
+                None,
+                Str("bits"),
+                U8(0b0111_1000),
+                Str("composite"),
+                Bool(false),
+                StructEnd,
+                SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn json() {
+        let json = serde_json::to_string_pretty(&super::catalog::<Flags>()).unwrap();
+
+        assert_eq!(
+            r#"[
+  {
+    "name": "A",
+    "bits": 1,
+    "composite": false
+  },
+  {
+    "name": "B",
+    "bits": 2,
+    "composite": false
+  },
+  {
+    "name": "C",
+    "bits": 4,
+    "composite": false
+  },
+  {
+    "name": "ABC",
+    "bits": 7,
+    "composite": true
+  },
+  {
+    "name": "high",
+    "bits": 128,
+    "composite": false
+  },
+  {
+    "name": null,
+    "bits": 120,
+    "composite": false
+  }
+]"#,
+            json
+        );
+    }
+
+    #[test]
+    fn empty() {
+        bitflags! {
+            struct Undefined: u8 {}
+        }
+
+        assert_eq!(
+            "[]",
+            serde_json::to_string(&super::catalog::<Undefined>()).unwrap()
+        );
+    }
+}
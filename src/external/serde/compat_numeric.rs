@@ -0,0 +1,236 @@
+/*!
+Deserialize flags from text or from their underlying bits as a number.
+
+This module can be used with `#[serde(with = "bitflags::serde::compat_numeric")]` on a field of a
+flags type to migrate human-readable documents that stored flags as numbers, like `6`, to the
+default format of text, like `"B | C"`. Both shapes are accepted when deserializing, but flags are
+always serialized in the same way as the default `serde` support, so documents are migrated as
+they're written back.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#         const C = 1 << 2;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::compat_numeric")]
+    flags: Flags,
+}
+
+let old: Config = serde_json::from_str(r#"{"flags":6}"#)?;
+let new: Config = serde_json::from_str(r#"{"flags":"B | C"}"#)?;
+
+assert_eq!(Flags::B | Flags::C, old.flags);
+assert_eq!(Flags::B | Flags::C, new.flags);
+
+assert_eq!(r#"{"flags":"B | C"}"#, serde_json::to_string(&old)?);
+# Ok::<(), serde_json::Error>(())
+```
+
+Numbers are converted through `from_bits_retain`, so any unknown bits are retained. Numbers that
+don't fit in the bits type, like `256` for a flags type with `u8` bits, are an error.
+
+Accepting both shapes relies on [`Deserializer::deserialize_any`], which self-describing formats
+like JSON support.
+*/
+
+use crate::{
+    parser::{self, ParseHex, WriteHex},
+    Flags,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, IntoDeserializer, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/**
+Serialize a set of flags as a human-readable string or their underlying bits.
+
+This is the same as [`serde::serialize`](crate::serde::serialize), so numbers are never written.
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + Serialize,
+{
+    crate::serde::serialize(flags, serializer)
+}
+
+/**
+Deserialize a set of flags from a human-readable string or number, or their underlying bits.
+
+Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+{
+    struct FlagsVisitor<B>(PhantomData<B>);
+
+    impl<'de, B: Flags> FlagsVisitor<B>
+    where
+        B::Bits: Deserialize<'de>,
+    {
+        // Deserializing the bits from the number checks that it fits in the bits type
+        fn from_number<E: Error, N: IntoDeserializer<'de, E>>(number: N) -> Result<B, E> {
+            B::Bits::deserialize(number.into_deserializer()).map(B::from_bits_retain)
+        }
+    }
+
+    impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
+    where
+        B::Bits: ParseHex + Deserialize<'de>,
+    {
+        type Value = B;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string value of `|` separated flags, or a number of bits")
+        }
+
+        fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
+            parser::from_str(flags).map_err(|e| E::custom(e.display_in(flags)))
+        }
+
+        fn visit_u64<E: Error>(self, bits: u64) -> Result<Self::Value, E> {
+            Self::from_number(bits)
+        }
+
+        fn visit_i64<E: Error>(self, bits: i64) -> Result<Self::Value, E> {
+            Self::from_number(bits)
+        }
+
+        fn visit_u128<E: Error>(self, bits: u128) -> Result<Self::Value, E> {
+            Self::from_number(bits)
+        }
+
+        fn visit_i128<E: Error>(self, bits: i128) -> Result<Self::Value, E> {
+            Self::from_number(bits)
+        }
+    }
+
+    // Deserialize human-readable flags by parsing them from strings like `"A | B"`,
+    // or from numbers like `3`
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(FlagsVisitor(PhantomData))
+    }
+    // Deserialize non-human-readable flags directly from the underlying bits
+    else {
+        crate::serde::as_bits::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token::*,
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+            const C = 1 << 2;
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Signed: i8 {
+            const A = 1;
+            const MIN = i8::MIN;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct Compat(#[serde(with = "crate::serde::compat_numeric")] Flags);
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct CompatSigned(#[serde(with = "crate::serde::compat_numeric")] Signed);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&Compat(Flags::empty()).readable(), &[Str("")]);
+        assert_tokens(&Compat(Flags::empty()).compact(), &[U8(0)]);
+
+        assert_tokens(&Compat(Flags::B | Flags::C).readable(), &[Str("B | C")]);
+        assert_tokens(
+            &Compat(Flags::B | Flags::C).compact(),
+            &[U8(1 << 1 | 1 << 2)],
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_de_tokens(&Compat(Flags::B | Flags::C).readable(), &[U64(6)]);
+        assert_de_tokens(&Compat(Flags::B | Flags::C).readable(), &[I64(6)]);
+        assert_de_tokens(&Compat(Flags::B | Flags::C).readable(), &[U8(6)]);
+        assert_de_tokens(&Compat(Flags::empty()).readable(), &[U64(0)]);
+
+        // Unknown bits are retained
+        assert_de_tokens(
+            &Compat(Flags::A | Flags::from_bits_retain(1 << 7)).readable(),
+            &[U64(1 | 1 << 7)],
+        );
+
+        assert_de_tokens(&CompatSigned(Signed::MIN).readable(), &[I64(-128)]);
+        assert_de_tokens(
+            &CompatSigned(Signed::from_bits_retain(-1)).readable(),
+            &[I8(-1)],
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert_de_tokens_error::<Readable<Compat>>(
+            &[U64(1 << 8)],
+            "invalid value: integer `256`, expected u8",
+        );
+        assert_de_tokens_error::<Readable<Compat>>(
+            &[I64(-1)],
+            "invalid value: integer `-1`, expected u8",
+        );
+        assert_de_tokens_error::<Readable<CompatSigned>>(
+            &[U64(1 << 7)],
+            "invalid value: integer `128`, expected i8",
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<Readable<Compat>>(&[Str("A | | B")], "encountered empty flag");
+        assert_de_tokens_error::<Readable<Compat>>(
+            &[Bool(true)],
+            "invalid type: boolean `true`, expected a string value of `|` separated flags, or a number of bits",
+        );
+    }
+
+    #[test]
+    fn migrate() {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        struct Document {
+            #[serde(with = "crate::serde::compat_numeric")]
+            flags: Flags,
+        }
+
+        let old: Document = serde_json::from_str(r#"{"flags":6}"#).unwrap();
+        let new: Document = serde_json::from_str(r#"{"flags":"B | C"}"#).unwrap();
+
+        assert_eq!(Flags::B | Flags::C, old.flags);
+        assert_eq!(old, new);
+
+        // Documents are always written back as text
+        let json = serde_json::to_string(&old).unwrap();
+        assert_eq!(r#"{"flags":"B | C"}"#, json);
+        assert_eq!(old, serde_json::from_str(&json).unwrap());
+
+        assert!(serde_json::from_str::<Document>(r#"{"flags":256}"#).is_err());
+        assert!(serde_json::from_str::<Document>(r#"{"flags":-1}"#).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+/*!
+Deserialize flags from text without regard to the ASCII case of their names.
+
+This module can be used with `#[serde(with = "bitflags::serde::ignore_case")]` on a field of a
+flags type to accept strings like `"read | Write"` for flags named `READ` and `WRITE`. Flags are
+still serialized in the same way as the default `serde` support, so their names keep their case.
+Non-human-readable formats use the underlying bits, which aren't affected.
+
+Names are matched in the same way as [`parser::from_str_ignore_case`]. A name that exactly matches
+a defined flag is always used. A name that matches multiple flags when ignoring case is an error.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const READ = 1;
+#         const WRITE = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::ignore_case")]
+    flags: Flags,
+}
+
+let config: Config = serde_json::from_str(r#"{"flags":"read | Write"}"#)?;
+
+assert_eq!(Flags::READ | Flags::WRITE, config.flags);
+assert_eq!(r#"{"flags":"READ | WRITE"}"#, serde_json::to_string(&config)?);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::{
+    parser::{self, ParseErrorKind, ParseHex, WriteHex},
+    Flags,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/**
+Serialize a set of flags as a human-readable string or their underlying bits.
+
+This is the same as [`serde::serialize`](crate::serde::serialize).
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + Serialize,
+{
+    crate::serde::serialize(flags, serializer)
+}
+
+/**
+Deserialize a set of flags from a human-readable string, ignoring the ASCII case of names, or
+their underlying bits.
+
+Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+{
+    struct FlagsVisitor<B>(PhantomData<B>);
+
+    impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
+    where
+        B::Bits: ParseHex,
+    {
+        type Value = B;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string value of `|` separated flags")
+        }
+
+        fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
+            parser::from_str_ignore_case(flags).map_err(|e| match e.kind() {
+                // Name the flags an ambiguous name could refer to
+                ParseErrorKind::AmbiguousNamedFlag => match e.token(flags) {
+                    Some(name) => E::custom(Ambiguous::<B>(name, PhantomData)),
+                    None => E::custom(e.display_in(flags)),
+                },
+                _ => E::custom(e.display_in(flags)),
+            })
+        }
+    }
+
+    // Deserialize human-readable flags by parsing them from strings like `"A | B"`
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(FlagsVisitor(PhantomData))
+    }
+    // Deserialize non-human-readable flags directly from the underlying bits
+    else {
+        crate::serde::as_bits::deserialize(deserializer)
+    }
+}
+
+struct Ambiguous<'a, B>(&'a str, PhantomData<B>);
+
+impl<'a, B: Flags> fmt::Display for Ambiguous<'a, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ambiguous named flag `{}` could be", self.0)?;
+
+        let mut first = true;
+        for flag in B::FLAGS.iter().chain(B::ALIASES) {
+            if flag.is_unnamed() || !flag.name().eq_ignore_ascii_case(self.0) {
+                continue;
+            }
+
+            if !first {
+                f.write_str(" or")?;
+            }
+
+            first = false;
+            write!(f, " `{}`", flag.name())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token::*,
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const READ = 1;
+            const WRITE = 1 << 1;
+            const Exec = 1 << 2;
+            const EXEC = 1 << 3;
+            const RUN = 1 << 4;
+            #[bitflags(alias = "Run")]
+            const START = 1 << 5;
+            #[bitflags(alias = "WRITABLE")]
+            const MODIFY = 1 << 6;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct IgnoreCase(#[serde(with = "crate::serde::ignore_case")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&IgnoreCase(Flags::empty()).readable(), &[Str("")]);
+        assert_tokens(&IgnoreCase(Flags::empty()).compact(), &[U8(0)]);
+
+        assert_tokens(
+            &IgnoreCase(Flags::READ | Flags::WRITE).readable(),
+            &[Str("READ | WRITE")],
+        );
+        assert_tokens(
+            &IgnoreCase(Flags::READ | Flags::WRITE).compact(),
+            &[U8(1 | 1 << 1)],
+        );
+    }
+
+    #[test]
+    fn mixed_case() {
+        for input in ["read | write", "READ|WRITE", "Read | wRiTe", "read | 0x2"] {
+            assert_de_tokens(
+                &IgnoreCase(Flags::READ | Flags::WRITE).readable(),
+                &[Str(input)],
+            );
+        }
+    }
+
+    #[test]
+    fn aliases() {
+        assert_de_tokens(
+            &IgnoreCase(Flags::READ | Flags::MODIFY).readable(),
+            &[Str("read | writable")],
+        );
+    }
+
+    #[test]
+    fn exact_match() {
+        // Exact matches are used even though they're ambiguous when ignoring case
+        assert_de_tokens(&IgnoreCase(Flags::Exec).readable(), &[Str("Exec")]);
+        assert_de_tokens(&IgnoreCase(Flags::EXEC).readable(), &[Str("EXEC")]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<Readable<IgnoreCase>>(
+            &[Str("READ | exec")],
+            "ambiguous named flag `exec` could be `Exec` or `EXEC`",
+        );
+        assert_de_tokens_error::<Readable<IgnoreCase>>(
+            &[Str("run")],
+            "ambiguous named flag `run` could be `RUN` or `Run`",
+        );
+
+        assert_de_tokens_error::<Readable<IgnoreCase>>(
+            &[Str("READ | BOGUS")],
+            "unrecognized named flag `BOGUS`",
+        );
+        assert_de_tokens_error::<Readable<IgnoreCase>>(&[Str("3")], "unrecognized named flag `3`");
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            r#""READ | WRITE""#,
+            serde_json::to_string(&IgnoreCase(Flags::READ | Flags::WRITE)).unwrap()
+        );
+        assert_eq!(
+            IgnoreCase(Flags::READ | Flags::WRITE),
+            serde_json::from_str(r#""read | Write""#).unwrap()
+        );
+        assert!(serde_json::from_str::<IgnoreCase>(r#""exec""#).is_err());
+    }
+}
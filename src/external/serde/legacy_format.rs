@@ -0,0 +1,364 @@
+/*!
+Serialize flags in the format used by `bitflags` 1.x.
+
+Flags types generated by `bitflags` 1.x that derived `Serialize` and `Deserialize` were
+represented as a struct with a single `bits` field, like `{"bits":3}` in JSON. This module can be
+used with `#[serde(with = "bitflags::serde::legacy_format")]` on a field of a flags type to keep
+reading and writing data in that format after upgrading.
+
+The `bits` field may be identified by its name or by its index, `0`, so formats that identify
+the fields of structs by their index are supported.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::legacy_format")]
+    flags: Flags,
+}
+
+let config = Config { flags: Flags::A | Flags::B };
+
+assert_eq!(r#"{"flags":{"bits":3}}"#, serde_json::to_string(&config)?);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::Flags;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+const FIELDS: &[&str] = &["bits"];
+
+/**
+Serialize a set of flags as a struct with a `bits` field.
+
+The struct is named after the flags type without its module path, like `Flags`, which is the
+same name `#[derive(Serialize)]` used in `bitflags` 1.x. Use [`serialize_named`] to pick the name
+instead. Any unknown bits will be retained.
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: Serialize,
+{
+    serialize_named(flags, type_name::<B>(), serializer)
+}
+
+/**
+Serialize a set of flags as a struct with a `bits` field, using the given name for the struct.
+
+This is useful for formats that encode the name of structs when the flags type has been renamed
+since it was serialized with `bitflags` 1.x. Any unknown bits will be retained.
+*/
+pub fn serialize_named<B: Flags, S: Serializer>(
+    flags: &B,
+    name: &'static str,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    B::Bits: Serialize,
+{
+    let mut serializer = serializer.serialize_struct(name, 1)?;
+    serializer.serialize_field("bits", &flags.bits())?;
+    serializer.end()
+}
+
+/**
+Deserialize a set of flags from a struct with a `bits` field.
+
+The struct is expected to be named after the flags type without its module path, like `Flags`.
+Use [`deserialize_named`] to pick the name instead. Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: Deserialize<'de>,
+{
+    deserialize_named(type_name::<B>(), deserializer)
+}
+
+/**
+Deserialize a set of flags from a struct with a `bits` field, using the given name for the struct.
+
+Any unknown bits will be retained.
+*/
+pub fn deserialize_named<'de, B: Flags, D: Deserializer<'de>>(
+    name: &'static str,
+    deserializer: D,
+) -> Result<B, D::Error>
+where
+    B::Bits: Deserialize<'de>,
+{
+    deserializer.deserialize_struct(name, FIELDS, BitsVisitor(PhantomData))
+}
+
+// The name of a type without its module path or generic arguments, like `Flags`
+fn type_name<T: ?Sized>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+struct BitsVisitor<B>(PhantomData<B>);
+
+impl<'de, B: Flags> Visitor<'de> for BitsVisitor<B>
+where
+    B::Bits: Deserialize<'de>,
+{
+    type Value = B;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a struct with a `bits` field")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let bits = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+
+        Ok(B::from_bits_retain(bits))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut bits = None;
+
+        while let Some(Field) = map.next_key()? {
+            if bits.is_some() {
+                return Err(A::Error::duplicate_field("bits"));
+            }
+
+            bits = Some(map.next_value()?);
+        }
+
+        let bits = bits.ok_or_else(|| A::Error::missing_field("bits"))?;
+
+        Ok(B::from_bits_retain(bits))
+    }
+}
+
+// The `bits` field, identified by either its name or its index
+struct Field;
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("the `bits` field")
+            }
+
+            fn visit_u64<E: Error>(self, index: u64) -> Result<Self::Value, E> {
+                match index {
+                    0 => Ok(Field),
+                    _ => Err(E::unknown_field(fmt_index(index, &mut [0; 20]), FIELDS)),
+                }
+            }
+
+            fn visit_str<E: Error>(self, name: &str) -> Result<Self::Value, E> {
+                match name {
+                    "bits" => Ok(Field),
+                    _ => Err(E::unknown_field(name, FIELDS)),
+                }
+            }
+
+            fn visit_bytes<E: Error>(self, name: &[u8]) -> Result<Self::Value, E> {
+                match name {
+                    b"bits" => Ok(Field),
+                    _ => Err(E::unknown_field(
+                        core::str::from_utf8(name).unwrap_or("\u{fffd}"),
+                        FIELDS,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+// Format an unknown field index without allocating, so it can be reported as an unknown field
+fn fmt_index(mut index: u64, buf: &mut [u8; 20]) -> &str {
+    let mut start = buf.len();
+
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (index % 10) as u8;
+        index /= 10;
+
+        if index == 0 {
+            break;
+        }
+    }
+
+    core::str::from_utf8(&buf[start..]).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token::*};
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct Legacy(#[serde(with = "crate::serde::legacy_format")] Flags);
+
+    const NAME: &str = "Flags";
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(
+            &Legacy(Flags::A | Flags::from_bits_retain(0xf0)),
+            &[
+                Struct { name: NAME, len: 1 },
+                Str("bits"),
+                U8(0xf1),
+                StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn indexed() {
+        assert_de_tokens(
+            &Legacy(Flags::A | Flags::B),
+            &[Struct { name: NAME, len: 1 }, U64(0), U8(3), StructEnd],
+        );
+
+        assert_de_tokens(
+            &Legacy(Flags::A | Flags::B),
+            &[
+                Seq {
+                    len: Option::Some(1),
+                },
+                U8(3),
+                SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn bincode() {
+        let config = bincode::config::standard();
+
+        let bytes = bincode::serde::encode_to_vec(Legacy(Flags::A | Flags::B), config).unwrap();
+        assert_eq!(vec![3], bytes);
+
+        let (decoded, _) = bincode::serde::decode_from_slice::<Legacy, _>(&bytes, config).unwrap();
+        assert_eq!(Legacy(Flags::A | Flags::B), decoded);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<Legacy>(
+            &[Struct { name: NAME, len: 1 }, U64(1)],
+            "unknown field `1`, expected `bits`",
+        );
+
+        assert_de_tokens_error::<Legacy>(
+            &[Struct { name: NAME, len: 1 }, Str("flags")],
+            "unknown field `flags`, expected `bits`",
+        );
+
+        assert_de_tokens_error::<Legacy>(
+            &[Struct { name: NAME, len: 0 }, StructEnd],
+            "missing field `bits`",
+        );
+    }
+
+    #[test]
+    fn named() {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+        #[serde(transparent)]
+        struct Renamed(
+            #[serde(
+                serialize_with = "serialize_old_name",
+                deserialize_with = "deserialize_old_name"
+            )]
+            Flags,
+        );
+
+        fn serialize_old_name<S: serde::Serializer>(
+            flags: &Flags,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            crate::serde::legacy_format::serialize_named(flags, "OldFlags", serializer)
+        }
+
+        fn deserialize_old_name<'de, D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Flags, D::Error> {
+            crate::serde::legacy_format::deserialize_named("OldFlags", deserializer)
+        }
+
+        assert_tokens(
+            &Renamed(Flags::A),
+            &[
+                Struct {
+                    name: "OldFlags",
+                    len: 1,
+                },
+                Str("bits"),
+                U8(1),
+                StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn v1_compat() {
+        // The representation `#[derive(Serialize, Deserialize)]` generated in `bitflags` 1.x
+        mod v1 {
+            #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+            pub struct Flags {
+                pub bits: u8,
+            }
+        }
+
+        let tokens = [Struct { name: NAME, len: 1 }, Str("bits"), U8(3), StructEnd];
+
+        assert_tokens(&v1::Flags { bits: 3 }, &tokens);
+        assert_tokens(&Legacy(Flags::A | Flags::B), &tokens);
+    }
+
+    #[test]
+    fn type_name() {
+        assert_eq!("Flags", super::type_name::<Flags>());
+        assert_eq!("Legacy", super::type_name::<Legacy>());
+        assert_eq!("Option", super::type_name::<Option<Flags>>());
+        assert_eq!("u8", super::type_name::<u8>());
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            r#"{"bits":3}"#,
+            serde_json::to_string(&Legacy(Flags::A | Flags::B)).unwrap()
+        );
+        assert_eq!(
+            Legacy(Flags::B),
+            serde_json::from_str(r#"{"bits":2}"#).unwrap()
+        );
+        assert!(serde_json::from_str::<Legacy>(r#""B""#).is_err());
+    }
+}
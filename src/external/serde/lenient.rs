@@ -0,0 +1,225 @@
+/*!
+Deserialize flags from text, ignoring any names that aren't defined.
+
+This module can be used with `#[serde(with = "bitflags::serde::lenient")]` on a field of a
+flags type so it can be read from text written by a newer version of the flags type that defines
+more flags. Flags are serialized in the same way as the default `serde` support. Non-human-readable
+formats use the underlying bits, which retain any unknown bits anyway.
+
+Only unknown names are ignored. Text that can't be parsed at all, like an empty flag in `"A | | B"`
+or an invalid hex number, is still an error.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::lenient")]
+    flags: Flags,
+}
+
+let config: Config = serde_json::from_str(r#"{"flags":"A | B | FUTURE"}"#)?;
+
+assert_eq!(Flags::A | Flags::B, config.flags);
+# Ok::<(), serde_json::Error>(())
+```
+
+Use [`deserialize_with`] to find out which names were ignored, like to log them.
+*/
+
+use crate::{
+    parser::{self, ParseHex, WriteHex},
+    Flags,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/**
+Serialize a set of flags as a human-readable string or their underlying bits.
+
+This is the same as [`serde::serialize`](crate::serde::serialize).
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + Serialize,
+{
+    crate::serde::serialize(flags, serializer)
+}
+
+/**
+Deserialize a set of flags from a human-readable string, ignoring unknown names, or their
+underlying bits.
+
+Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+{
+    deserialize_with(deserializer, |_| {})
+}
+
+/**
+Deserialize a set of flags from a human-readable string, ignoring unknown names, or their
+underlying bits.
+
+This function is like [`deserialize`], except `unknown` is called with each name that's ignored.
+
+```
+# use serde::de::IntoDeserializer;
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+let mut ignored = Vec::new();
+
+let deserializer = serde_json::Value::from("A | FUTURE | B").into_deserializer();
+let flags: Flags = bitflags::serde::lenient::deserialize_with(deserializer, |name| {
+    ignored.push(name.to_owned())
+})?;
+
+assert_eq!(Flags::A | Flags::B, flags);
+assert_eq!(vec!["FUTURE"], ignored);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+pub fn deserialize_with<'de, B: Flags, D: Deserializer<'de>>(
+    deserializer: D,
+    unknown: impl FnMut(&str),
+) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+{
+    struct FlagsVisitor<B, F>(F, PhantomData<B>);
+
+    impl<'de, B: Flags, F: FnMut(&str)> Visitor<'de> for FlagsVisitor<B, F>
+    where
+        B::Bits: ParseHex,
+    {
+        type Value = B;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string value of `|` separated flags")
+        }
+
+        fn visit_str<E: Error>(mut self, flags: &str) -> Result<Self::Value, E> {
+            parser::parse(flags, '|', |flag| {
+                Ok(B::from_name(flag).unwrap_or_else(|| {
+                    (self.0)(flag);
+                    B::empty()
+                }))
+            })
+            .map_err(|e| E::custom(e.display_in(flags)))
+        }
+    }
+
+    // Deserialize human-readable flags by parsing them from strings like `"A | B"`
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(FlagsVisitor(unknown, PhantomData))
+    }
+    // Deserialize non-human-readable flags directly from the underlying bits
+    else {
+        crate::serde::as_bits::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token::*,
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct Lenient(#[serde(with = "crate::serde::lenient")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&Lenient(Flags::empty()).readable(), &[Str("")]);
+        assert_tokens(&Lenient(Flags::empty()).compact(), &[U8(0)]);
+
+        assert_tokens(&Lenient(Flags::A | Flags::B).readable(), &[Str("A | B")]);
+        assert_tokens(&Lenient(Flags::A | Flags::B).compact(), &[U8(1 | 1 << 1)]);
+    }
+
+    #[test]
+    fn unknown() {
+        assert_de_tokens(
+            &Lenient(Flags::A | Flags::B).readable(),
+            &[Str("A | B | FUTURE")],
+        );
+        assert_de_tokens(&Lenient(Flags::empty()).readable(), &[Str("FUTURE")]);
+
+        // Unknown bits are still retained
+        assert_de_tokens(
+            &Lenient(Flags::A | Flags::from_bits_retain(1 << 7)).readable(),
+            &[Str("FUTURE | A | 0x80")],
+        );
+        assert_de_tokens(
+            &Lenient(Flags::from_bits_retain(1 << 7)).compact(),
+            &[U8(1 << 7)],
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<Readable<Lenient>>(
+            &[Str("A | | FUTURE")],
+            "encountered empty flag",
+        );
+
+        assert_de_tokens_error::<Readable<Lenient>>(&[Str("A | 0xg")], "invalid hex flag `0xg`");
+    }
+
+    #[test]
+    fn forward_compatible() {
+        bitflags! {
+            #[derive(serde_derive::Serialize, Debug, PartialEq, Eq)]
+            #[serde(transparent)]
+            struct NewerFlags: u8 {
+                const A = 1;
+                const B = 1 << 1;
+                const FUTURE = 1 << 2;
+            }
+        }
+
+        let json =
+            serde_json::to_string(&(NewerFlags::A | NewerFlags::B | NewerFlags::FUTURE)).unwrap();
+        assert_eq!(r#""A | B | FUTURE""#, json);
+
+        assert_eq!(
+            Lenient(Flags::A | Flags::B),
+            serde_json::from_str(&json).unwrap()
+        );
+
+        let mut ignored = Vec::new();
+        let flags: Flags = crate::serde::lenient::deserialize_with(
+            &mut serde_json::Deserializer::from_str(&json),
+            |name| ignored.push(name.to_owned()),
+        )
+        .unwrap();
+
+        assert_eq!(Flags::A | Flags::B, flags);
+        assert_eq!(vec!["FUTURE"], ignored);
+    }
+}
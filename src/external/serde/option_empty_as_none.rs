@@ -0,0 +1,207 @@
+/*!
+Serialize an optional set of flags, treating an empty string as `None`.
+
+This module can be used with `#[serde(with = "bitflags::serde::option_empty_as_none")]` on an
+`Option` field of a flags type. `None` is serialized as a null value and `Some` in the same way as
+the default `serde` support. When deserializing from a human-readable format, both a null value and
+an empty string like `""` become `None`.
+
+This isn't symmetric: `Some(Flags::empty())` is serialized as `""` in human-readable formats, so it
+deserializes back as `None`. Non-human-readable formats use the underlying bits, so `Some(Flags::empty())`
+roundtrips through them.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::option_empty_as_none")]
+    flags: Option<Flags>,
+}
+
+let config: Config = serde_json::from_str(r#"{"flags":""}"#)?;
+assert_eq!(None, config.flags);
+
+let config: Config = serde_json::from_str(r#"{"flags":"A | B"}"#)?;
+assert_eq!(Some(Flags::A | Flags::B), config.flags);
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::{
+    parser::{self, ParseHex, WriteHex},
+    Flags,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/**
+Serialize an optional set of flags as a null value, or a human-readable string or the underlying bits.
+
+Any unknown bits will be retained.
+*/
+pub fn serialize<B: Flags, S: Serializer>(
+    flags: &Option<B>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + Serialize,
+{
+    struct AsSerialize<'a, B>(&'a B);
+
+    impl<'a, B: Flags> Serialize for AsSerialize<'a, B>
+    where
+        B::Bits: WriteHex + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            crate::serde::serialize(self.0, serializer)
+        }
+    }
+
+    match flags {
+        Some(flags) => serializer.serialize_some(&AsSerialize(flags)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/**
+Deserialize an optional set of flags from a null value, or a human-readable string or the underlying bits.
+
+An empty human-readable string is deserialized as `None`. Any unknown bits will be retained.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<B>, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+{
+    struct OptionVisitor<B>(PhantomData<B>);
+
+    impl<'de, B: Flags> Visitor<'de> for OptionVisitor<B>
+    where
+        B::Bits: ParseHex + Deserialize<'de>,
+    {
+        type Value = Option<B>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an optional string value of `|` separated flags")
+        }
+
+        fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            // Deserialize human-readable flags by parsing them from strings like `"A | B"`
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(FlagsVisitor(PhantomData))
+            }
+            // Deserialize non-human-readable flags directly from the underlying bits
+            else {
+                crate::serde::as_bits::deserialize(deserializer).map(Some)
+            }
+        }
+    }
+
+    struct FlagsVisitor<B>(PhantomData<B>);
+
+    impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
+    where
+        B::Bits: ParseHex,
+    {
+        type Value = Option<B>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string value of `|` separated flags")
+        }
+
+        fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
+            if flags.trim().is_empty() {
+                return Ok(None);
+            }
+
+            parser::from_str(flags)
+                .map(Some)
+                .map_err(|e| E::custom(e.display_in(flags)))
+        }
+    }
+
+    deserializer.deserialize_option(OptionVisitor(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token::*};
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct EmptyAsNone(#[serde(with = "crate::serde::option_empty_as_none")] Option<Flags>);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&EmptyAsNone(Option::None).readable(), &[None]);
+        assert_tokens(&EmptyAsNone(Option::None).compact(), &[None]);
+
+        assert_tokens(
+            &EmptyAsNone(Option::Some(Flags::A | Flags::B)).readable(),
+            &[Some, Str("A | B")],
+        );
+        assert_tokens(
+            &EmptyAsNone(Option::Some(Flags::A | Flags::B)).compact(),
+            &[Some, U8(1 | 1 << 1)],
+        );
+
+        // An empty flags value only roundtrips through the underlying bits
+        assert_tokens(
+            &EmptyAsNone(Option::Some(Flags::empty())).compact(),
+            &[Some, U8(0)],
+        );
+        assert_de_tokens(&EmptyAsNone(Option::None).readable(), &[Some, Str("")]);
+    }
+
+    #[test]
+    fn json() {
+        for (flags, json) in [
+            (Option::None, "null"),
+            (Option::Some(Flags::empty()), r#""""#),
+            (Option::Some(Flags::A | Flags::B), r#""A | B""#),
+        ] {
+            assert_eq!(json, serde_json::to_string(&EmptyAsNone(flags)).unwrap());
+        }
+
+        for (json, flags) in [
+            ("null", Option::None),
+            (r#""""#, Option::None),
+            (r#""0x0""#, Option::Some(Flags::empty())),
+            (r#""A | B""#, Option::Some(Flags::A | Flags::B)),
+        ] {
+            assert_eq!(EmptyAsNone(flags), serde_json::from_str(json).unwrap());
+        }
+
+        assert!(serde_json::from_str::<EmptyAsNone>(r#""A | BOGUS""#).is_err());
+    }
+}
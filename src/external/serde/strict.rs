@@ -0,0 +1,127 @@
+/*!
+Deserialize flags, rejecting any unknown bits.
+
+This module can be used with `#[serde(with = "bitflags::serde::strict")]` on a field of a
+flags type so data from an untrusted source can't set bits that don't correspond to any defined
+flag. Flags are serialized in the same way as the default `serde` support.
+
+Deserializing underlying bits from a non-human-readable format fails if they contain any unknown
+bits, instead of retaining them. Deserializing text from a human-readable format fails if any hex
+numbers in it contain unknown bits. The error names the unknown bits, like
+`unknown bits: 0x80 (bit 7)`.
+
+```
+# use serde_derive::{Serialize, Deserialize};
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     pub struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bitflags::serde::strict")]
+    flags: Flags,
+}
+
+let config: Config = serde_json::from_str(r#"{"flags":"A | 0x2"}"#)?;
+assert_eq!(Flags::A | Flags::B, config.flags);
+
+assert!(serde_json::from_str::<Config>(r#"{"flags":"A | 0x80"}"#).is_err());
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use crate::{
+    parser::{ParseHex, WriteHex},
+    Flags, UnknownBits,
+};
+use core::fmt;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/**
+Serialize a set of flags as a human-readable string or their underlying bits.
+
+This is the same as [`serde::serialize`](crate::serde::serialize).
+*/
+pub fn serialize<B: Flags, S: Serializer>(flags: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + Serialize,
+{
+    crate::serde::serialize(flags, serializer)
+}
+
+/**
+Deserialize a set of flags from a human-readable string or their underlying bits.
+
+Any unknown bits will fail deserialization.
+*/
+pub fn deserialize<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+    UnknownBits<B::Bits>: fmt::Display,
+{
+    let flags: B = crate::serde::deserialize(deserializer)?;
+
+    B::from_bits_strict(flags.bits()).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token::*,
+    };
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    #[serde(transparent)]
+    struct Strict(#[serde(with = "crate::serde::strict")] Flags);
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&Strict(Flags::empty()).readable(), &[Str("")]);
+        assert_tokens(&Strict(Flags::empty()).compact(), &[U8(0)]);
+
+        assert_tokens(&Strict(Flags::A | Flags::B).readable(), &[Str("A | B")]);
+        assert_tokens(&Strict(Flags::A | Flags::B).compact(), &[U8(1 | 1 << 1)]);
+    }
+
+    #[test]
+    fn unknown() {
+        assert_de_tokens_error::<Compact<Strict>>(&[U8(0x81)], "unknown bits: 0x80 (bit 7)");
+
+        assert_de_tokens_error::<Readable<Strict>>(
+            &[Str("A | 0x84")],
+            "unknown bits: 0x84 (bits 2, 7)",
+        );
+
+        assert_de_tokens_error::<Readable<Strict>>(
+            &[Str("BOGUS")],
+            "unrecognized named flag `BOGUS`",
+        );
+    }
+
+    #[test]
+    fn bincode() {
+        let config = bincode::config::standard();
+
+        let bytes = bincode::serde::encode_to_vec(Strict(Flags::A | Flags::B), config).unwrap();
+        let (decoded, _) = bincode::serde::decode_from_slice::<Strict, _>(&bytes, config).unwrap();
+        assert_eq!(Strict(Flags::A | Flags::B), decoded);
+
+        // A payload with unknown bits is an error instead of being retained or truncated
+        let bytes = bincode::serde::encode_to_vec(0xf1u8, config).unwrap();
+        let err = bincode::serde::decode_from_slice::<Strict, _>(&bytes, config).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unknown bits: 0xf0 (bits 4, 5, 6, 7)"));
+    }
+}
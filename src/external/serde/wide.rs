@@ -0,0 +1,150 @@
+// `WideBits` is serialized as a tuple of its words, from the least significant word up
+
+use crate::wide::WideBits;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl<const N: usize> Serialize for WideBits<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializer = serializer.serialize_tuple(N)?;
+
+        for word in self.words().iter() {
+            serializer.serialize_element(word)?;
+        }
+
+        serializer.end()
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for WideBits<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct WordsVisitor<const N: usize>(PhantomData<WideBits<N>>);
+
+        impl<'de, const N: usize> Visitor<'de> for WordsVisitor<N> {
+            type Value = WideBits<N>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a tuple of {} u64 words", N)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut words = [0; N];
+
+                for (i, word) in words.iter_mut().enumerate() {
+                    *word = seq
+                        .next_element()?
+                        .ok_or_else(|| Error::invalid_length(i, &self))?;
+                }
+
+                Ok(WideBits::from_words(words))
+            }
+        }
+
+        deserializer.deserialize_tuple(N, WordsVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token::*};
+
+    use crate::{wide::WideBits, Flag, Flags};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Caps(WideBits<3>);
+
+    impl Caps {
+        const A: Self = Caps(WideBits::bit(0));
+        const B: Self = Caps(WideBits::bit(100));
+    }
+
+    impl Flags for Caps {
+        const FLAGS: &'static [Flag<Self>] = &[Flag::new("A", Caps::A), Flag::new("B", Caps::B)];
+
+        type Bits = WideBits<3>;
+
+        fn bits(&self) -> WideBits<3> {
+            self.0
+        }
+
+        fn from_bits_retain(bits: WideBits<3>) -> Self {
+            Caps(bits)
+        }
+    }
+
+    impl serde::Serialize for Caps {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            crate::serde::serialize(self, serializer)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Caps {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            crate::serde::deserialize(deserializer)
+        }
+    }
+
+    #[test]
+    fn tokens() {
+        assert_tokens(
+            &WideBits::from_words([1, 0, 1 << 63]),
+            &[Tuple { len: 3 }, U64(1), U64(0), U64(1 << 63), TupleEnd],
+        );
+
+        assert_tokens(
+            &Caps::A
+                .union(Caps::B)
+                .union(Caps(WideBits::bit(191)))
+                .compact(),
+            &[
+                Tuple { len: 3 },
+                U64(1),
+                U64(1 << 36),
+                U64(1 << 63),
+                TupleEnd,
+            ],
+        );
+
+        assert_tokens(
+            &Caps::A
+                .union(Caps::B)
+                .union(Caps(WideBits::bit(191)))
+                .readable(),
+            &[Str(
+                "A | B | 0x800000000000000000000000000000000000000000000000",
+            )],
+        );
+    }
+
+    #[test]
+    fn too_short() {
+        assert_de_tokens_error::<WideBits<3>>(
+            &[Tuple { len: 2 }, U64(1), U64(0), TupleEnd],
+            "invalid length 2, expected a tuple of 3 u64 words",
+        );
+    }
+
+    #[test]
+    fn json() {
+        let caps = Caps::A.union(Caps(WideBits::bit(64)));
+
+        assert_eq!("[1,1,0]", serde_json::to_string(&caps.0).unwrap());
+        assert_eq!(
+            caps.0,
+            serde_json::from_str::<WideBits<3>>("[1,1,0]").unwrap()
+        );
+
+        assert_eq!(
+            r#""A | 0x10000000000000000""#,
+            serde_json::to_string(&caps).unwrap()
+        );
+        assert_eq!(
+            caps,
+            serde_json::from_str::<Caps>(r#""A | 0x10000000000000000""#).unwrap()
+        );
+    }
+}
@@ -0,0 +1,270 @@
+/*!
+Converting flags types to and from JavaScript values using `wasm-bindgen`.
+
+Flags values are converted to a JavaScript number when the bits type fits in one exactly.
+JavaScript numbers can only represent integers up to 53 bits, so 64 and 128 bit flags types
+are converted to a `BigInt` instead. Use [`to_js_string`] to convert flags into a string
+of flag names instead, like `"A | B"`.
+
+The defined flags can be exported to JavaScript as an object with [`constants`]:
+
+```ignore
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name = Flags)]
+pub fn flags() -> js_sys::Object {
+    bitflags::wasm_bindgen::constants::<Flags>()
+}
+```
+
+so JavaScript code can combine them like `Flags.A | Flags.B`.
+*/
+
+use super::__private::alloc::string::{String, ToString};
+use crate::{
+    parser::{self, ParseHex, WriteHex},
+    Bits, Flags,
+};
+use js_sys::{wasm_bindgen::JsValue, Object, Reflect, TypeError};
+
+/**
+A bits type that can be converted to and from a JavaScript value.
+
+Bits types up to 32 bits are converted to a number. 64 and 128 bit types are converted to a
+`BigInt`, but can also be converted from a number, as long as it's an integer that fits.
+*/
+pub trait JsBits: Bits {
+    /// Convert the bits into a JavaScript value.
+    fn to_js(self) -> JsValue;
+
+    /// Convert a JavaScript value into bits.
+    ///
+    /// This method returns `None` if the value isn't an integer, or doesn't fit in the bits type.
+    fn from_js(value: &JsValue) -> Option<Self>;
+}
+
+macro_rules! impl_js_bits_number {
+    ($($bits:ty),*) => {
+        $(
+            impl JsBits for $bits {
+                fn to_js(self) -> JsValue {
+                    JsValue::from_f64(self as f64)
+                }
+
+                fn from_js(value: &JsValue) -> Option<Self> {
+                    from_number(value)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_js_bits_bigint {
+    ($($bits:ty),*) => {
+        $(
+            impl JsBits for $bits {
+                fn to_js(self) -> JsValue {
+                    JsValue::from(self)
+                }
+
+                fn from_js(value: &JsValue) -> Option<Self> {
+                    if value.is_bigint() {
+                        <$bits>::try_from(value.clone()).ok()
+                    } else {
+                        from_number(value)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_js_bits_number!(i8, i16, i32, u8, u16, u32, isize, usize);
+impl_js_bits_bigint!(i64, i128, u64, u128);
+
+fn from_number<T: Copy + TryFrom<i64>>(value: &JsValue) -> Option<T> {
+    // The largest integer a JavaScript number can represent exactly
+    const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+    let number = value.as_f64()?;
+
+    // Reject fractions and unsafe integers, which may have already lost precision
+    if !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&number) || number as i64 as f64 != number {
+        return None;
+    }
+
+    T::try_from(number as i64).ok()
+}
+
+/**
+Convert a flags value into a JavaScript number or `BigInt`.
+
+Unknown bits are converted along with known ones.
+*/
+pub fn to_js<B: Flags>(flags: &B) -> JsValue
+where
+    B::Bits: JsBits,
+{
+    flags.bits().to_js()
+}
+
+/**
+Convert a flags value into a JavaScript string of flag names, like `"A | B"`.
+
+This uses the same format as [`parser::to_writer`].
+*/
+pub fn to_js_string<B: Flags>(flags: &B) -> JsValue
+where
+    B::Bits: WriteHex,
+{
+    let mut s = String::new();
+
+    // Writing to a `String` can't fail
+    let _ = parser::to_writer(flags, &mut s);
+
+    JsValue::from(s)
+}
+
+/**
+Convert a JavaScript value into a flags value.
+
+The value can be a number, a `BigInt`, or a string of flag names like `"A | B"`.
+Unknown bits are retained.
+
+If the value can't be converted then a JavaScript `TypeError` is returned.
+*/
+pub fn from_js<B: Flags>(value: &JsValue) -> Result<B, JsValue>
+where
+    B::Bits: JsBits + ParseHex,
+{
+    if let Some(s) = value.as_string() {
+        return parser::from_str(&s)
+            .map_err(|e| TypeError::new(&e.display_in(&s).to_string()).into());
+    }
+
+    B::Bits::from_js(value)
+        .map(B::from_bits_retain)
+        .ok_or_else(|| {
+            TypeError::new("expected flags as an integer, a bigint, or a string of flag names")
+                .into()
+        })
+}
+
+/**
+Create a frozen JavaScript object with the name and value of each defined flag.
+
+Each value is converted in the same way as [`to_js`]. Unnamed flags are skipped.
+*/
+pub fn constants<B: Flags>() -> Object
+where
+    B::Bits: JsBits,
+{
+    let object = Object::new();
+
+    for flag in B::FLAGS {
+        if flag.is_unnamed() {
+            continue;
+        }
+
+        // Setting a property on a new object can't fail
+        let _ = Reflect::set(
+            &object,
+            &JsValue::from_str(flag.name()),
+            &flag.value().bits().to_js(),
+        );
+    }
+
+    Object::freeze(&object)
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::{wasm_bindgen::JsValue, Reflect};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+            const ALPHA = 0x8000_0000;
+        }
+    }
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Wide: u64 {
+            const A = 0x1;
+            const B = 1 << 63;
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_number_roundtrip() {
+        for color in [
+            Color::empty(),
+            Color::RED | Color::BLUE,
+            Color::all(),
+            Color::from_bits_retain(0xf0),
+        ] {
+            let bits = color.bits();
+            let value = JsValue::from(color);
+
+            assert_eq!(Some(bits as f64), value.as_f64());
+            assert_eq!(
+                Color::from_bits_retain(bits),
+                Color::try_from(value).unwrap()
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_bigint_roundtrip() {
+        let value = JsValue::from(Wide::all());
+
+        assert!(value.is_bigint());
+        assert_eq!(Wide::all(), Wide::try_from(value).unwrap());
+
+        assert_eq!(Wide::A, Wide::try_from(JsValue::from_f64(1.0)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_string() {
+        assert_eq!(
+            Some("RED | BLUE".into()),
+            super::to_js_string(&(Color::RED | Color::BLUE)).as_string()
+        );
+
+        assert_eq!(
+            Color::RED | Color::BLUE,
+            Color::try_from(JsValue::from_str("RED | BLUE")).unwrap()
+        );
+
+        assert!(Color::try_from(JsValue::from_str("PURPLE")).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid() {
+        assert!(Color::try_from(JsValue::from_f64(1.5)).is_err());
+        assert!(Color::try_from(JsValue::from_f64(-1.0)).is_err());
+        assert!(Color::try_from(JsValue::from_f64(f64::NAN)).is_err());
+        assert!(Color::try_from(JsValue::from_f64(4294967296.0)).is_err());
+        assert!(Color::try_from(JsValue::TRUE).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_constants() {
+        let constants = super::constants::<Color>();
+
+        assert_eq!(
+            Some(1.0),
+            Reflect::get(&constants, &"RED".into()).unwrap().as_f64()
+        );
+        assert_eq!(
+            Some(0x8000_0000u32 as f64),
+            Reflect::get(&constants, &"ALPHA".into()).unwrap().as_f64()
+        );
+        assert!(js_sys::Object::is_frozen(&constants));
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Ref};
+
+    bitflags! {
+        #[derive(FromBytes, IntoBytes, Immutable, KnownLayout, Debug, PartialEq, Eq)]
+        #[repr(transparent)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    #[derive(FromBytes, IntoBytes, Immutable, KnownLayout)]
+    #[repr(C)]
+    struct Header {
+        len: u32,
+        color: Color,
+    }
+
+    #[test]
+    fn test_zerocopy() {
+        let bytes = (Color::RED | Color::BLUE).bits().to_ne_bytes();
+
+        let color = Ref::<_, Color>::from_bytes(&bytes[..]).unwrap();
+        assert_eq!(Color::RED | Color::BLUE, *color);
+
+        assert_eq!(&bytes[..], Color::from_bits_retain(0x5).as_bytes());
+    }
+
+    #[test]
+    fn test_zerocopy_unknown_bits() {
+        let bytes = 0xf1u32.to_ne_bytes();
+
+        let color = Color::read_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(Color::from_bits_retain(0xf1), color);
+    }
+
+    #[test]
+    fn test_zerocopy_field() {
+        let mut bytes = [0u8; 8];
+
+        {
+            let mut header = Ref::<_, Header>::from_bytes(&mut bytes[..]).unwrap();
+            header.len = 4;
+            header.color = Color::GREEN;
+        }
+
+        let header = Header::ref_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(4, header.len);
+        assert_eq!(Color::GREEN, header.color);
+        assert_eq!(&bytes[4..], Color::GREEN.bits().as_bytes());
+    }
+}
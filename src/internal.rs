@@ -10,14 +10,30 @@
 #[doc(hidden)]
 macro_rules! __declare_internal_bitflags {
     (
+        options: [$($option:tt)*],
         $vis:vis struct $InternalBitFlags:ident: $T:ty
     ) => {
         // NOTE: The ABI of this type is _guaranteed_ to be the same as `T`
         // This is relied on by some external libraries like `bytemuck` to make
         // its `unsafe` trait impls sound.
-        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        #[repr(transparent)]
-        $vis struct $InternalBitFlags($T);
+        __bitflags_custom_bits! {
+            options: [$($option)*],
+            primitive: {
+                __declare_external_bitflags_zerocopy! {
+                    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                    #[repr(transparent)]
+                    $vis struct $InternalBitFlags($T);
+                }
+            },
+            custom: {
+                // Custom bits types might not implement all of these traits, so the type is generic
+                // over its bits, and only implements the ones its bits do. The bits type is the
+                // default, so `$InternalBitFlags` can still be named without it
+                #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                #[repr(transparent)]
+                $vis struct $InternalBitFlags<B = $T>(B);
+            },
+        }
     };
 }
 
@@ -29,6 +45,7 @@ macro_rules! __declare_internal_bitflags {
 #[doc(hidden)]
 macro_rules! __impl_internal_bitflags {
     (
+        options: [$($option:tt)*],
         $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -36,14 +53,20 @@ macro_rules! __impl_internal_bitflags {
             )*
         }
     ) => {
-        // NOTE: This impl is also used to prevent using bits types from non-primitive types
-        // in the `bitflags` macro. If this approach is changed, this guard will need to be
-        // retained somehow
         impl $crate::__private::PublicFlags for $PublicBitFlags {
-            type Primitive = $T;
             type Internal = $InternalBitFlags;
         }
 
+        // NOTE: This prevents using non-primitive bits types in the `bitflags` macro without
+        // the `custom_bits` option, since the methods generated for them are `const`
+        __bitflags_custom_bits! {
+            options: [$($option)*],
+            primitive: {
+                const _: fn() = $crate::__private::assert_primitive::<$T>;
+            },
+            custom: {},
+        }
+
         impl $crate::__private::core::default::Default for $InternalBitFlags {
             #[inline]
             fn default() -> Self {
@@ -53,19 +76,7 @@ macro_rules! __impl_internal_bitflags {
 
         impl $crate::__private::core::fmt::Debug for $InternalBitFlags {
             fn fmt(&self, f: &mut $crate::__private::core::fmt::Formatter<'_>) -> $crate::__private::core::fmt::Result {
-                if self.is_empty() {
-                    // If no flags are set then write an empty hex flag to avoid
-                    // writing an empty string. In some contexts, like serialization,
-                    // an empty string is preferable, but it may be unexpected in
-                    // others for a format not to produce any output.
-                    //
-                    // We can remove this `0x0` and remain compatible with `FromStr`,
-                    // because an empty string will still parse to an empty set of flags,
-                    // just like `0x0` does.
-                    $crate::__private::core::write!(f, "{:#x}", <$T as $crate::Bits>::EMPTY)
-                } else {
-                    $crate::__private::core::fmt::Display::fmt(self, f)
-                }
+                $crate::__private::core::fmt::Debug::fmt(&$crate::__private::AsDebug(&$PublicBitFlags(*self)), f)
             }
         }
 
@@ -98,6 +109,7 @@ macro_rules! __impl_internal_bitflags {
         // The internal flags type offers a similar API to the public one
 
         __impl_public_bitflags! {
+            options: [$($option)*],
             $InternalBitFlags: $T, $PublicBitFlags {
                 $(
                     $(#[$inner $($args)*])*
@@ -107,10 +119,12 @@ macro_rules! __impl_internal_bitflags {
         }
 
         __impl_public_bitflags_ops! {
+            options: [$($option)*],
             $InternalBitFlags
         }
 
         __impl_public_bitflags_iter! {
+            options: [$($option)*],
             $InternalBitFlags: $T, $PublicBitFlags
         }
 
@@ -0,0 +1,40 @@
+/*!
+Reading and writing flags values as bytes.
+
+Flags types generated by the [`bitflags`](crate::bitflags) macro with the `inherent_methods` option
+have `write_to` and `read_from` methods that write and read exactly as many bytes as their bits type
+has, in the given [`Endian`] byte order. All bits are written and read as-is, so unknown bits roundtrip.
+
+```
+use bitflags::{bitflags, io::Endian};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(inherent_methods)]
+    struct Flags: u16 {
+        const A = 1;
+        const B = 1 << 8;
+    }
+}
+
+let mut header = Vec::new();
+(Flags::A | Flags::B).write_to(&mut header, Endian::Big)?;
+
+assert_eq!(vec![0x01, 0x01], header);
+assert_eq!(Flags::A | Flags::B, Flags::read_from(&header[..], Endian::Big)?);
+# Ok::<(), std::io::Error>(())
+```
+
+This module requires the `std` feature.
+*/
+
+/**
+The order of the bytes written or read for a flags value.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The least significant byte comes first.
+    Little,
+    /// The most significant byte comes first.
+    Big,
+}
@@ -2,6 +2,8 @@
 Yield the bits of a source flags value in a set of contained flags values.
 */
 
+use core::ops::RangeInclusive;
+
 use crate::{Flag, Flags};
 
 /**
@@ -9,6 +11,8 @@ An iterator over flags values.
 
 This iterator will yield flags values for contained, defined flags first, with any remaining bits yielded
 as a final flags value.
+
+Iterating from the back with [`DoubleEndedIterator`] yields the same flags values in reverse order.
 */
 pub struct Iter<B: 'static> {
     inner: IterNames<B>,
@@ -35,6 +39,26 @@ impl<B: 'static> Iter<B> {
     }
 }
 
+impl<B: Flags> Iter<B> {
+    // The final flags value of any bits that aren't in a yielded named flag
+    fn remaining_after(&self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+
+        let remaining = match &self.inner.marks {
+            Some(marks) => B::from_bits_retain(marks.rest.bits()),
+            None => self.inner.replay(|_| ()),
+        };
+
+        if !remaining.is_empty() {
+            Some(remaining)
+        } else {
+            None
+        }
+    }
+}
+
 impl<B: Flags> Iterator for Iter<B> {
     type Item = B;
 
@@ -56,19 +80,68 @@ impl<B: Flags> Iterator for Iter<B> {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len() + self.remaining_after().map_or(0, |_| 1);
+
+        (len, Some(len))
+    }
 }
 
+impl<B: Flags> DoubleEndedIterator for Iter<B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.done {
+            self.inner.mark();
+        }
+
+        // Any remaining bits are yielded last, so they're the first value from the back
+        if let Some(remaining) = self.remaining_after() {
+            self.done = true;
+
+            return Some(remaining);
+        }
+
+        self.done = true;
+
+        self.inner.next_back().map(|(_, flag)| flag)
+    }
+}
+
+impl<B: Flags> ExactSizeIterator for Iter<B> {}
+
 /**
 An iterator over flags values.
 
 This iterator only yields flags values for contained, defined, named flags. Any remaining bits
 won't be yielded, but can be found with the [`IterNames::remaining`] method.
+
+Iterating from the back with [`DoubleEndedIterator`] yields the same flags values in reverse order.
+Whether a flag is yielded depends on the flags before it, so the first time a flags value is
+yielded from the back the flags are walked once to find which of them will be yielded.
 */
 pub struct IterNames<B: 'static> {
     flags: &'static [Flag<B>],
     idx: usize,
     source: B,
     remaining: B,
+    // The bits that haven't been yielded from the front yet,
+    // if any flags have been yielded from the back
+    front: Option<B>,
+    // The flags that will be yielded, if they've been found
+    marks: Option<Marks<B>>,
+}
+
+// The flags that will be yielded by an `IterNames`.
+//
+// At most 128 flags are tracked at a time, from the back. If there are more flags
+// than that then they're walked again when those 128 have been yielded.
+struct Marks<B> {
+    // The index of the flag in the lowest bit of `yielded`
+    base: usize,
+    // The flags from `base` that will be yielded
+    yielded: u128,
+    // The bits that will be remaining once all flags have been yielded
+    rest: B,
 }
 
 impl<B: Flags> IterNames<B> {
@@ -78,6 +151,8 @@ impl<B: Flags> IterNames<B> {
             idx: 0,
             remaining: B::from_bits_retain(flags.bits()),
             source: B::from_bits_retain(flags.bits()),
+            front: None,
+            marks: None,
         }
     }
 }
@@ -91,6 +166,8 @@ impl<B: 'static> IterNames<B> {
             idx: 0,
             remaining,
             source,
+            front: None,
+            marks: None,
         }
     }
 
@@ -104,37 +181,239 @@ impl<B: 'static> IterNames<B> {
     }
 }
 
+impl<B: Flags> IterNames<B> {
+    fn front(&self) -> &B {
+        self.front.as_ref().unwrap_or(&self.remaining)
+    }
+
+    // Whether the flag at `idx` would be yielded from the front, given the bits
+    // that haven't been covered by a flag yielded from the front yet
+    fn yields(&self, idx: usize, front: &B) -> Option<B> {
+        let flag = &self.flags[idx];
+
+        // Skip unnamed flags
+        if flag.name().is_empty() {
+            return None;
+        }
+
+        let bits = B::from_bits_retain(flag.value().bits());
+
+        // If the flag is set in the original source _and_ it has bits that haven't
+        // been covered by a previous flag yet then yield it. These conditions cover
+        // two cases for multi-bit flags:
+        //
+        // 1. When flags partially overlap, such as `0b00000001` and `0b00000101`, we'll
+        // yield both flags.
+        // 2. When flags fully overlap, such as in convenience flags that are a shorthand for others,
+        // we won't yield both flags.
+        if self.source.contains(B::from_bits_retain(bits.bits()))
+            && front.intersects(B::from_bits_retain(bits.bits()))
+        {
+            Some(bits)
+        } else {
+            None
+        }
+    }
+
+    // Walk the flags that are left to yield from the front without consuming them,
+    // returning the bits that would be remaining afterwards.
+    //
+    // Whether a flag is yielded depends on the flags yielded before it, so this is
+    // also how flags are found from the back.
+    fn replay(&self, mut yielded: impl FnMut(usize)) -> B {
+        let mut front = B::from_bits_retain(self.front().bits());
+        let mut remaining = B::from_bits_retain(self.remaining.bits());
+
+        for idx in self.idx..self.flags.len() {
+            if front.is_empty() {
+                break;
+            }
+
+            if let Some(bits) = self.yields(idx, &front) {
+                front.remove(B::from_bits_retain(bits.bits()));
+                remaining.remove(bits);
+
+                yielded(idx);
+            }
+        }
+
+        remaining
+    }
+
+    // Find the flags that will be yielded, if they haven't been found already
+    fn mark(&mut self) {
+        loop {
+            match &self.marks {
+                // The flags still to be yielded from the back are already known
+                Some(marks) if marks.base <= self.idx || marks.yielded != 0 => return,
+                // All the flags that were found have been yielded from the back,
+                // but there are more flags before them
+                Some(marks) => self.flags = &self.flags[..marks.base],
+                None => (),
+            }
+
+            let base = self.idx.max(self.flags.len().saturating_sub(128));
+            let mut yielded = 0;
+            let rest = self.replay(|idx| {
+                if idx >= base {
+                    yielded |= 1 << (idx - base);
+                }
+            });
+
+            self.marks = Some(Marks {
+                base,
+                yielded,
+                rest,
+            });
+        }
+    }
+
+    // The flags that will be yielded out of the ones that have been found, skipping
+    // any that have already been passed from the front
+    fn pending(&self) -> Option<(usize, u128)> {
+        let marks = self.marks.as_ref()?;
+
+        let passed = self.idx.saturating_sub(marks.base) as u32;
+        let pending = marks.yielded & u128::MAX.checked_shl(passed).unwrap_or(0);
+
+        Some((marks.base, pending))
+    }
+}
+
 impl<B: Flags> Iterator for IterNames<B> {
     type Item = (&'static str, B);
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(flag) = self.flags.get(self.idx) {
+        while self.idx < self.flags.len() {
             // Short-circuit if our state is empty
-            if self.remaining.is_empty() {
+            if self.front().is_empty() {
                 return None;
             }
 
+            let idx = self.idx;
+            self.idx += 1;
+
+            if let Some(bits) = self.yields(idx, self.front()) {
+                if let Some(front) = &mut self.front {
+                    front.remove(B::from_bits_retain(bits.bits()));
+                }
+                self.remaining.remove(B::from_bits_retain(bits.bits()));
+
+                return Some((self.flags[idx].name(), bits));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.pending() {
+            // All of the flags that will be yielded have been found
+            Some((base, pending)) if base <= self.idx => pending.count_ones() as usize,
+            _ => {
+                let mut len = 0;
+                self.replay(|_| len += 1);
+
+                len
+            }
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<B: Flags> DoubleEndedIterator for IterNames<B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.mark();
+
+        let (base, pending) = self.pending()?;
+
+        if pending == 0 {
+            return None;
+        }
+
+        let last = 127 - pending.leading_zeros() as usize;
+        let idx = base + last;
+
+        if let Some(marks) = &mut self.marks {
+            marks.yielded &= !(1 << last);
+        }
+
+        // Flags before the one yielded from the back still depend on the bits
+        // yielded from the front, so track those separately from now on
+        if self.front.is_none() {
+            self.front = Some(B::from_bits_retain(self.remaining.bits()));
+        }
+
+        let flag = &self.flags[idx];
+        self.flags = &self.flags[..idx];
+
+        let bits = B::from_bits_retain(flag.value().bits());
+        self.remaining.remove(B::from_bits_retain(bits.bits()));
+
+        Some((flag.name(), bits))
+    }
+}
+
+impl<B: Flags> ExactSizeIterator for IterNames<B> {}
+
+/**
+An iterator over flags values that aren't contained in a source flags value.
+
+This iterator only yields flags values for defined, named flags that aren't composed of other flags.
+Flags that have the same bits as a previously yielded flag won't be yielded.
+*/
+pub struct IterMissing<B: 'static> {
+    flags: &'static [Flag<B>],
+    idx: usize,
+    source: B,
+    yielded: B,
+}
+
+impl<B: Flags> IterMissing<B> {
+    pub(crate) fn new(flags: &B) -> Self {
+        IterMissing {
+            flags: B::FLAGS,
+            idx: 0,
+            source: B::from_bits_retain(flags.bits()),
+            yielded: B::empty(),
+        }
+    }
+}
+
+impl<B: 'static> IterMissing<B> {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub const fn __private_const_new(flags: &'static [Flag<B>], source: B, yielded: B) -> Self {
+        IterMissing {
+            flags,
+            idx: 0,
+            source,
+            yielded,
+        }
+    }
+}
+
+impl<B: Flags> Iterator for IterMissing<B> {
+    type Item = (&'static str, B);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(flag) = self.flags.get(self.idx) {
             self.idx += 1;
 
-            // Skip unnamed flags
-            if flag.name().is_empty() {
+            // Skip unnamed flags and flags that are composed of others
+            if flag.is_unnamed() || flag.is_composite() {
                 continue;
             }
 
             let bits = flag.value().bits();
 
-            // If the flag is set in the original source _and_ it has bits that haven't
-            // been covered by a previous flag yet then yield it. These conditions cover
-            // two cases for multi-bit flags:
-            //
-            // 1. When flags partially overlap, such as `0b00000001` and `0b00000101`, we'll
-            // yield both flags.
-            // 2. When flags fully overlap, such as in convenience flags that are a shorthand for others,
-            // we won't yield both flags.
-            if self.source.contains(B::from_bits_retain(bits))
-                && self.remaining.intersects(B::from_bits_retain(bits))
+            // Only yield flags that aren't fully contained in the source, and that
+            // don't have the same bits as a flag that's already been yielded
+            if !self.source.contains(B::from_bits_retain(bits))
+                && !self.yielded.contains(B::from_bits_retain(bits))
             {
-                self.remaining.remove(B::from_bits_retain(bits));
+                self.yielded.insert(B::from_bits_retain(bits));
 
                 return Some((flag.name(), B::from_bits_retain(bits)));
             }
@@ -143,3 +422,191 @@ impl<B: Flags> Iterator for IterNames<B> {
         None
     }
 }
+
+/**
+An iterator over the defined flags with a single bit that were removed from a flags value.
+
+This iterator is returned by the `drain` method on flags types generated by the [`bitflags`](crate::bitflags)
+macro. The flags are removed from the drained value up front, before anything is yielded, so this iterator
+doesn't borrow it. The flags are yielded from the lowest bit up, like with `pop_first`.
+*/
+pub struct Drain<B: 'static> {
+    remaining: B,
+    pop: fn(&mut B) -> Option<B>,
+}
+
+impl<B> Drain<B> {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub fn __private_new(drained: B, pop: fn(&mut B) -> Option<B>) -> Self {
+        Drain {
+            remaining: drained,
+            pop,
+        }
+    }
+}
+
+impl<B> Iterator for Drain<B> {
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.pop)(&mut self.remaining)
+    }
+}
+
+/**
+An iterator over the ranges of set bits in a flags value.
+
+This iterator yields the positions of each maximal run of contiguous set bits, from the least
+significant bit up, like `3..=7` for `0b1111_1000`. Names aren't considered, so unknown bits
+and the bits of unnamed flags are yielded in the same way as any others.
+*/
+pub struct IterRanges {
+    bits: u128,
+}
+
+impl IterRanges {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub const fn __private_const_new(bits: u128, size: usize) -> Self {
+        IterRanges {
+            bits: truncate_bits(bits, size),
+        }
+    }
+}
+
+impl Iterator for IterRanges {
+    type Item = RangeInclusive<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let start = self.bits.trailing_zeros();
+        let len = (!(self.bits >> start)).trailing_zeros().min(128 - start);
+        let end = start + len - 1;
+
+        // Unset the bits in the range, and any below it
+        self.bits = if end >= 127 {
+            0
+        } else {
+            self.bits & (!0 << (end + 1))
+        };
+
+        Some(start..=end)
+    }
+}
+
+/**
+An iterator over the positions of unknown bits in a flags value.
+
+This iterator yields the position of each set bit that isn't part of any defined flag, from the
+least significant bit up. If the flags type defines an unnamed flag like `const _ = !0` then
+every bit is known, so this iterator won't yield anything.
+*/
+pub struct IterUnknownBits {
+    bits: u128,
+}
+
+impl IterUnknownBits {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub const fn __private_const_new(unknown: u128, size: usize) -> Self {
+        IterUnknownBits {
+            bits: truncate_bits(unknown, size),
+        }
+    }
+}
+
+impl Iterator for IterUnknownBits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let position = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+
+        Some(position)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for IterUnknownBits {}
+
+/**
+An iterator over the positions of set bits in a flags value.
+
+This iterator yields the position of each set bit, from the least significant bit up, like `0`
+and `7` for `0x81`. Names aren't considered, so unknown bits are yielded in the same way as the
+bits of defined flags.
+
+Iterating from the back with [`DoubleEndedIterator`] yields the same positions in reverse order.
+*/
+pub struct IterBitIndices {
+    bits: u128,
+}
+
+impl IterBitIndices {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub const fn __private_const_new(bits: u128, size: usize) -> Self {
+        IterBitIndices {
+            bits: truncate_bits(bits, size),
+        }
+    }
+}
+
+impl Iterator for IterBitIndices {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let position = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+
+        Some(position)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for IterBitIndices {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let position = 127 - self.bits.leading_zeros();
+        self.bits &= !(1 << position);
+
+        Some(position)
+    }
+}
+
+impl ExactSizeIterator for IterBitIndices {}
+
+// Signed bits types are sign-extended when they're cast to `u128`,
+// so only keep the bits that fit in a bits type of the given size
+const fn truncate_bits(bits: u128, size: usize) -> u128 {
+    if size >= 16 {
+        bits
+    } else {
+        bits & ((1 << (size * 8)) - 1)
+    }
+}
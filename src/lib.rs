@@ -65,16 +65,154 @@ without generating additional constants for them. It helps compatibility when th
 may start setting additional bits at any time. The [known and unknown bits](#known-and-unknown-bits)
 section has more details on this behavior.
 
+### Numbering flags automatically
+
+Flags can be declared without a value. They're given the lowest bit that isn't set in any flag
+declared before them, including flags with explicit values:
+
+```rust
+# use bitflags::bitflags;
+bitflags! {
+    pub struct Flags: u32 {
+        const A; // 0b00000001
+        const B = 0b00000100;
+        const C; // 0b00000010
+        const D; // 0b00001000
+    }
+}
+# assert_eq!(0b00001111, Flags::all().bits());
+```
+
+If there are no bits left for a flag, the flags type will fail to compile.
+
+### Declaring the same flags at different widths
+
+A single declaration can generate multiple flags types with the same flags but different
+underlying bits types. Each flags type gets the same attributes and visibility:
+
+```rust
+# use bitflags::bitflags;
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Caps: u32, WireCaps: u8 {
+        const A = 0b00000001;
+        const B = 0b00000010;
+    }
+}
+
+let wire = WireCaps::A | WireCaps::B;
+let caps = Caps::from_bits_retain(wire.bits().into());
+
+assert_eq!(Caps::A | Caps::B, caps);
+```
+
+Flags types can't be generic over their bits type, because their methods are `const`.
+Each flag's value must fit in every bits type it's declared for.
+
+### Sharing flags between flags types
+
+A flags type can import the named flags of another flags type with the same bits type by
+starting its flags with `use $Base;`. The imported flags are included in [`Flags::FLAGS`] after
+the flags declared in the flags type, so they're formatted, parsed, and included in `Debug` output.
+Their bits are taken before any flags are [numbered automatically](#numbering-flags-automatically).
+The `deny_overlap` [option](macro.bitflags.html#options) makes sure the flags added to each
+flags type don't reuse the bits of imported ones:
+
+```rust
+# use bitflags::bitflags;
+bitflags! {
+    pub struct Base: u8 {
+        const ACK = 1;
+        const SYN = 1 << 1;
+    }
+
+    #[bitflags(deny_overlap, from_str)]
+    pub struct Data: u8 {
+        use Base;
+        const COMPRESSED; // 0b00000100
+    }
+}
+
+let data: Data = "SYN | COMPRESSED".parse()?;
+# assert_eq!(0b00000110, data.bits());
+# Ok::<(), bitflags::parser::ParseError>(())
+```
+
+Imported flags aren't constants on the flags type, so convert them with `from_bits_retain`, like
+`Data::from_bits_retain(Base::SYN.bits())`. Aliases of imported flags aren't imported. The imported
+flags type must be generated by [`bitflags`].
+
+### Platform-specific flags
+
+Flags can have `#[cfg]` and `#[cfg_attr]` attributes. A flag that's configured out isn't defined at all,
+so its bits aren't included in `all`, and its name can't be parsed:
+
+```rust
+# use bitflags::bitflags;
+bitflags! {
+    pub struct Flags: u32 {
+        const A = 0b00000001;
+
+        #[cfg(target_os = "linux")]
+        const EPOLL = 0b00010000;
+    }
+}
+```
+
+Flags can also be `#[deprecated]`. Using a deprecated flag's constant will produce a warning,
+but the code generated for it by the `bitflags` macro won't, so it's still included in `all`
+and can still be parsed by name.
+
 ### Custom derives
 
 You can derive some traits on generated flags types if you enable Cargo features. The following
 libraries are currently supported:
 
 - `serde`: Support `#[derive(Serialize, Deserialize)]`, using text for human-readable formats,
-and a raw number for binary formats.
+  and a raw number for binary formats.
 - `arbitrary`: Support `#[derive(Arbitrary)]`, only generating flags values with known bits.
 - `bytemuck`: Support `#[derive(Pod, Zeroable)]`, for casting between flags values and their
-underlying bits values.
+  underlying bits values.
+- `schemars`: Support `#[derive(JsonSchema)]`, describing the text format used by `serde`
+  for human-readable formats.
+- `zerocopy`: Support `#[derive(FromBytes, IntoBytes, Immutable, KnownLayout)]`, for reading and
+  writing flags values directly as bytes. Like any use of `zerocopy`'s derives, this needs `zerocopy`
+  as a direct dependency. `Unaligned` isn't supported, because it can't be derived conditionally on
+  the underlying bits type.
+- `rkyv`: Support `#[derive(Archive, Serialize, Deserialize)]`, archiving flags values as their
+  underlying bits. Archived flags values can be converted back using `to_native`.
+- `borsh`: Support `#[derive(BorshSerialize, BorshDeserialize)]`, using the underlying bits
+  as the wire format.
+- `bincode`: Support `#[derive(Encode, Decode)]` from `bincode` 2, using the
+  underlying bits as the wire format. `bincode`'s derives can currently only parse flags types
+  declared as `pub`.
+- `defmt`: Support `#[derive(Format)]` from `defmt`, logging flags values in the same text
+  format as `Display`, without allocating.
+- `quickcheck`: Implement `quickcheck::Arbitrary` for flags types that are `Clone`, only generating
+  flags values with known bits, and shrinking towards fewer flags.
+- `proptest`: Implement `proptest::arbitrary::Arbitrary` for flags types that are `Debug`, generating
+  subsets of the defined flags, optionally restricted to a mask, and shrinking towards `empty()`.
+- `rand`: Implement `Distribution` for `rand::distr::StandardUniform`, so `rng.random()` samples
+  uniformly from subsets of the defined bits. Use `bitflags::rand::sample_with` to sample within a mask.
+  Flags types backed by `usize` or `isize` can't be sampled, because `rand` doesn't support them.
+- `clap`: Implement `ValueParserFactory` for flags types, so they can be parsed from command-line
+  arguments like `--features A,B` with `clap::value_parser!`. See `bitflags::clap::FlagsValueParser`.
+- `postgres`: Support `#[derive(ToSql, FromSql)]` from `postgres-types` with `#[postgres(transparent)]`,
+  storing flags as the Postgres integer type with the same width as the underlying bits.
+  See `bitflags::postgres::PostgresBits` for how unsigned bits are stored.
+- `sqlx`: Support `#[derive(sqlx::Type)]` with `#[sqlx(transparent)]`, storing flags as their underlying
+  bits in any database that supports the bits type. Type information is the same as the bits type,
+  so `query!` and friends check flags columns just like integer ones.
+- `diesel`: Implement `ToSql` and `FromSql` for flags types against the `TinyInt`, `SmallInt`, `Integer`,
+  or `BigInt` SQL type their bits type supports, like `i32` for `Integer`. Add
+  `#[derive(AsExpression, FromSqlRow)]` with `#[diesel(sql_type = ...)]` to use flags in queries.
+  Range checks are the same as for the bits type on the same backend.
+- `pyo3`: Implement `FromPyObject` and `IntoPyObject` from `pyo3` for flags types, converting flags to a Python `int`.
+  Flags can be extracted from either an `int` or a string of flag names like `"A | B"`.
+  Use `bitflags::pyo3::to_py_string` to convert flags into a string of flag names instead.
+- `wasm-bindgen`: Implement `From<Flags> for JsValue` and `TryFrom<JsValue> for Flags`, converting flags
+  to a JavaScript number, or a `BigInt` for 64 and 128 bit flags types. A string of flag names is also accepted.
+  See `bitflags::wasm_bindgen::constants` for exporting the defined flags to JavaScript.
 
 You can also define your own flags type outside of the [`bitflags`] macro and then use it to generate methods.
 This can be useful if you need a custom `#[derive]` attribute for a library that `bitflags` doesn't
@@ -121,6 +259,72 @@ impl Flags {
 }
 ```
 
+### Restricting the generated API
+
+The constant generated for a flag is `pub` by default. A flag can be given a visibility like any
+other constant, which is applied to its generated constant:
+
+```rust
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(display)]
+    pub struct Flags: u32 {
+        const A = 1;
+        // Only code in this crate can use `Flags::B`
+        pub(crate) const B = 1 << 1;
+    }
+}
+
+// Restricted flags are still formatted and parsed by name
+assert_eq!("A | B", (Flags::A | Flags::B).to_string());
+```
+
+The methods generated for a flags type are always `pub`, and the [`Flags`] trait
+it implements can create flags values from any bits. To make a flags type public without letting
+other crates create arbitrary flags values, keep the flags type private and wrap it in a public
+newtype that only exposes what you want:
+
+```rust
+mod caps {
+    use bitflags::bitflags;
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[bitflags(display)]
+        pub(crate) struct Inner: u32 {
+            const READ = 1;
+            const WRITE = 1 << 1;
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Caps(Inner);
+
+    impl Caps {
+        // Only code in this crate can create `Caps`
+        pub(crate) fn read_write() -> Self {
+            Caps(Inner::READ | Inner::WRITE)
+        }
+
+        pub fn can_write(&self) -> bool {
+            self.0.contains(Inner::WRITE)
+        }
+    }
+
+    // Formatting is still generated for the private flags type
+    impl std::fmt::Display for Caps {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+}
+
+let caps = caps::Caps::read_write();
+
+assert!(caps.can_write());
+assert_eq!("READ | WRITE", caps.to_string());
+```
+
 ## Working with flags values
 
 Use generated constants and standard bitwise operators to interact with flags values:
@@ -148,14 +352,98 @@ let b = ab - Flags::A;
 let c = !ab;
 ```
 
+Operators can't be used in `const` contexts, so generated flags types also have `const` methods
+for each of them, like `union`, `intersection`, `difference`, `symmetric_difference`, and `complement`:
+
+```rust
+# use bitflags::bitflags;
+# bitflags! {
+#     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#     pub struct Flags: u32 {
+#         const A = 0b00000001;
+#         const B = 0b00000010;
+#         const C = 0b00000100;
+#     }
+# }
+const DEFAULT: Flags = Flags::A.union(Flags::B).difference(Flags::C);
+```
+
 See the docs for the [`Flags`] trait for more details on operators and how they behave.
 
+### Sharing flags values between threads
+
+If you enable the `atomic` Cargo feature, an `AtomicFlags` type can be used to share a flags value
+between threads, including in a `static`. See the `atomic` module for more details.
+
+### Reading and writing flags values as bytes
+
+If you enable the `std` Cargo feature, flags types with the `inherent_methods` [option](#options) have
+`write_to` and `read_from` methods that write and read their bits through `std::io`, in either byte order.
+See the `io` module for more details.
+
+### Flags types with more than 128 bits
+
+For more bits than a primitive integer has, use a [`wide::WideBits`] as the bits type with the
+`custom_bits` [option](#options), like `WideBits<3>` for 192 bits. See the [`wide`] module for more details.
+
+### Storing flags values with a niche
+
+`Option<Flags>` is larger than `Flags`, because flags types can use every value of their bits type.
+The [`non_zero::NonZeroFlags`] type stores a flags value so that `Option<NonZeroFlags<Flags>>`
+is the same size as `Flags`. See the [`non_zero`] module for more details.
+
+### Passing flags values through FFI
+
+A flags type with a `#[repr(transparent)]` attribute is guaranteed to have the same layout and ABI
+as its underlying bits type, so it can be passed directly to and from C. `#[repr(C)]` guarantees
+the same size and alignment. Without either attribute the layout isn't guaranteed:
+
+```rust
+# use bitflags::bitflags;
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Flags: u32 {
+        const A = 0b00000001;
+        const B = 0b00000010;
+    }
+}
+
+extern "C" fn callback(flags: Flags) -> Flags {
+    flags | Flags::B
+}
+# assert_eq!(Flags::A | Flags::B, callback(Flags::A));
+```
+
+Otherwise, use [`Flags::bits`] and [`Flags::from_bits_retain`] to convert flags values to and from
+their bits. They don't truncate, so any bits set by the other side are kept.
+
 # Formatting and parsing
 
 `bitflags` defines a text format that can be used to convert any flags value to and from strings.
 
 See the [`parser`] module for more details.
 
+Generated flags types also implement the [`Binary`](core::fmt::Binary), [`Octal`](core::fmt::Octal),
+[`LowerHex`](core::fmt::LowerHex), and [`UpperHex`](core::fmt::UpperHex) formatting traits.
+They format the underlying bits value, including any formatting options like `{:#010x}`:
+
+```rust
+# use bitflags::bitflags;
+# bitflags! {
+#     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#     pub struct Flags: u32 {
+#         const A = 0b00000001;
+#         const B = 0b00000010;
+#         const C = 0b00000100;
+#     }
+# }
+assert_eq!("0x00000003", format!("{:#010x}", Flags::A | Flags::B));
+```
+
+A derived `Debug` implementation formats a flags value in the text format, like `Flags(A | B)`.
+The alternate form, `{:#?}`, instead lists the bits value and whether each named flag is set.
+
 # Specification
 
 The terminology and behavior of generated flags types is
@@ -205,6 +493,23 @@ bitflags! {
 
 The known bits are `0b0000_0111` and the unknown bits are `0b1111_1000`.
 
+Unknown bits are formatted as a hex number after any named flags, so they're visible in `Debug` output:
+
+```
+# use bitflags::bitflags;
+# bitflags! {
+#     #[derive(Debug)]
+#     struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#         const C = 1 << 2;
+#     }
+# }
+assert_eq!("Flags(A | B | 0x80)", format!("{:?}", Flags::from_bits_retain(0b1000_0011)));
+assert_eq!("Flags(0x80)", format!("{:?}", Flags::from_bits_retain(0b1000_0000)));
+assert_eq!("Flags(0x0)", format!("{:?}", Flags::empty()));
+```
+
 `bitflags` doesn't guarantee that a flags value will only ever have known bits set, but some operators
 will unset any unknown bits they encounter. In a future version of `bitflags`, all operators will
 unset unknown bits.
@@ -243,18 +548,30 @@ The result of `Flags::A ^ Flags::B` is `0b0000_0010`, which doesn't correspond t
 #![cfg_attr(test, allow(mixed_script_confusables))]
 
 #[doc(inline)]
-pub use traits::{Bits, Flag, Flags};
+pub use traits::{Bits, Changes, Flag, Flags, UnknownBits};
 
 pub mod iter;
+pub mod non_zero;
 pub mod parser;
+pub mod wide;
+
+#[cfg(feature = "atomic")]
+pub mod atomic;
+
+#[cfg(feature = "std")]
+pub mod io;
 
 mod traits;
 
 #[doc(hidden)]
 pub mod __private {
-    pub use crate::{external::__private::*, traits::__private::*};
+    #[allow(unused_imports)]
+    pub use crate::{external::__private::*, parser::__private::*, traits::__private::*};
 
     pub use core;
+
+    #[cfg(feature = "std")]
+    pub extern crate std;
 }
 
 #[allow(unused_imports)]
@@ -389,6 +706,22 @@ A declaration that begins with `impl` will only generate methods and trait imple
 The struct itself must be a newtype using the bits type as its field.
 
 The syntax for `impl` mode is identical to `struct` mode besides the starting token.
+Other attributes can't be applied to the struct from within the macro, but [options](#options) can.
+
+`impl` mode generates:
+
+- an associated constant for each named flag;
+- the inherent methods of generated flags types, like `from_bits`, `contains`, and `iter`;
+- the [`Flags`] trait;
+- the bitwise operators, [`Extend`](core::iter::Extend), [`FromIterator`](core::iter::FromIterator),
+  and [`IntoIterator`](core::iter::IntoIterator).
+
+It doesn't generate `Debug`, `Display`, or `FromStr`, because the struct may already implement them.
+Use the `debug`, `display`, and `from_str` options to generate them. Inherent methods defined
+for the struct outside of the `bitflags` macro may conflict with the generated ones.
+
+Since the struct implements [`Flags`], the functions in [`parser`] and the `serde` module can
+be used with it directly, such as through `#[serde(with = "bitflags::serde")]`.
 
 ## Examples
 
@@ -407,6 +740,27 @@ bitflags! {
 }
 ```
 
+Implement flags for a type generated by a tool like `bindgen`, with the same formatting and parsing
+as a flags type in `struct` mode:
+
+```
+# use bitflags::bitflags;
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Mode(pub u32);
+
+bitflags! {
+    #[bitflags(debug, display, from_str)]
+    impl Mode: u32 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+    }
+}
+
+assert_eq!("Mode(READ | WRITE)", format!("{:?}", Mode::READ | Mode::WRITE));
+assert_eq!(Mode::READ, "READ".parse().unwrap());
+```
+
 # Named and unnamed flags
 
 Constants in the body of a declaration are flags. The identifier of the constant is the name of
@@ -440,11 +794,188 @@ bitflags! {
     }
 }
 ```
+
+# Options
+
+A `#[bitflags(..)]` attribute on a flags type in `struct` or `impl` mode changes the generated
+code. Options are separated by `,` and may be spread across multiple attributes. Nothing
+listed here is generated unless it's asked for, so existing flags types with manual trait
+implementations aren't affected.
+
+Traits like `Debug`, `Display`, `FromStr`, and `serde`'s are only implemented for a flags type when
+they're derived or asked for by an option, so they can always be implemented manually instead.
+The `no_ops` option opts out of the traits that are implemented by default.
+
+The following options are supported:
+
+- `atomic`: Generate a `const fn into_atomic` method that converts a flags value into an `AtomicFlags`,
+  so a `static` can be initialized from flags values. This option requires the `atomic` feature.
+- `bits_convert`: Implement [`From`](core::convert::From) to convert a flags value into its bits type, and
+  [`TryFrom`](core::convert::TryFrom) to convert a bits value into the flags type. `TryFrom` fails with
+  [`UnknownBits`] for exactly the bits values that `from_bits` rejects. Use `bits_convert = retain` to implement
+  `From` for bits values instead, using `from_bits_retain`, so any bits value is accepted and unknown bits are kept.
+  Neither conversion is lossy, since no bits are ever dropped, but only the `TryFrom` conversion guarantees the
+  flags value contains no unknown bits. Only one form can be used, because `From` implies an infallible `TryFrom`.
+- `bits_ops`: Implement [`BitOr`](core::ops::BitOr), [`BitAnd`](core::ops::BitAnd), [`BitXor`](core::ops::BitXor),
+  and their assigning variants between the flags type and its bits type, like `flags | 0x80`. The bits value is
+  converted with `from_bits_retain`, so unknown bits are handled in the same way as `flags | Flags::from_bits_retain(0x80)`.
+  The non-assigning operators are also implemented with the bits value on the left, like `0x80 | flags`,
+  returning a flags value. This option isn't on by default because the extra impls can make type inference
+  of integer expressions ambiguous.
+- `custom_bits`: Support a bits type that isn't a primitive integer, like [`wide::WideBits`] or your own type that
+  implements [`Bits`], [`ParseHex`](parser::ParseHex), and [`WriteHex`](parser::WriteHex). The operators of a custom bits type can't
+  be called in a `const` context, so generated methods like `all`, `contains`, and `union` aren't `const`, but
+  `empty`, `bits`, and `from_bits_retain` still are. Every flag needs a value that can be evaluated in a `const`
+  context, so composite flags can't use `|`. The formatting traits like [`LowerHex`](core::fmt::LowerHex)
+  aren't implemented, and neither are the traits for external libraries. Options that check flags while
+  compiling, like `deny_overlap`, `exhaustive`, and `kind`, aren't supported, and neither are `inherent_methods`
+  or importing flags with `use`.
+- `debug`: Implement [`Debug`](core::fmt::Debug) with the same output as `#[derive(Debug)]`
+  on a flags type in `struct` mode. This is useful in `impl` mode.
+- `default`: Implement [`Default`](core::default::Default) as [`Flags::empty`]. Use `default = ..`
+  to set the default value to an expression instead, like `default = Self::A.union(Self::C)`.
+  Deriving `Default` on a flags type in `struct` mode also gives an empty default value, but this
+  option works in `impl` mode too.
+- `deny_overlap`: Fail to compile if any two named flags share bits, unless one of them is
+  a composite made entirely of other flags, like `const AB = Self::A.bits() | Self::B.bits();`.
+  Unnamed flags and flags without any bits are ignored.
+- `display`: Implement [`Display`](core::fmt::Display) using [`parser::to_writer`].
+- `empty = ".."`: Format an empty flags value as the given text, like `empty = "none"`, instead of an empty
+  string. This sets [`Flags::EMPTY_NAME`], so it applies to [`parser::to_writer`], `Display`, `Debug`, and
+  `serde`. The text is parsed back to an empty flags value, and an empty string still is too. Text that's
+  empty, can't be parsed as a name, or is the name or alias of a flag fails to compile.
+- `exhaustive`: Fail to compile unless every bit of the underlying bits type is part of a defined flag,
+  so [`Flags::all`] is the same as [`Bits::ALL`]. Unnamed flags count, so reserved ranges can be
+  declared with `const _ = ..;`. The error doesn't say which bits are missing, but
+  `!Flags::all().bits()` does.
+- `groups(..)`: Declare some composite flags, like `groups(READ, WRITE)`, as groups. Generates a `GROUPS`
+  table like [`Flags::FLAGS`], and a `groups` method that yields the name of each group along with the
+  intersection of a flags value and the group. Groups may overlap. Formatting and parsing aren't affected.
+- `kind = $Kind`: Generate an enum called `$Kind` with a variant for each named flag, so flags
+  can be matched exhaustively. The enum has the same visibility and `#[derive]`s as the flags type.
+  [`From`](core::convert::From) converts a variant into its flag, [`TryFrom`](core::convert::TryFrom)
+  converts a flags value that's exactly one named flag into its variant, and `iter_kinds` yields
+  the variants of named flags contained in a flags value. Every named flag needs to be a single bit,
+  so composites aren't supported. This option isn't supported in `impl` mode.
+- `no_ops`: Don't implement the bitwise operators like [`BitOr`](core::ops::BitOr) and [`Not`](core::ops::Not),
+  the formatting traits like [`LowerHex`](core::fmt::LowerHex) and [`Binary`](core::fmt::Binary),
+  [`Extend`](core::iter::Extend), or [`FromIterator`](core::iter::FromIterator), so you can implement
+  them yourself. The generated methods, like `union`, don't depend on them.
+- `from_str`: Implement [`FromStr`](core::str::FromStr) using [`parser::from_str`].
+- `inherent_methods`: Generate the rest of the flags API as inherent methods, like `count`, `first`,
+  `is_subset`, `retain`, `mask_range`, and `write_to`. Most of them are `const`. Without this option,
+  flags types only get the inherent methods they've always had, like `from_bits`, `contains`, and `iter`,
+  so methods you define on them yourself don't conflict with new ones. Methods like `is_subset` and
+  `retain` are still available through the [`Flags`] trait.
+- `subset_ord`: Implement [`PartialOrd`](core::cmp::PartialOrd) by set inclusion, so `A < A | B`, but
+  `A` and `B` are incomparable. Unknown bits are compared like any other bits. The flags type also needs
+  to implement [`PartialEq`](core::cmp::PartialEq), like with `#[derive(PartialEq)]`. Don't derive
+  `PartialOrd` or `Ord`, which compare the underlying bits as numbers instead.
+
+A `#[bitflags(..)]` attribute on a named flag supports the following options:
+
+- `alias = ".."`: Also parse the flag from the given name, like `alias = "WRITE"` on a flag that
+  was renamed from `WRITE`. Aliases are accepted by [`Flags::from_name`] and anything built on it,
+  like [`parser::from_str`] and `serde`, and by the case-insensitive [`Flags::from_name_ignore_case`]
+  and [`parser::from_str_ignore_case`], but they're never formatted. They're listed in
+  [`Flags::ALIASES`]. A flag may have multiple aliases.
+  An alias that's empty or the name of a flag fails to compile.
+- `rename = ".."`: Use the given name for the flag instead of its identifier, like `rename = "allow-read"`.
+  The name is used by [`Flags::FLAGS`], so it's what formatting, parsing, and `serde` see, but the constant
+  keeps its identifier. Names don't need to be valid identifiers, but a name that's empty, contains a `|`,
+  starts or ends with whitespace, starts with `0x`, or is shared with another flag fails to compile.
+
+## Examples
+
+Format and parse flags values as text:
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(display, from_str)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+let flags = Flags::A | Flags::from_bits_retain(1 << 7);
+
+assert_eq!("A | 0x80", flags.to_string());
+assert_eq!(flags, "A | 0x80".parse().unwrap());
+```
+
+Match on the flags contained in a flags value:
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(kind = EventKind)]
+    pub struct Events: u8 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+    }
+}
+
+for kind in (Events::READ | Events::WRITE).iter_kinds() {
+    match kind {
+        EventKind::READ => println!("read"),
+        EventKind::WRITE => println!("write"),
+    }
+}
+
+assert_eq!(Ok(EventKind::READ), EventKind::try_from(Events::READ));
+assert_eq!(Events::WRITE, Events::from(EventKind::WRITE));
+```
 */
 #[macro_export(local_inner_macros)]
 macro_rules! bitflags {
     (
-        $(#[$outer:meta])*
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            use $Base:ty;
+            $($flags:tt)*
+        }
+
+        $($t:tt)*
+    ) => {
+        bitflags! {
+            $(#[$($outer)*])*
+            #[bitflags(import = $Base)]
+            $vis struct $BitFlags: $T {
+                // Imported flags take up the bits of the flags type they're imported from
+                const _ = <$Base>::all().bits();
+                $($flags)*
+            }
+
+            $($t)*
+        }
+    };
+    (
+        $(#[bitflags($($option:tt)*)])*
+        impl $BitFlags:ident: $T:ty {
+            use $Base:ty;
+            $($flags:tt)*
+        }
+
+        $($t:tt)*
+    ) => {
+        bitflags! {
+            $(#[bitflags($($option)*)])*
+            #[bitflags(import = $Base)]
+            impl $BitFlags: $T {
+                // Imported flags take up the bits of the flags type they're imported from
+                const _ = <$Base>::all().bits();
+                $($flags)*
+            }
+
+            $($t)*
+        }
+    };
+    (
+        $(#[$($outer:tt)*])*
         $vis:vis struct $BitFlags:ident: $T:ty {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -454,15 +985,40 @@ macro_rules! bitflags {
 
         $($t:tt)*
     ) => {
-        // Declared in the scope of the `bitflags!` call
-        // This type appears in the end-user's API
-        __declare_public_bitflags! {
-            $(#[$outer])*
-            $vis struct $BitFlags
+        // Split any `#[bitflags(..)]` options out from the attributes
+        // that are applied to the generated flags type
+        __bitflags_options! {
+            unprocessed: [$(#[$($outer)*])*],
+            attrs: [],
+            options: [],
+            then: { $crate::__bitflags_struct },
+            args: {
+                $vis struct $BitFlags: $T {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag = $value;
+                    )*
+                }
+            },
         }
 
-        // Workaround for: https://github.com/bitflags/bitflags/issues/320
+        bitflags! {
+            $($t)*
+        }
+    };
+    (
+        $(#[bitflags($($option:tt)*)])*
+        impl $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt = $value:expr;
+            )*
+        }
+
+        $($t:tt)*
+    ) => {
         __impl_public_bitflags_consts! {
+            options: [$($($option)* ,)*],
             $BitFlags: $T {
                 $(
                     $(#[$inner $($args)*])*
@@ -480,19 +1036,12 @@ macro_rules! bitflags {
             unused_imports,
             non_upper_case_globals,
             clippy::assign_op_pattern,
-            clippy::indexing_slicing,
-            clippy::same_name_method,
             clippy::iter_without_into_iter,
         )]
         const _: () = {
-            // Declared in a "hidden" scope that can't be reached directly
-            // These types don't appear in the end-user's API
-            __declare_internal_bitflags! {
-                $vis struct InternalBitFlags: $T
-            }
-
-            __impl_internal_bitflags! {
-                InternalBitFlags: $T, $BitFlags {
+            __impl_public_bitflags! {
+                options: [$($($option)* ,)*],
+                $BitFlags: $T, $BitFlags {
                     $(
                         $(#[$inner $($args)*])*
                         const $Flag = $value;
@@ -500,81 +1049,169 @@ macro_rules! bitflags {
                 }
             }
 
-            // This is where new library trait implementations can be added
-            __impl_external_bitflags! {
-                InternalBitFlags: $T, $BitFlags {
-                    $(
-                        $(#[$inner $($args)*])*
-                        const $Flag;
-                    )*
-                }
+            __bitflags_ops! {
+                options: [$($($option)* ,)*],
+                $BitFlags
             }
 
-            __impl_public_bitflags_forward! {
-                $BitFlags: $T, InternalBitFlags
-            }
+            __impl_public_bitflags_iter! {
+                options: [$($($option)* ,)*],
+                $BitFlags: $T, $BitFlags
+            }
 
-            __impl_public_bitflags_ops! {
-                $BitFlags
+            __impl_public_bitflags_io! {
+                options: [$($($option)* ,)*],
+                $BitFlags: $T
             }
 
-            __impl_public_bitflags_iter! {
-                $BitFlags: $T, $BitFlags
+            __impl_public_bitflags_options! {
+                $BitFlags: $T {
+                    $($($option)* ,)*
+                }
+            }
+
+            __bitflags_custom_bits! {
+                options: [$($($option)* ,)*],
+                primitive: {},
+                custom: {
+                    __bitflags_custom_bits_options! {
+                        options: [$($($option)* ,)*],
+                    }
+                },
             }
         };
 
+        __bitflags_kind! {
+            options: [$($($option)* ,)*],
+            attrs: [],
+            decl: { impl $BitFlags: $T {} },
+        }
+
         bitflags! {
             $($t)*
         }
     };
     (
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt $(= $value:expr)?;
+            )*
+        }
+
+        $($t:tt)*
+    ) => {
+        // Some flags don't have values, so number them before generating the flags type
+        __bitflags_auto! {
+            decl: { $(#[$($outer)*])* $vis struct $BitFlags: $T },
+            name: $BitFlags,
+            bits: $T,
+            taken: [],
+            auto: [],
+            flags: [],
+            unprocessed: [
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag $(= $value)?;
+                )*
+            ],
+            rest: [$($t)*],
+        }
+    };
+    (
+        $(#[bitflags($($option:tt)*)])*
         impl $BitFlags:ident: $T:ty {
             $(
                 $(#[$inner:ident $($args:tt)*])*
-                const $Flag:tt = $value:expr;
+                const $Flag:tt $(= $value:expr)?;
             )*
         }
 
         $($t:tt)*
     ) => {
-        __impl_public_bitflags_consts! {
-            $BitFlags: $T {
+        // Some flags don't have values, so number them before generating the flags type
+        __bitflags_auto! {
+            decl: { $(#[bitflags($($option)*)])* impl $BitFlags: $T },
+            name: $BitFlags,
+            bits: $T,
+            taken: [],
+            auto: [],
+            flags: [],
+            unprocessed: [
                 $(
                     $(#[$inner $($args)*])*
-                    const $Flag = $value;
+                    const $Flag $(= $value)?;
                 )*
-            }
+            ],
+            rest: [$($t)*],
+        }
+    };
+    (
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt $(= $value:expr)?;
+            )*
         }
 
-        #[allow(
-            dead_code,
-            deprecated,
-            unused_doc_comments,
-            unused_attributes,
-            unused_mut,
-            unused_imports,
-            non_upper_case_globals,
-            clippy::assign_op_pattern,
-            clippy::iter_without_into_iter,
-        )]
-        const _: () = {
-            __impl_public_bitflags! {
-                $BitFlags: $T, $BitFlags {
-                    $(
-                        $(#[$inner $($args)*])*
-                        const $Flag = $value;
-                    )*
-                }
+        $($t:tt)*
+    ) => {
+        // Some flags have a visibility, so pass it on as an option on the flag
+        bitflags! {
+            $(#[$($outer)*])*
+            $vis struct $BitFlags: $T {
+                $(
+                    $(#[$inner $($args)*])*
+                    $(#[bitflags(vis = pub $(($($fvis)*))?)])?
+                    const $Flag $(= $value)?;
+                )*
             }
 
-            __impl_public_bitflags_ops! {
-                $BitFlags
-            }
+            $($t)*
+        }
+    };
+    (
+        $(#[bitflags($($option:tt)*)])*
+        impl $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt $(= $value:expr)?;
+            )*
+        }
 
-            __impl_public_bitflags_iter! {
-                $BitFlags: $T, $BitFlags
+        $($t:tt)*
+    ) => {
+        // Some flags have a visibility, so pass it on as an option on the flag
+        bitflags! {
+            $(#[bitflags($($option)*)])*
+            impl $BitFlags: $T {
+                $(
+                    $(#[$inner $($args)*])*
+                    $(#[bitflags(vis = pub $(($($fvis)*))?)])?
+                    const $Flag $(= $value)?;
+                )*
             }
-        };
+
+            $($t)*
+        }
+    };
+    (
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty $(, $Others:ident: $OthersT:ty)+ $(,)? {
+            $($flags:tt)*
+        }
+
+        $($t:tt)*
+    ) => {
+        // The same flags are declared for multiple flags types
+        __bitflags_widths! {
+            attrs: [$(#[$($outer)*])*],
+            vis: $vis,
+            types: [$BitFlags: $T, $($Others: $OthersT,)*],
+            flags: { $($flags)* },
+        }
 
         bitflags! {
             $($t)*
@@ -583,6 +1220,190 @@ macro_rules! bitflags {
     () => {};
 }
 
+/**
+Parse a flags value from text in a `const` context.
+
+The text uses the same format as [`parser::from_str`]: flag names separated by `|`, where any
+unnamed bits can be given as hex numbers like `0x1f`. Whitespace around each flag is ignored,
+and text that's empty or only whitespace produces an empty flags value.
+
+```
+# use bitflags::{bitflags, flags};
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+}
+
+const DEFAULT: Flags = flags!(Flags, "A | B");
+
+assert_eq!(Flags::A | Flags::B, DEFAULT);
+assert_eq!(Flags::C | Flags::from_bits_retain(0x80), flags!(Flags, "C | 0x80"));
+```
+
+Text that can't be parsed fails compilation, naming the problem in the error:
+
+```compile_fail
+# use bitflags::{bitflags, flags};
+# bitflags! {
+#     pub struct Flags: u8 {
+#         const A = 1;
+#     }
+# }
+// error: evaluation of `UNRECOGNIZED_NAMED_FLAG` failed
+const DEFAULT: Flags = flags!(Flags, "A | D");
+```
+
+The type must be generated by [`bitflags!`]. Names are matched against [`Flags::FLAGS`], so
+renamed flags use their new name, and the text from the `empty` option is accepted, but unlike
+the runtime parser, aliases aren't supported.
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! flags {
+    ($Flags:ty, $text:expr $(,)?) => {{
+        const VALUE: $Flags = {
+            const TEXT: &str = $text;
+
+            // The parsed bits, and an error code if the text couldn't be parsed
+            const PARSED: (<$Flags as $crate::Flags>::Bits, u8) = {
+                let flags = <$Flags as $crate::Flags>::FLAGS;
+                let empty = <$Flags as $crate::Flags>::EMPTY_NAME;
+                let size =
+                    $crate::__private::core::mem::size_of::<<$Flags as $crate::Flags>::Bits>();
+
+                let mut bits = <$Flags>::empty().bits();
+                let mut error = 0;
+
+                let mut cursor = if $crate::__private::const_is_blank(TEXT) {
+                    $crate::__private::core::option::Option::None
+                } else {
+                    $crate::__private::core::option::Option::Some(0)
+                };
+
+                while let $crate::__private::core::option::Option::Some(start) = cursor {
+                    let (start, end, next) = $crate::__private::const_next_flag(TEXT, start);
+                    cursor = next;
+
+                    if start == end {
+                        error = 1;
+                        break;
+                    }
+
+                    match $crate::__private::const_parse_hex(TEXT, start, end, size) {
+                        $crate::__private::core::option::Option::Some(
+                            $crate::__private::core::option::Option::Some(parsed),
+                        ) => {
+                            bits |= parsed as <$Flags as $crate::Flags>::Bits;
+                        }
+                        $crate::__private::core::option::Option::Some(
+                            $crate::__private::core::option::Option::None,
+                        ) => {
+                            error = 2;
+                            break;
+                        }
+                        $crate::__private::core::option::Option::None => {
+                            // The text of an empty flags value doesn't set any bits
+                            let mut found =
+                                $crate::__private::const_range_eq(TEXT, start, end, empty);
+
+                            let mut i = 0;
+                            while i < flags.len() {
+                                if flags[i].is_named()
+                                    && $crate::__private::const_range_eq(
+                                        TEXT,
+                                        start,
+                                        end,
+                                        flags[i].name(),
+                                    )
+                                {
+                                    bits |= flags[i].value().bits();
+                                    found = true;
+                                    break;
+                                }
+
+                                i += 1;
+                            }
+
+                            if !found {
+                                error = 3;
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                (bits, error)
+            };
+
+            // Named so that they appear in the error when the text can't be parsed
+            const EMPTY_FLAG: () = [()][(PARSED.1 == 1) as usize];
+            const INVALID_HEX_FLAG: () = [()][(PARSED.1 == 2) as usize];
+            const UNRECOGNIZED_NAMED_FLAG: () = [()][(PARSED.1 == 3) as usize];
+
+            let () = EMPTY_FLAG;
+            let () = INVALID_HEX_FLAG;
+            let () = UNRECOGNIZED_NAMED_FLAG;
+
+            <$Flags>::from_bits_retain(PARSED.0)
+        };
+
+        VALUE
+    }};
+}
+
+/**
+Assert an invariant about flags at compile time.
+
+The condition is a `const` expression, like a call to the `const fn` methods on flags types
+such as `contains`, or `is_disjoint` and `implies` with the `inherent_methods` option. If it's
+false then compilation fails, so invariants between flags are checked whenever their values change.
+This works for flags types of any bits type.
+
+```
+# use bitflags::{bitflags, const_assert_flags};
+bitflags! {
+    #[bitflags(inherent_methods)]
+    pub struct Flags: u32 {
+        const CHUNKED = 1;
+        const COMPRESSED = Self::CHUNKED.bits() | 1 << 1;
+        const ENCRYPTED = 1 << 2;
+    }
+}
+
+const_assert_flags!(Flags::COMPRESSED.implies(Flags::CHUNKED));
+const_assert_flags!(Flags::COMPRESSED.is_disjoint(Flags::ENCRYPTED));
+```
+
+An invariant that doesn't hold fails compilation:
+
+```compile_fail
+# use bitflags::{bitflags, const_assert_flags};
+# bitflags! {
+#     #[bitflags(inherent_methods)]
+#     pub struct Flags: u32 {
+#         const CHUNKED = 1;
+#         const COMPRESSED = 1 << 1;
+#     }
+# }
+// error: evaluation of `FLAGS_ASSERTION_FAILED` failed
+const_assert_flags!(Flags::COMPRESSED.implies(Flags::CHUNKED));
+```
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_flags {
+    ($cond:expr $(,)?) => {
+        const _: () = {
+            // Named so that it appears in the error when the condition is false
+            const FLAGS_ASSERTION_FAILED: () = [()][!$cond as usize];
+
+            FLAGS_ASSERTION_FAILED
+        };
+    };
+}
+
 /// Implement functions on bitflags types.
 ///
 /// We need to be careful about adding new methods and trait implementations here because they
@@ -591,27 +1412,57 @@ macro_rules! bitflags {
 #[doc(hidden)]
 macro_rules! __impl_bitflags {
     (
+        options: [$($option:tt)*],
         $PublicBitFlags:ident: $T:ty {
             fn empty() $empty:block
             fn all() $all:block
+            fn all_named() $all_named:block
             fn bits($bits0:ident) $bits:block
             fn from_bits($from_bits0:ident) $from_bits:block
             fn from_bits_truncate($from_bits_truncate0:ident) $from_bits_truncate:block
             fn from_bits_retain($from_bits_retain0:ident) $from_bits_retain:block
+            fn from_bits_strict($from_bits_strict0:ident) $from_bits_strict:block
+            fn from_bit_index($from_bit_index0:ident) $from_bit_index:block
+            fn mask_range($mask_range0:ident) $mask_range:block
             fn from_name($from_name0:ident) $from_name:block
+            fn from_name_ignore_case($from_name_ignore_case0:ident) $from_name_ignore_case:block
             fn is_empty($is_empty0:ident) $is_empty:block
             fn is_all($is_all0:ident) $is_all:block
+            fn contains_unknown_bits($contains_unknown_bits0:ident) $contains_unknown_bits:block
+            fn unknown_bits($unknown_bits0:ident) $unknown_bits:block
+            fn split_known($split_known0:ident) $split_known:block
+            fn with_unknown($with_unknown0:ident, $with_unknown1:ident) $with_unknown:block
+            fn count($count0:ident) $count:block
+            fn first($first0:ident) $first:block
+            fn last($last0:ident) $last:block
+            fn pop_first($pop_first0:ident) $pop_first:block
+            fn pop_last($pop_last0:ident) $pop_last:block
             fn intersects($intersects0:ident, $intersects1:ident) $intersects:block
             fn contains($contains0:ident, $contains1:ident) $contains:block
+            fn is_subset($is_subset0:ident, $is_subset1:ident) $is_subset:block
+            fn is_superset($is_superset0:ident, $is_superset1:ident) $is_superset:block
+            fn is_disjoint($is_disjoint0:ident, $is_disjoint1:ident) $is_disjoint:block
+            fn implies($implies0:ident, $implies1:ident) $implies:block
             fn insert($insert0:ident, $insert1:ident) $insert:block
             fn remove($remove0:ident, $remove1:ident) $remove:block
+            fn insert_changed($insert_changed0:ident, $insert_changed1:ident) $insert_changed:block
+            fn remove_changed($remove_changed0:ident, $remove_changed1:ident) $remove_changed:block
+            fn replace($replace0:ident, $replace1:ident) $replace:block
+            fn fetch_insert($fetch_insert0:ident, $fetch_insert1:ident) $fetch_insert:block
+            fn fetch_remove($fetch_remove0:ident, $fetch_remove1:ident) $fetch_remove:block
+            fn fetch_toggle($fetch_toggle0:ident, $fetch_toggle1:ident) $fetch_toggle:block
             fn toggle($toggle0:ident, $toggle1:ident) $toggle:block
+            fn invert_within($invert_within0:ident, $invert_within1:ident) $invert_within:block
             fn set($set0:ident, $set1:ident, $set2:ident) $set:block
+            fn clear($clear0:ident) $clear:block
+            fn truncate($truncate0:ident) $truncate:block
+            fn retain($retain0:ident, $retain1:ident) $retain:block
             fn intersection($intersection0:ident, $intersection1:ident) $intersection:block
             fn union($union0:ident, $union1:ident) $union:block
             fn difference($difference0:ident, $difference1:ident) $difference:block
             fn symmetric_difference($symmetric_difference0:ident, $symmetric_difference1:ident) $symmetric_difference:block
             fn complement($complement0:ident) $complement:block
+            fn complement_of($complement_of0:ident, $complement_of1:ident) $complement_of:block
         }
     ) => {
         #[allow(dead_code, deprecated, unused_attributes)]
@@ -622,10 +1473,13 @@ macro_rules! __impl_bitflags {
                 $empty
             }
 
-            /// Get a flags value with all known bits set.
-            #[inline]
-            pub const fn all() -> Self {
-                $all
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Get a flags value with all known bits set.
+                #[inline]
+                pub fn all() -> Self {
+                    $all
+                }
             }
 
             /// Get the underlying bits value.
@@ -637,20 +1491,26 @@ macro_rules! __impl_bitflags {
                 $bits
             }
 
-            /// Convert from a bits value.
-            ///
-            /// This method will return `None` if any unknown bits are set.
-            #[inline]
-            pub const fn from_bits(bits: $T) -> $crate::__private::core::option::Option<Self> {
-                let $from_bits0 = bits;
-                $from_bits
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Convert from a bits value.
+                ///
+                /// This method will return `None` if any unknown bits are set.
+                #[inline]
+                pub fn from_bits(bits: $T) -> $crate::__private::core::option::Option<Self> {
+                    let $from_bits0 = bits;
+                    $from_bits
+                }
             }
 
-            /// Convert from a bits value, unsetting any unknown bits.
-            #[inline]
-            pub const fn from_bits_truncate(bits: $T) -> Self {
-                let $from_bits_truncate0 = bits;
-                $from_bits_truncate
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Convert from a bits value, unsetting any unknown bits.
+                #[inline]
+                pub fn from_bits_truncate(bits: $T) -> Self {
+                    let $from_bits_truncate0 = bits;
+                    $from_bits_truncate
+                }
             }
 
             /// Convert from a bits value exactly.
@@ -670,34 +1530,46 @@ macro_rules! __impl_bitflags {
                 $from_name
             }
 
-            /// Whether all bits in this flags value are unset.
-            #[inline]
-            pub const fn is_empty(&self) -> bool {
-                let $is_empty0 = self;
-                $is_empty
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Whether all bits in this flags value are unset.
+                #[inline]
+                pub fn is_empty(&self) -> bool {
+                    let $is_empty0 = self;
+                    $is_empty
+                }
             }
 
-            /// Whether all known bits in this flags value are set.
-            #[inline]
-            pub const fn is_all(&self) -> bool {
-                let $is_all0 = self;
-                $is_all
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Whether all known bits in this flags value are set.
+                #[inline]
+                pub fn is_all(&self) -> bool {
+                    let $is_all0 = self;
+                    $is_all
+                }
             }
 
-            /// Whether any set bits in a source flags value are also set in a target flags value.
-            #[inline]
-            pub const fn intersects(&self, other: Self) -> bool {
-                let $intersects0 = self;
-                let $intersects1 = other;
-                $intersects
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Whether any set bits in a source flags value are also set in a target flags value.
+                #[inline]
+                pub fn intersects(&self, other: Self) -> bool {
+                    let $intersects0 = self;
+                    let $intersects1 = other;
+                    $intersects
+                }
             }
 
-            /// Whether all set bits in a source flags value are also set in a target flags value.
-            #[inline]
-            pub const fn contains(&self, other: Self) -> bool {
-                let $contains0 = self;
-                let $contains1 = other;
-                $contains
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// Whether all set bits in a source flags value are also set in a target flags value.
+                #[inline]
+                pub fn contains(&self, other: Self) -> bool {
+                    let $contains0 = self;
+                    let $contains1 = other;
+                    $contains
+                }
             }
 
             /// The bitwise or (`|`) of the bits in two flags values.
@@ -720,6 +1592,9 @@ macro_rules! __impl_bitflags {
             }
 
             /// The bitwise exclusive-or (`^`) of the bits in two flags values.
+            ///
+            /// Each bit set in `other` is flipped independently, including all the bits of multi-bit
+            /// flags and any unknown bits. Use `symmetric_difference` to toggle bits by value.
             #[inline]
             pub fn toggle(&mut self, other: Self) {
                 let $toggle0 = self;
@@ -728,6 +1603,9 @@ macro_rules! __impl_bitflags {
             }
 
             /// Call `insert` when `value` is `true` or `remove` when `value` is `false`.
+            ///
+            /// Like `insert` and `remove`, any unknown bits in `other` will be set or unset as-is,
+            /// without truncating them.
             #[inline]
             pub fn set(&mut self, other: Self, value: bool) {
                 let $set0 = self;
@@ -736,69 +1614,554 @@ macro_rules! __impl_bitflags {
                 $set
             }
 
-            /// The bitwise and (`&`) of the bits in two flags values.
-            #[inline]
-            #[must_use]
-            pub const fn intersection(self, other: Self) -> Self {
-                let $intersection0 = self;
-                let $intersection1 = other;
-                $intersection
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// The bitwise and (`&`) of the bits in two flags values.
+                #[inline]
+                #[must_use]
+                pub fn intersection(self, other: Self) -> Self {
+                    let $intersection0 = self;
+                    let $intersection1 = other;
+                    $intersection
+                }
             }
 
-            /// The bitwise or (`|`) of the bits in two flags values.
-            #[inline]
-            #[must_use]
-            pub const fn union(self, other: Self) -> Self {
-                let $union0 = self;
-                let $union1 = other;
-                $union
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// The bitwise or (`|`) of the bits in two flags values.
+                #[inline]
+                #[must_use]
+                pub fn union(self, other: Self) -> Self {
+                    let $union0 = self;
+                    let $union1 = other;
+                    $union
+                }
             }
 
-            /// The intersection of a source flags value with the complement of a target flags value (`&!`).
-            ///
-            /// This method is not equivalent to `self & !other` when `other` has unknown bits set.
-            /// `difference` won't truncate `other`, but the `!` operator will.
-            #[inline]
-            #[must_use]
-            pub const fn difference(self, other: Self) -> Self {
-                let $difference0 = self;
-                let $difference1 = other;
-                $difference
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// The intersection of a source flags value with the complement of a target flags value (`&!`).
+                ///
+                /// This method is not equivalent to `self & !other` when `other` has unknown bits set.
+                /// `difference` won't truncate `other`, but the `!` operator will.
+                #[inline]
+                #[must_use]
+                pub fn difference(self, other: Self) -> Self {
+                    let $difference0 = self;
+                    let $difference1 = other;
+                    $difference
+                }
             }
 
-            /// The bitwise exclusive-or (`^`) of the bits in two flags values.
-            #[inline]
-            #[must_use]
-            pub const fn symmetric_difference(self, other: Self) -> Self {
-                let $symmetric_difference0 = self;
-                let $symmetric_difference1 = other;
-                $symmetric_difference
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// The bitwise exclusive-or (`^`) of the bits in two flags values.
+                #[inline]
+                #[must_use]
+                pub fn symmetric_difference(self, other: Self) -> Self {
+                    let $symmetric_difference0 = self;
+                    let $symmetric_difference1 = other;
+                    $symmetric_difference
+                }
             }
 
-            /// The bitwise negation (`!`) of the bits in a flags value, truncating the result.
-            #[inline]
-            #[must_use]
-            pub const fn complement(self) -> Self {
-                let $complement0 = self;
-                $complement
+            __bitflags_const_fn! {
+                options: [$($option)*],
+                /// The bitwise negation (`!`) of the bits in a flags value, truncating the result.
+                #[inline]
+                #[must_use]
+                pub fn complement(self) -> Self {
+                    let $complement0 = self;
+                    $complement
+                }
+            }
+        }
+
+        __bitflags_inherent_methods! {
+            options: [$($option)*],
+            {
+                #[allow(dead_code, deprecated, unused_attributes)]
+                impl $PublicBitFlags {
+                /// Get a flags value with the bits of all named flags set.
+                ///
+                /// This is the same as `all`, except the bits of unnamed flags, like `const _ = !0`,
+                /// aren't included unless they're also part of a named flag.
+                #[inline]
+                pub const fn all_named() -> Self {
+                    $all_named
+                }
+
+                /// Convert from a bits value, returning any unknown bits as an error.
+                ///
+                /// This method is like `from_bits`, except the error reports which bits weren't known.
+                #[inline]
+                pub const fn from_bits_strict(
+                    bits: $T,
+                ) -> $crate::__private::core::result::Result<Self, $crate::UnknownBits<$T>> {
+                    let $from_bits_strict0 = bits;
+                    $from_bits_strict
+                }
+
+                /// Convert from the index of a single bit, where `0` is the lowest bit.
+                ///
+                /// This method will return `None` if `index` is out of range for the bits type. Otherwise,
+                /// the bit is set regardless of whether it corresponds to a defined flag, like with
+                /// `from_bits_retain(1 << index)`.
+                #[inline]
+                pub const fn from_bit_index(
+                    index: u32,
+                ) -> $crate::__private::core::option::Option<Self> {
+                    let $from_bit_index0 = index;
+                    $from_bit_index
+                }
+
+                /// Get a flags value with exactly the bits in a range of positions set, like `4..=7` for `0xf0`.
+                ///
+                /// The bits are set regardless of whether they correspond to defined flags, so this method can
+                /// be used to define flags for fields that span multiple bits, like `const FIELD = Self::mask_range(4..=7).bits();`.
+                /// If the range is empty, like `7..=4`, then this method returns an empty flags value.
+                ///
+                /// # Panics
+                ///
+                /// This method panics if the end of a non-empty range is out of range for the bits type.
+                /// In a `const` context, like when defining a flag, that fails compilation instead.
+                #[inline]
+                pub const fn mask_range(
+                    range: $crate::__private::core::ops::RangeInclusive<u32>,
+                ) -> Self {
+                    let $mask_range0 = range;
+                    $mask_range
+                }
+
+                /// Get a flags value with the bits of a flag with the given name set,
+                /// matching the name without regard to ASCII case.
+                ///
+                /// If `name` exactly matches a named flag or alias then that flag is used. Otherwise, this
+                /// method will return `None` unless the flags and aliases `name` matches when ignoring
+                /// case all have the same value.
+                #[inline]
+                pub fn from_name_ignore_case(
+                    name: &str,
+                ) -> $crate::__private::core::option::Option<Self> {
+                    let $from_name_ignore_case0 = name;
+                    $from_name_ignore_case
+                }
+
+                /// Whether any unknown bits are set in this flags value.
+                ///
+                /// If the flags type defines an unnamed flag like `const _ = !0` then every bit is known,
+                /// so this method will always return `false`.
+                #[inline]
+                pub const fn contains_unknown_bits(&self) -> bool {
+                    let $contains_unknown_bits0 = self;
+                    $contains_unknown_bits
+                }
+
+                /// Get the bits in this flags value that don't correspond to any defined flag.
+                #[inline]
+                pub const fn unknown_bits(&self) -> $T {
+                    let $unknown_bits0 = self;
+                    $unknown_bits
+                }
+
+                /// Split this flags value into its known bits, as a flags value, and its unknown bits.
+                ///
+                /// The known bits are the same as `from_bits_truncate`, and the unknown bits are the same as
+                /// `unknown_bits`. They're returned as a bits value so they can't be mistaken for valid flags.
+                /// Use `with_unknown` to combine them again.
+                #[inline]
+                pub const fn split_known(self) -> (Self, $T) {
+                    let $split_known0 = self;
+                    $split_known
+                }
+
+                /// Set the given bits in this flags value as-is, like unknown bits from `split_known`.
+                ///
+                /// This is the same as `self.union(Self::from_bits_retain(bits))`.
+                #[inline]
+                #[must_use]
+                pub const fn with_unknown(self, bits: $T) -> Self {
+                    let $with_unknown0 = self;
+                    let $with_unknown1 = bits;
+                    $with_unknown
+                }
+
+                /// Get the number of defined single-bit flags that are set in this flags value.
+                ///
+                /// Unlike counting the set bits in [`bits`](#method.bits), unknown bits aren't counted,
+                /// and composite flags like `AB = A | B` don't count as an extra flag. Unnamed flags
+                /// like `const _ = !0` aren't counted either.
+                #[inline]
+                pub const fn count(&self) -> u32 {
+                    let $count0 = self;
+                    $count
+                }
+
+                /// Get the defined flag with the lowest set bit that's contained in this flags value.
+                ///
+                /// If more than one flag has the same lowest bit, like `A` and `AB = A | B`, then the one
+                /// with the fewest bits is returned. Unknown bits are ignored, so this method returns `None`
+                /// if no defined, named flags are contained in this flags value.
+                #[inline]
+                pub const fn first(&self) -> $crate::__private::core::option::Option<Self> {
+                    let $first0 = self;
+                    $first
+                }
+
+                /// Get the defined flag with the highest set bit that's contained in this flags value.
+                ///
+                /// If more than one flag has the same highest bit, like `B` and `AB = A | B`, then the one
+                /// with the fewest bits is returned. Unknown bits are ignored, so this method returns `None`
+                /// if no defined, named flags are contained in this flags value.
+                #[inline]
+                pub const fn last(&self) -> $crate::__private::core::option::Option<Self> {
+                    let $last0 = self;
+                    $last
+                }
+
+                /// Remove and return the defined flag with a single bit that has the lowest position
+                /// in this flags value.
+                ///
+                /// Only named flags with exactly one bit are popped, so calling this method repeatedly
+                /// yields the same flags as `count`. Bits that are only defined as part of a composite flag,
+                /// like `AB = 0b11` without `A` or `B`, and unknown bits are never popped, so this method
+                /// returns `None` once only those bits remain.
+                #[inline]
+                pub fn pop_first(&mut self) -> $crate::__private::core::option::Option<Self> {
+                    let $pop_first0 = self;
+                    $pop_first
+                }
+
+                /// Remove and return the defined flag with a single bit that has the highest position
+                /// in this flags value.
+                ///
+                /// This method is like `pop_first`, except it pops flags from the highest bit down.
+                #[inline]
+                pub fn pop_last(&mut self) -> $crate::__private::core::option::Option<Self> {
+                    let $pop_last0 = self;
+                    $pop_last
+                }
+
+                /// Whether all set bits in this flags value are also set in `other`.
+                ///
+                /// The empty flags value is a subset of every flags value, including itself.
+                /// This is the same as `other.contains(*self)`.
+                #[inline]
+                pub const fn is_subset(&self, other: Self) -> bool {
+                    let $is_subset0 = self;
+                    let $is_subset1 = other;
+                    $is_subset
+                }
+
+                /// Whether all set bits in `other` are also set in this flags value.
+                ///
+                /// Every flags value is a superset of the empty flags value.
+                /// This is the same as `self.contains(other)`.
+                #[inline]
+                pub const fn is_superset(&self, other: Self) -> bool {
+                    let $is_superset0 = self;
+                    let $is_superset1 = other;
+                    $is_superset
+                }
+
+                /// Whether no set bits in this flags value are also set in `other`.
+                ///
+                /// The empty flags value is disjoint from every flags value, including itself.
+                /// This is the same as `!self.intersects(other)`.
+                #[inline]
+                pub const fn is_disjoint(&self, other: Self) -> bool {
+                    let $is_disjoint0 = self;
+                    let $is_disjoint1 = other;
+                    $is_disjoint
+                }
+
+                /// Whether all set bits in `other` are also set whenever all set bits in this flags value are.
+                ///
+                /// This is the same as `self.contains(other)`, but reads better when asserting how flags
+                /// are defined, like with [`const_assert_flags`](crate::const_assert_flags).
+                #[inline]
+                pub const fn implies(&self, other: Self) -> bool {
+                    let $implies0 = self;
+                    let $implies1 = other;
+                    $implies
+                }
+
+                /// Call `insert`, returning whether this flags value changed.
+                ///
+                /// This method returns `true` if any bit set in `other` was unset in this flags value,
+                /// even if other bits in `other` were already set. Use `!self.intersects(other)` before
+                /// inserting to check whether all the bits were unset instead.
+                #[inline]
+                pub fn insert_changed(&mut self, other: Self) -> bool {
+                    let $insert_changed0 = self;
+                    let $insert_changed1 = other;
+                    $insert_changed
+                }
+
+                /// Call `remove`, returning whether this flags value changed.
+                ///
+                /// This method returns `true` if any bit set in `other` was set in this flags value,
+                /// even if other bits in `other` were already unset. Use `self.contains(other)` before
+                /// removing to check whether all the bits were set instead.
+                #[inline]
+                pub fn remove_changed(&mut self, other: Self) -> bool {
+                    let $remove_changed0 = self;
+                    let $remove_changed1 = other;
+                    $remove_changed
+                }
+
+                /// Replace this flags value with `new`, returning the previous flags value.
+                #[inline]
+                pub fn replace(&mut self, new: Self) -> Self {
+                    let $replace0 = self;
+                    let $replace1 = new;
+                    $replace
+                }
+
+                /// Call `insert`, returning the previous flags value.
+                #[inline]
+                pub fn fetch_insert(&mut self, other: Self) -> Self {
+                    let $fetch_insert0 = self;
+                    let $fetch_insert1 = other;
+                    $fetch_insert
+                }
+
+                /// Call `remove`, returning the previous flags value.
+                #[inline]
+                pub fn fetch_remove(&mut self, other: Self) -> Self {
+                    let $fetch_remove0 = self;
+                    let $fetch_remove1 = other;
+                    $fetch_remove
+                }
+
+                /// Call `toggle`, returning the previous flags value.
+                #[inline]
+                pub fn fetch_toggle(&mut self, other: Self) -> Self {
+                    let $fetch_toggle0 = self;
+                    let $fetch_toggle1 = other;
+                    $fetch_toggle
+                }
+
+                /// Flip the bits of this flags value that are set in `mask`, leaving any others as-is.
+                ///
+                /// The bits within `mask` end up the same as in `self.complement_of(mask)`, like an in-place
+                /// form of `complement_of`.
+                /// Any unknown bits in `mask` are flipped too, without truncating them.
+                #[inline]
+                pub fn invert_within(&mut self, mask: Self) {
+                    let $invert_within0 = self;
+                    let $invert_within1 = mask;
+                    $invert_within
+                }
+
+                /// Unset all bits in this flags value.
+                #[inline]
+                pub fn clear(&mut self) {
+                    let $clear0 = self;
+                    $clear
+                }
+
+                /// Unset any unknown bits in this flags value.
+                ///
+                /// This is equivalent to `*self = Self::from_bits_truncate(self.bits())`.
+                #[inline]
+                pub fn truncate(&mut self) {
+                    let $truncate0 = self;
+                    $truncate
+                }
+
+                /// Unset the bits of any contained named flags that don't match a predicate.
+                ///
+                /// The predicate is called once for each flag yielded by `iter_names`.
+                /// Any unknown bits, or bits not corresponding to a named flag, are left as-is.
+                #[inline]
+                pub fn retain(&mut self, f: impl $crate::__private::core::ops::FnMut(Self) -> bool) {
+                    let $retain0 = self;
+                    let $retain1 = f;
+                    $retain
+                }
+
+                /// The complement of a flags value relative to `mask`, so the bits set in `mask` but not in this flags value.
+                ///
+                /// This is the same as `mask.difference(self)`. Unlike `complement`, the result isn't truncated,
+                /// so any unknown bits in `mask` are kept.
+                #[inline]
+                #[must_use]
+                pub const fn complement_of(self, mask: Self) -> Self {
+                    let $complement_of0 = self;
+                    let $complement_of1 = mask;
+                    $complement_of
+                }
+                }
             }
         }
     };
 }
 
-/// A macro that processed the input to `bitflags!` and shuffles attributes around
-/// based on whether or not they're "expression-safe".
+/// A macro that generates the given items for the `inherent_methods` option.
 ///
-/// This macro is a token-tree muncher that works on 2 levels:
-///
-/// For each attribute, we explicitly match on its identifier, like `cfg` to determine
-/// whether or not it should be considered expression-safe.
-///
-/// If you find yourself with an attribute that should be considered expression-safe
-/// and isn't, it can be added here.
+/// This macro is a token-tree muncher that looks through the options for `inherent_methods`.
+/// If there isn't one then nothing is generated, so the methods are only available through
+/// the `Flags` trait.
 #[macro_export(local_inner_macros)]
 #[doc(hidden)]
-macro_rules! __bitflags_expr_safe_attrs {
+macro_rules! __bitflags_inherent_methods {
+    (
+        options: [inherent_methods $($rest:tt)*],
+        { $($item:tt)* }
+    ) => {
+        $($item)*
+    };
+    (
+        options: [$other:tt $($rest:tt)*],
+        { $($item:tt)* }
+    ) => {
+        __bitflags_inherent_methods! {
+            options: [$($rest)*],
+            { $($item)* }
+        }
+    };
+    (
+        options: [],
+        { $($item:tt)* }
+    ) => {};
+}
+
+/// A macro that generates one of the given sets of items, depending on the `custom_bits` option.
+///
+/// This macro is a token-tree muncher that looks through the options for `custom_bits`.
+/// If there isn't one then the bits type is a primitive integer, so the `primitive` items
+/// are generated. Otherwise, the `custom` items are.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_custom_bits {
+    (
+        options: [custom_bits $($rest:tt)*],
+        primitive: { $($primitive:tt)* },
+        custom: { $($custom:tt)* },
+    ) => {
+        $($custom)*
+    };
+    (
+        options: [$other:tt $($rest:tt)*],
+        primitive: { $($primitive:tt)* },
+        custom: { $($custom:tt)* },
+    ) => {
+        __bitflags_custom_bits! {
+            options: [$($rest)*],
+            primitive: { $($primitive)* },
+            custom: { $($custom)* },
+        }
+    };
+    (
+        options: [],
+        primitive: { $($primitive:tt)* },
+        custom: { $($custom:tt)* },
+    ) => {
+        $($primitive)*
+    };
+}
+
+/// A macro that generates a method that's `const`, unless the `custom_bits` option is set.
+///
+/// The operators on custom bits types are trait impls, which can't be called in a `const` context,
+/// so methods that use them can only be `const` for primitive integers.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_const_fn {
+    (
+        options: [$($option:tt)*],
+        $(#[$attr:meta])*
+        $vis:vis fn $($rest:tt)*
+    ) => {
+        __bitflags_custom_bits! {
+            options: [$($option)*],
+            primitive: {
+                $(#[$attr])*
+                $vis const fn $($rest)*
+            },
+            custom: {
+                $(#[$attr])*
+                $vis fn $($rest)*
+            },
+        }
+    };
+}
+
+/// A macro that fails to compile if an option that isn't supported with `custom_bits` is set.
+///
+/// These options generate `const` code that uses the operators of the bits type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_custom_bits_options {
+    (
+        options: [deny_overlap $($rest:tt)*],
+    ) => {
+        __bitflags_custom_bits_options! {
+            unsupported: "the `deny_overlap` option",
+        }
+    };
+    (
+        options: [exhaustive $($rest:tt)*],
+    ) => {
+        __bitflags_custom_bits_options! {
+            unsupported: "the `exhaustive` option",
+        }
+    };
+    (
+        options: [import $($rest:tt)*],
+    ) => {
+        __bitflags_custom_bits_options! {
+            unsupported: "importing flags with `use`",
+        }
+    };
+    (
+        options: [inherent_methods $($rest:tt)*],
+    ) => {
+        __bitflags_custom_bits_options! {
+            unsupported: "the `inherent_methods` option",
+        }
+    };
+    (
+        options: [kind $($rest:tt)*],
+    ) => {
+        __bitflags_custom_bits_options! {
+            unsupported: "the `kind` option",
+        }
+    };
+    (
+        options: [$other:tt $($rest:tt)*],
+    ) => {
+        __bitflags_custom_bits_options! {
+            options: [$($rest)*],
+        }
+    };
+    (
+        options: [],
+    ) => {};
+    (
+        unsupported: $what:literal,
+    ) => {
+        $crate::__private::core::compile_error!($crate::__private::core::concat!(
+            $what,
+            " isn't supported with the `custom_bits` option"
+        ));
+    };
+}
+
+/// A macro that processed the input to `bitflags!` and shuffles attributes around
+/// based on whether or not they're "expression-safe".
+///
+/// This macro is a token-tree muncher that works on 2 levels:
+///
+/// For each attribute, we explicitly match on its identifier, like `cfg` to determine
+/// whether or not it should be considered expression-safe.
+///
+/// If you find yourself with an attribute that should be considered expression-safe
+/// and isn't, it can be added here.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_expr_safe_attrs {
     // Entrypoint: Move all flags and all attributes into `unprocessed` lists
     // where they'll be munched one-at-a-time
     (
@@ -815,6 +2178,38 @@ macro_rules! __bitflags_expr_safe_attrs {
             },
         }
     };
+    // Process the next 8 attributes on the current flag
+    // `doc`: Doc comments aren't propagated to expressions. Every line of a doc comment is
+    // a separate attribute, so they're skipped 8 at a time to stay under the recursion limit
+    (
+        expr: { $e:expr },
+            attrs: {
+            unprocessed: [
+                #[doc $($d0:tt)*]
+                #[doc $($d1:tt)*]
+                #[doc $($d2:tt)*]
+                #[doc $($d3:tt)*]
+                #[doc $($d4:tt)*]
+                #[doc $($d5:tt)*]
+                #[doc $($d6:tt)*]
+                #[doc $($d7:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($expr:tt)*],
+        },
+    ) => {
+        __bitflags_expr_safe_attrs! {
+            expr: { $e },
+            attrs: {
+                unprocessed: [
+                    $($attrs_rest)*
+                ],
+                processed: [
+                    $($expr)*
+                ],
+            },
+        }
+    };
     // Process the next attribute on the current flag
     // `cfg`: The next flag should be propagated to expressions
     // NOTE: You can copy this rules block and replace `cfg` with
@@ -883,6 +2278,303 @@ macro_rules! __bitflags_expr_safe_attrs {
     }
 }
 
+/// A macro that removes `#[bitflags(..)]` options from the attributes on a flag,
+/// so the remaining attributes can be applied to an item generated for it.
+///
+/// The item is `pub`, unless the flag has a `vis = ..` option, which is written
+/// in front of the item instead.
+///
+/// The options are split from the other attributes by `__bitflags_options`, so long doc comments
+/// don't run into the recursion limit. This macro is then a token-tree muncher that looks through
+/// the options for `vis = ..`.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_item_attrs {
+    // Entrypoint: Split the options out from the other attributes
+    (
+        $(#[$inner:ident $($args:tt)*])*
+        { $($item:tt)* }
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$(#[$inner $($args)*])*],
+            attrs: [],
+            options: [],
+            then: { $crate::__bitflags_item_attrs },
+            args: {
+                vis: [pub],
+                item: { $($item)* },
+            },
+        }
+    };
+    // Process the next option on the flag
+    // `vis = ..`: The visibility of the item
+    (
+        options: [vis = $fvis:vis , $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        vis: [$($vis:tt)*],
+        item: { $($item:tt)* },
+    ) => {
+        __bitflags_item_attrs! {
+            options: [$($rest)*],
+            attrs: [$($attrs)*],
+            vis: [$fvis],
+            item: { $($item)* },
+        }
+    };
+    // Process the next option on the flag
+    // `$other`: Options for the flag aren't applied to the item
+    (
+        options: [$other:tt $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        vis: [$($vis:tt)*],
+        item: { $($item:tt)* },
+    ) => {
+        __bitflags_item_attrs! {
+            options: [$($rest)*],
+            attrs: [$($attrs)*],
+            vis: [$($vis)*],
+            item: { $($item)* },
+        }
+    };
+    // Once all options are processed, generate the item
+    (
+        options: [],
+        attrs: [$($attrs:tt)*],
+        vis: [$($vis:tt)*],
+        item: { $($item:tt)* },
+    ) => {
+        $($attrs)*
+        $($vis)* $($item)*
+    };
+}
+
+/// A macro that collects the aliases of a flag from its `#[bitflags(alias = "..")]` options.
+///
+/// The options are split from the other attributes by `__bitflags_options`, so long doc comments
+/// don't run into the recursion limit. This macro is then a token-tree muncher that looks at each
+/// option in turn. It produces an array of the aliases, or with `insert: [table[i] = value]`,
+/// statements that add each alias to `table` as a flag with `value`. The value isn't
+/// mentioned at all if the flag doesn't have any aliases.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_flag_aliases {
+    // Entrypoint: Split the options out from the other attributes
+    (
+        $(#[$inner:ident $($args:tt)*])*
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$(#[$inner $($args)*])*],
+            attrs: [],
+            options: [],
+            then: { $crate::__bitflags_flag_aliases },
+            args: {
+                aliases: [],
+                insert: [],
+            },
+        }
+    };
+    (
+        insert: [$table:ident[$i:ident] = $value:expr],
+        $(#[$inner:ident $($args:tt)*])*
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$(#[$inner $($args)*])*],
+            attrs: [],
+            options: [],
+            then: { $crate::__bitflags_flag_aliases },
+            args: {
+                aliases: [],
+                insert: [$table, $i, $value],
+            },
+        }
+    };
+    // Process the next option on the flag
+    // `alias = ".."`: The flag can also be parsed from this name
+    (
+        options: [alias = $alias:literal , $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($aliases:tt)*],
+        insert: [$($insert:tt)*],
+    ) => {
+        __bitflags_flag_aliases! {
+            options: [$($rest)*],
+            attrs: [],
+            aliases: [$($aliases)* $alias,],
+            insert: [$($insert)*],
+        }
+    };
+    // Process the next option on the flag
+    // `rename = ".."`: Handled by `__bitflags_flag_name`
+    (
+        options: [rename = $rename:literal , $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($aliases:tt)*],
+        insert: [$($insert:tt)*],
+    ) => {
+        __bitflags_flag_aliases! {
+            options: [$($rest)*],
+            attrs: [],
+            aliases: [$($aliases)*],
+            insert: [$($insert)*],
+        }
+    };
+    // Process the next option on the flag
+    // `vis = ..`: Handled by `__bitflags_item_attrs`
+    (
+        options: [vis = $fvis:vis , $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($aliases:tt)*],
+        insert: [$($insert:tt)*],
+    ) => {
+        __bitflags_flag_aliases! {
+            options: [$($rest)*],
+            attrs: [],
+            aliases: [$($aliases)*],
+            insert: [$($insert)*],
+        }
+    };
+    // Process the next option on the flag
+    // `,`: A trailing comma, or an empty `#[bitflags()]` attribute
+    (
+        options: [, $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($aliases:tt)*],
+        insert: [$($insert:tt)*],
+    ) => {
+        __bitflags_flag_aliases! {
+            options: [$($rest)*],
+            attrs: [],
+            aliases: [$($aliases)*],
+            insert: [$($insert)*],
+        }
+    };
+    // Once all options are processed, generate the array of aliases
+    (
+        options: [],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($alias:literal,)*],
+        insert: [],
+    ) => {
+        [$($alias),*]
+    };
+    (
+        options: [],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($alias:literal,)*],
+        insert: [$table:ident, $i:ident, $value:expr],
+    ) => {
+        $(
+            $table[$i] = $crate::Flag::new($alias, $value);
+            $i += 1;
+        )*
+    };
+    // Unrecognized options are reported when generating the array of aliases
+    (
+        options: [$option:tt $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($aliases:tt)*],
+        insert: [$table:ident, $i:ident, $value:expr],
+    ) => {};
+    (
+        options: [$option:tt $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        aliases: [$($aliases:tt)*],
+        insert: [$($insert:tt)*],
+    ) => {
+        $crate::__private::core::compile_error!($crate::__private::core::concat!(
+            "unrecognized `bitflags` flag option `",
+            $crate::__private::core::stringify!($option),
+            "`"
+        ))
+    };
+}
+
+/// A macro that produces the name of a flag, which is its `#[bitflags(rename = "..")]` option
+/// if it has one, or its identifier otherwise.
+///
+/// The options are split from the other attributes by `__bitflags_options`, so long doc comments
+/// don't run into the recursion limit. This macro is then a token-tree muncher that looks at each
+/// option in turn. Unrecognized options are reported by `__bitflags_flag_aliases`.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_flag_name {
+    // Entrypoint: Split the options out from the other attributes
+    (
+        $Flag:ident
+        $(#[$inner:ident $($args:tt)*])*
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$(#[$inner $($args)*])*],
+            attrs: [],
+            options: [],
+            then: { $crate::__bitflags_flag_name },
+            args: {
+                flag: $Flag,
+                rename: [],
+            },
+        }
+    };
+    // Process the next option on the flag
+    // `rename = ".."`: The flag is formatted and parsed using this name
+    (
+        options: [rename = $rename:literal , $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        flag: $Flag:ident,
+        rename: [],
+    ) => {
+        __bitflags_flag_name! {
+            options: [$($rest)*],
+            attrs: [],
+            flag: $Flag,
+            rename: [$rename],
+        }
+    };
+    (
+        options: [rename = $rename:literal , $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        flag: $Flag:ident,
+        rename: [$($prev:tt)*],
+    ) => {
+        $crate::__private::core::compile_error!($crate::__private::core::concat!(
+            "the `",
+            $crate::__private::core::stringify!($Flag),
+            "` flag can only be renamed once"
+        ))
+    };
+    // Process the next option on the flag
+    // `$other`: Other options are skipped, because they're handled (or reported) elsewhere
+    (
+        options: [$other:tt $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        flag: $Flag:ident,
+        rename: [$($rename:tt)*],
+    ) => {
+        __bitflags_flag_name! {
+            options: [$($rest)*],
+            attrs: [],
+            flag: $Flag,
+            rename: [$($rename)*],
+        }
+    };
+    // Once all options are processed, generate the name
+    (
+        options: [],
+        attrs: [$($attrs:tt)*],
+        flag: $Flag:ident,
+        rename: [],
+    ) => {
+        $crate::__private::core::stringify!($Flag)
+    };
+    (
+        options: [],
+        attrs: [$($attrs:tt)*],
+        flag: $Flag:ident,
+        rename: [$rename:literal],
+    ) => {
+        $rename
+    };
+}
+
 /// Implement a flag, which may be a wildcard `_`.
 #[macro_export(local_inner_macros)]
 #[doc(hidden)]
@@ -907,6 +2599,1244 @@ macro_rules! __bitflags_flag {
     };
 }
 
+/// A macro that declares the same flags for multiple flags types, like `struct A: u8, B: u32`.
+///
+/// This macro is a token-tree muncher that looks at each flags type in turn.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_widths {
+    (
+        attrs: [$($attrs:tt)*],
+        vis: $vis:vis,
+        types: [$BitFlags:ident: $T:ty, $($rest:tt)*],
+        flags: { $($flags:tt)* },
+    ) => {
+        bitflags! {
+            $($attrs)*
+            $vis struct $BitFlags: $T {
+                $($flags)*
+            }
+        }
+
+        __bitflags_widths! {
+            attrs: [$($attrs)*],
+            vis: $vis,
+            types: [$($rest)*],
+            flags: { $($flags)* },
+        }
+    };
+    (
+        attrs: [$($attrs:tt)*],
+        vis: $vis:vis,
+        types: [],
+        flags: { $($flags:tt)* },
+    ) => {};
+}
+
+/// A macro that assigns values to flags that are declared without one, like `const A;`.
+///
+/// This macro is a token-tree muncher that looks at up to 8 flags at a time, so a flags type
+/// with lots of flags stays well under the recursion limit. A flag without a value is given the
+/// lowest bit that isn't set in any flag declared before it. Once every flag has a value, the
+/// flags type is passed back to the `bitflags` macro to generate.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_auto {
+    // Process the next 8 flags
+    (
+        decl: { $($decl:tt)* },
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        taken: [$($taken:tt)*],
+        auto: [$($auto:tt)*],
+        flags: [$($flags:tt)*],
+        unprocessed: [
+            $(#[$inner0:ident $($args0:tt)*])*
+            const $Flag0:tt $(= $value0:expr)?;
+            $(#[$inner1:ident $($args1:tt)*])*
+            const $Flag1:tt $(= $value1:expr)?;
+            $(#[$inner2:ident $($args2:tt)*])*
+            const $Flag2:tt $(= $value2:expr)?;
+            $(#[$inner3:ident $($args3:tt)*])*
+            const $Flag3:tt $(= $value3:expr)?;
+            $(#[$inner4:ident $($args4:tt)*])*
+            const $Flag4:tt $(= $value4:expr)?;
+            $(#[$inner5:ident $($args5:tt)*])*
+            const $Flag5:tt $(= $value5:expr)?;
+            $(#[$inner6:ident $($args6:tt)*])*
+            const $Flag6:tt $(= $value6:expr)?;
+            $(#[$inner7:ident $($args7:tt)*])*
+            const $Flag7:tt $(= $value7:expr)?;
+            $($rest:tt)*
+        ],
+        rest: [$($t:tt)*],
+    ) => {
+        __bitflags_auto! {
+            decl: { $($decl)* },
+            name: $BitFlags,
+            bits: $T,
+            taken: [
+                $($taken)*
+                { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                { $(#[$inner2 $($args2)*])* { __bitflags_auto_flag!(taken: $Flag2 $(= $value2)?) } }
+                { $(#[$inner3 $($args3)*])* { __bitflags_auto_flag!(taken: $Flag3 $(= $value3)?) } }
+                { $(#[$inner4 $($args4)*])* { __bitflags_auto_flag!(taken: $Flag4 $(= $value4)?) } }
+                { $(#[$inner5 $($args5)*])* { __bitflags_auto_flag!(taken: $Flag5 $(= $value5)?) } }
+                { $(#[$inner6 $($args6)*])* { __bitflags_auto_flag!(taken: $Flag6 $(= $value6)?) } }
+                { $(#[$inner7 $($args7)*])* { __bitflags_auto_flag!(taken: $Flag7 $(= $value7)?) } }
+            ],
+            auto: [
+                $($auto)*
+                { $(#[$inner0 $($args0)*])* const $Flag0 $(= $value0)? }
+                { $(#[$inner1 $($args1)*])* const $Flag1 $(= $value1)? }
+                { $(#[$inner2 $($args2)*])* const $Flag2 $(= $value2)? }
+                { $(#[$inner3 $($args3)*])* const $Flag3 $(= $value3)? }
+                { $(#[$inner4 $($args4)*])* const $Flag4 $(= $value4)? }
+                { $(#[$inner5 $($args5)*])* const $Flag5 $(= $value5)? }
+                { $(#[$inner6 $($args6)*])* const $Flag6 $(= $value6)? }
+                { $(#[$inner7 $($args7)*])* const $Flag7 $(= $value7)? }
+            ],
+            flags: [
+                $($flags)*
+                $(#[$inner0 $($args0)*])*
+                const $Flag0 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                    ],
+                    $Flag0 $(= $value0)?
+                );
+                $(#[$inner1 $($args1)*])*
+                const $Flag1 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                    ],
+                    $Flag1 $(= $value1)?
+                );
+                $(#[$inner2 $($args2)*])*
+                const $Flag2 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                            { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                    ],
+                    $Flag2 $(= $value2)?
+                );
+                $(#[$inner3 $($args3)*])*
+                const $Flag3 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                            { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                            { $(#[$inner2 $($args2)*])* { __bitflags_auto_flag!(taken: $Flag2 $(= $value2)?) } }
+                    ],
+                    $Flag3 $(= $value3)?
+                );
+                $(#[$inner4 $($args4)*])*
+                const $Flag4 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                            { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                            { $(#[$inner2 $($args2)*])* { __bitflags_auto_flag!(taken: $Flag2 $(= $value2)?) } }
+                            { $(#[$inner3 $($args3)*])* { __bitflags_auto_flag!(taken: $Flag3 $(= $value3)?) } }
+                    ],
+                    $Flag4 $(= $value4)?
+                );
+                $(#[$inner5 $($args5)*])*
+                const $Flag5 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                            { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                            { $(#[$inner2 $($args2)*])* { __bitflags_auto_flag!(taken: $Flag2 $(= $value2)?) } }
+                            { $(#[$inner3 $($args3)*])* { __bitflags_auto_flag!(taken: $Flag3 $(= $value3)?) } }
+                            { $(#[$inner4 $($args4)*])* { __bitflags_auto_flag!(taken: $Flag4 $(= $value4)?) } }
+                    ],
+                    $Flag5 $(= $value5)?
+                );
+                $(#[$inner6 $($args6)*])*
+                const $Flag6 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                            { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                            { $(#[$inner2 $($args2)*])* { __bitflags_auto_flag!(taken: $Flag2 $(= $value2)?) } }
+                            { $(#[$inner3 $($args3)*])* { __bitflags_auto_flag!(taken: $Flag3 $(= $value3)?) } }
+                            { $(#[$inner4 $($args4)*])* { __bitflags_auto_flag!(taken: $Flag4 $(= $value4)?) } }
+                            { $(#[$inner5 $($args5)*])* { __bitflags_auto_flag!(taken: $Flag5 $(= $value5)?) } }
+                    ],
+                    $Flag6 $(= $value6)?
+                );
+                $(#[$inner7 $($args7)*])*
+                const $Flag7 = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [
+                        $($taken)*
+                            { $(#[$inner0 $($args0)*])* { __bitflags_auto_flag!(taken: $Flag0 $(= $value0)?) } }
+                            { $(#[$inner1 $($args1)*])* { __bitflags_auto_flag!(taken: $Flag1 $(= $value1)?) } }
+                            { $(#[$inner2 $($args2)*])* { __bitflags_auto_flag!(taken: $Flag2 $(= $value2)?) } }
+                            { $(#[$inner3 $($args3)*])* { __bitflags_auto_flag!(taken: $Flag3 $(= $value3)?) } }
+                            { $(#[$inner4 $($args4)*])* { __bitflags_auto_flag!(taken: $Flag4 $(= $value4)?) } }
+                            { $(#[$inner5 $($args5)*])* { __bitflags_auto_flag!(taken: $Flag5 $(= $value5)?) } }
+                            { $(#[$inner6 $($args6)*])* { __bitflags_auto_flag!(taken: $Flag6 $(= $value6)?) } }
+                    ],
+                    $Flag7 $(= $value7)?
+                );
+            ],
+            unprocessed: [$($rest)*],
+            rest: [$($t)*],
+        }
+    };
+    // Process the next flag
+    (
+        decl: { $($decl:tt)* },
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        taken: [$($taken:tt)*],
+        auto: [$($auto:tt)*],
+        flags: [$($flags:tt)*],
+        unprocessed: [
+            $(#[$inner:ident $($args:tt)*])*
+            const $Flag:tt $(= $value:expr)?;
+            $($rest:tt)*
+        ],
+        rest: [$($t:tt)*],
+    ) => {
+        __bitflags_auto! {
+            decl: { $($decl)* },
+            name: $BitFlags,
+            bits: $T,
+            taken: [
+                $($taken)*
+                { $(#[$inner $($args)*])* { __bitflags_auto_flag!(taken: $Flag $(= $value)?) } }
+            ],
+            auto: [
+                $($auto)*
+                { $(#[$inner $($args)*])* const $Flag $(= $value)? }
+            ],
+            flags: [
+                $($flags)*
+                $(#[$inner $($args)*])*
+                const $Flag = __bitflags_auto_flag!(
+                    value: $T,
+                    taken: [$($taken)*],
+                    $Flag $(= $value)?
+                );
+            ],
+            unprocessed: [$($rest)*],
+            rest: [$($t)*],
+        }
+    };
+    // Once all flags have values, generate the flags type
+    (
+        decl: { $($decl:tt)* },
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        taken: [$($taken:tt)*],
+        auto: [$({ $(#[$inner:ident $($args:tt)*])* const $Flag:tt $(= $value:expr)? })*],
+        flags: [$($flags:tt)*],
+        unprocessed: [],
+        rest: [$($t:tt)*],
+    ) => {
+        bitflags! {
+            $($decl)* {
+                $($flags)*
+            }
+        }
+
+        #[allow(deprecated)]
+        const _: () = {
+            // Named so that it appears in the error when there are no bits left for a flag
+            const AUTO_FLAGS_MUST_HAVE_BITS: () = {
+                let mut overflow = false;
+
+                $(
+                    __bitflags_expr_safe_attrs!(
+                        $(#[$inner $($args)*])*
+                        {{
+                            overflow = overflow || __bitflags_auto_flag!(overflow: $BitFlags: $T, $Flag $(= $value)?);
+                        }}
+                    );
+                )*
+
+                // If every bit is taken before a numbered flag then fail to compile. This indexes
+                // out-of-bounds instead of panicking so it's supported by older compilers
+                let no_bits_left_for_flag = [()];
+                no_bits_left_for_flag[overflow as usize]
+            };
+
+            AUTO_FLAGS_MUST_HAVE_BITS
+        };
+
+        bitflags! {
+            $($t)*
+        }
+    };
+}
+
+/// A macro that handles a single flag for `__bitflags_auto`, depending on whether it has a value.
+///
+/// - `taken`: The bits of the flag, to be skipped by the flags after it.
+/// - `value`: The value of the flag. A flag without a value gets the lowest bit that isn't
+///   in `taken`, or no bits if they're all taken.
+/// - `overflow`: Whether the flag was numbered, but there were no bits left for it.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_auto_flag {
+    (taken: _ = $value:expr) => {
+        $value
+    };
+    (taken: $Flag:ident $(= $value:expr)?) => {
+        Self::$Flag.bits()
+    };
+    (
+        value: $T:ty,
+        taken: [$({ $(#[$inner:ident $($args:tt)*])* $taken:block })*],
+        $Flag:tt = $value:expr
+    ) => {
+        $value
+    };
+    (
+        value: $T:ty,
+        taken: [$({ $(#[$inner:ident $($args:tt)*])* $taken:block })*],
+        $Flag:ident
+    ) => {
+        {
+            let mut taken = <$T as $crate::Bits>::EMPTY;
+
+            $(
+                __bitflags_expr_safe_attrs!(
+                    $(#[$inner $($args)*])*
+                    {{
+                        taken = taken | $taken;
+                    }}
+                );
+            )*
+
+            // Isolate the lowest unset bit
+            !taken & taken.wrapping_add(1)
+        }
+    };
+    (overflow: $BitFlags:ident: $T:ty, $Flag:tt = $value:expr) => {
+        false
+    };
+    (overflow: $BitFlags:ident: $T:ty, $Flag:ident) => {
+        $BitFlags::$Flag.bits() == <$T as $crate::Bits>::EMPTY
+    };
+}
+
+/// A macro that splits `#[bitflags(..)]` options from the other attributes on a flags type or flag,
+/// and then passes both to the macro in `then`.
+///
+/// This macro is a token-tree muncher, but it doesn't look at each attribute in turn. Long doc
+/// comments are made of lots of attributes, so to stay well under the recursion limit it looks at
+/// up to 8 attributes at a time. The first rules find the first `#[bitflags(..)]` attribute among
+/// them, and if there isn't one then all 8 are passed through at once.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_options {
+    // The next `#[bitflags(..)]` attribute is the first one
+    (
+        unprocessed: [
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)*],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 1 other attribute
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 2 other attributes
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 3 other attributes
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[$($a2:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*] #[$($a2)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 4 other attributes
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[$($a2:tt)*]
+            #[$($a3:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*] #[$($a2)*] #[$($a3)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 5 other attributes
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[$($a2:tt)*]
+            #[$($a3:tt)*]
+            #[$($a4:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*] #[$($a2)*] #[$($a3)*] #[$($a4)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 6 other attributes
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[$($a2:tt)*]
+            #[$($a3:tt)*]
+            #[$($a4:tt)*]
+            #[$($a5:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*] #[$($a2)*] #[$($a3)*] #[$($a4)*] #[$($a5)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // The next `#[bitflags(..)]` attribute comes after 7 other attributes
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[$($a2:tt)*]
+            #[$($a3:tt)*]
+            #[$($a4:tt)*]
+            #[$($a5:tt)*]
+            #[$($a6:tt)*]
+            #[bitflags($($option:tt)*)]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*] #[$($a2)*] #[$($a3)*] #[$($a4)*] #[$($a5)*] #[$($a6)*]],
+            options: [$($options)* $($option)* ,],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // None of the next 8 attributes are `#[bitflags(..)]`, so they're all passed through
+    (
+        unprocessed: [
+            #[$($a0:tt)*]
+            #[$($a1:tt)*]
+            #[$($a2:tt)*]
+            #[$($a3:tt)*]
+            #[$($a4:tt)*]
+            #[$($a5:tt)*]
+            #[$($a6:tt)*]
+            #[$($a7:tt)*]
+            $($rest:tt)*
+        ],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        __bitflags_options! {
+            unprocessed: [$($rest)*],
+            attrs: [$($attrs)* #[$($a0)*] #[$($a1)*] #[$($a2)*] #[$($a3)*] #[$($a4)*] #[$($a5)*] #[$($a6)*] #[$($a7)*]],
+            options: [$($options)*],
+            then: { $($then)* },
+            args: { $($args)* },
+        }
+    };
+    // There are fewer than 8 attributes left and none of them are `#[bitflags(..)]`,
+    // so pass them through and hand the results to the next macro
+    (
+        unprocessed: [$(#[$($attr:tt)*])*],
+        attrs: [$($attrs:tt)*],
+        options: [$($options:tt)*],
+        then: { $($then:tt)* },
+        args: { $($args:tt)* },
+    ) => {
+        $($then)*! {
+            options: [$($options)*],
+            attrs: [$($attrs)* $(#[$($attr)*])*],
+            $($args)*
+        }
+    };
+}
+
+/// A macro that generates operators for a flags type, unless the `no_ops` option is set.
+///
+/// This macro is a token-tree muncher that looks through the options for `no_ops`.
+/// The operators themselves also depend on the `custom_bits` option, so all the options
+/// are passed along.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_ops {
+    (
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident
+    ) => {
+        __bitflags_ops! {
+            unprocessed: [$($option)*],
+            options: [$($option)*],
+            $PublicBitFlags
+        }
+    };
+    (
+        unprocessed: [no_ops $($rest:tt)*],
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident
+    ) => {};
+    (
+        unprocessed: [$other:tt $($rest:tt)*],
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident
+    ) => {
+        __bitflags_ops! {
+            unprocessed: [$($rest)*],
+            options: [$($option)*],
+            $PublicBitFlags
+        }
+    };
+    (
+        unprocessed: [],
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident
+    ) => {
+        __impl_public_bitflags_ops! {
+            options: [$($option)*],
+            $PublicBitFlags
+        }
+    };
+}
+
+/// A macro that sets `Flags::EMPTY_NAME` for the `empty` option.
+///
+/// This macro is a token-tree muncher that looks through the options for `empty = ".."`.
+/// If there isn't one then nothing is generated, so the default empty text is used.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_empty_name {
+    (
+        options: [empty = $empty:literal $($rest:tt)*],
+    ) => {
+        const EMPTY_NAME: &'static str = $empty;
+    };
+    (
+        options: [$other:tt $($rest:tt)*],
+    ) => {
+        __bitflags_empty_name! {
+            options: [$($rest)*],
+        }
+    };
+    (
+        options: [],
+    ) => {};
+}
+
+/// A macro that produces `Flags::FLAGS`, including the flags imported with `use $Base;`.
+///
+/// This macro is a token-tree muncher that looks through the options for `import = $Base`.
+/// If there aren't any then the flags declared in the flags type are used as-is. Otherwise,
+/// they're copied into a table along with the flags of each imported flags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_import_flags {
+    (
+        options: [import = $Base:ty, $($rest:tt)*],
+        imports: [$($imports:tt)*],
+        name: $PublicBitFlags:ident,
+        flags: $flags:expr,
+    ) => {
+        __bitflags_import_flags! {
+            options: [$($rest)*],
+            imports: [$($imports)* { $Base }],
+            name: $PublicBitFlags,
+            flags: $flags,
+        }
+    };
+    (
+        options: [$other:tt $($rest:tt)*],
+        imports: [$($imports:tt)*],
+        name: $PublicBitFlags:ident,
+        flags: $flags:expr,
+    ) => {
+        __bitflags_import_flags! {
+            options: [$($rest)*],
+            imports: [$($imports)*],
+            name: $PublicBitFlags,
+            flags: $flags,
+        }
+    };
+    (
+        options: [],
+        imports: [],
+        name: $PublicBitFlags:ident,
+        flags: $flags:expr,
+    ) => {
+        $flags
+    };
+    (
+        options: [],
+        imports: [$({ $Base:ty })+],
+        name: $PublicBitFlags:ident,
+        flags: $flags:expr,
+    ) => {{
+        const DECLARED: &[$crate::Flag<$PublicBitFlags>] = $flags;
+        const LEN: usize = DECLARED.len() $(+ <$Base as $crate::Flags>::FLAGS.len())+;
+
+        const TABLE: [$crate::Flag<$PublicBitFlags>; LEN] = {
+            const UNNAMED: $crate::Flag<$PublicBitFlags> =
+                $crate::Flag::new("", $PublicBitFlags::empty());
+
+            let mut table = [UNNAMED; LEN];
+            let mut i = 0;
+
+            // The flags declared in the flags type come first
+            while i < DECLARED.len() {
+                table[i] = $crate::Flag::new(
+                    DECLARED[i].name(),
+                    $PublicBitFlags::from_bits_retain(DECLARED[i].value().bits()),
+                );
+                i += 1;
+            }
+
+            $(
+                let imported = <$Base as $crate::Flags>::FLAGS;
+
+                let mut j = 0;
+                while j < imported.len() {
+                    table[i] = $crate::Flag::new(
+                        imported[j].name(),
+                        $PublicBitFlags::from_bits_retain(imported[j].value().bits()),
+                    );
+                    i += 1;
+                    j += 1;
+                }
+            )+
+
+            table
+        };
+
+        &TABLE
+    }};
+}
+
+/// A macro that generates an enum of named flags for the `kind` option.
+///
+/// This macro is a token-tree muncher that works in 3 stages:
+///
+/// 1. Look through the options for `kind = $Kind`. If there isn't one then nothing is generated.
+/// 2. Look through the attributes on the flags type for `#[derive]`s to copy to the enum.
+/// 3. Look through the flags for named ones. Unnamed flags don't get a variant.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_kind {
+    // Look for the `kind` option
+    // `kind = $Kind`: The enum is named `$Kind`
+    (
+        options: [kind = $Kind:ident $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            attrs: {
+                unprocessed: [$($attrs)*],
+                derives: [],
+            },
+            decl: { $($decl)* },
+        }
+    };
+    // Look for the `kind` option
+    // `$other`: Skip it
+    (
+        options: [$other:tt $($rest:tt)*],
+        attrs: [$($attrs:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        __bitflags_kind! {
+            options: [$($rest)*],
+            attrs: [$($attrs)*],
+            decl: { $($decl)* },
+        }
+    };
+    // There's no `kind` option, so there's nothing to generate
+    (
+        options: [],
+        attrs: [$($attrs:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {};
+    // The `kind` option needs to know the visibility and derives of the flags type,
+    // so it's not supported in `impl` mode
+    (
+        kind: $Kind:ident,
+        attrs: { $($attrs:tt)* },
+        decl: { impl $($decl:tt)* },
+    ) => {
+        $crate::__private::core::compile_error!(
+            "the `kind` option isn't supported in `impl` mode"
+        );
+    };
+    // Process the next attribute on the flags type
+    // `derive`: The derive is copied to the enum
+    (
+        kind: $Kind:ident,
+        attrs: {
+            unprocessed: [
+                #[derive $($args:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            derives: [$($derives:tt)*],
+        },
+        decl: { $($decl:tt)* },
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                derives: [$($derives)* #[derive $($args)*]],
+            },
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the flags type
+    // `$other`: The attribute only applies to the flags type
+    (
+        kind: $Kind:ident,
+        attrs: {
+            unprocessed: [
+                #[$($other:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            derives: [$($derives:tt)*],
+        },
+        decl: { $($decl:tt)* },
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                derives: [$($derives)*],
+            },
+            decl: { $($decl)* },
+        }
+    };
+    // Once all attributes are processed, start on the flags
+    (
+        kind: $Kind:ident,
+        attrs: {
+            unprocessed: [],
+            derives: [$($derives:tt)*],
+        },
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
+                $($flags:tt)*
+            }
+        },
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            derives: [$($derives)*],
+            vis: $vis,
+            name: $BitFlags,
+            bits: $T,
+            named: [],
+            unprocessed: [$($flags)*],
+        }
+    };
+    // Process the next flag
+    // `const _`: Unnamed flags don't get a variant
+    (
+        kind: $Kind:ident,
+        derives: [$($derives:tt)*],
+        vis: $vis:vis,
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        named: [$($named:tt)*],
+        unprocessed: [
+            $(#[$inner:ident $($args:tt)*])*
+            const _ = $value:expr;
+            $($rest:tt)*
+        ],
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            derives: [$($derives)*],
+            vis: $vis,
+            name: $BitFlags,
+            bits: $T,
+            named: [$($named)*],
+            unprocessed: [$($rest)*],
+        }
+    };
+    // Process the next flag
+    // `const $Flag`: Named flags get a variant
+    (
+        kind: $Kind:ident,
+        derives: [$($derives:tt)*],
+        vis: $vis:vis,
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        named: [$($named:tt)*],
+        unprocessed: [
+            $(#[$inner:ident $($args:tt)*])*
+            const $Flag:ident = $value:expr;
+            $($rest:tt)*
+        ],
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            derives: [$($derives)*],
+            vis: $vis,
+            name: $BitFlags,
+            bits: $T,
+            named: [$($named)*],
+            flag: {
+                name: $Flag,
+                unprocessed: [$(#[$inner $($args)*])*],
+                processed: [],
+            },
+            unprocessed: [$($rest)*],
+        }
+    };
+    // Process the next attribute on a named flag
+    // `bitflags`: Options for the flag aren't applied to its variant
+    (
+        kind: $Kind:ident,
+        derives: [$($derives:tt)*],
+        vis: $vis:vis,
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        named: [$($named:tt)*],
+        flag: {
+            name: $Flag:ident,
+            unprocessed: [
+                #[bitflags $($args:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        unprocessed: [$($rest:tt)*],
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            derives: [$($derives)*],
+            vis: $vis,
+            name: $BitFlags,
+            bits: $T,
+            named: [$($named)*],
+            flag: {
+                name: $Flag,
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            unprocessed: [$($rest)*],
+        }
+    };
+    // Process the next attribute on a named flag
+    // `$other`: The attribute is applied to its variant
+    (
+        kind: $Kind:ident,
+        derives: [$($derives:tt)*],
+        vis: $vis:vis,
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        named: [$($named:tt)*],
+        flag: {
+            name: $Flag:ident,
+            unprocessed: [
+                #[$other:ident $($args:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        unprocessed: [$($rest:tt)*],
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            derives: [$($derives)*],
+            vis: $vis,
+            name: $BitFlags,
+            bits: $T,
+            named: [$($named)*],
+            flag: {
+                name: $Flag,
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)* #[$other $($args)*]],
+            },
+            unprocessed: [$($rest)*],
+        }
+    };
+    // Once all attributes on a named flag are processed, move on to the next flag
+    (
+        kind: $Kind:ident,
+        derives: [$($derives:tt)*],
+        vis: $vis:vis,
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        named: [$($named:tt)*],
+        flag: {
+            name: $Flag:ident,
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        unprocessed: [$($rest:tt)*],
+    ) => {
+        __bitflags_kind! {
+            kind: $Kind,
+            derives: [$($derives)*],
+            vis: $vis,
+            name: $BitFlags,
+            bits: $T,
+            named: [
+                $($named)*
+                { $($processed)* $Flag }
+            ],
+            unprocessed: [$($rest)*],
+        }
+    };
+    // Once all flags are processed, generate the enum
+    (
+        kind: $Kind:ident,
+        derives: [$($derives:tt)*],
+        vis: $vis:vis,
+        name: $BitFlags:ident,
+        bits: $T:ty,
+        named: [$({ $(#[$inner:ident $($args:tt)*])* $Flag:ident })*],
+        unprocessed: [],
+    ) => {
+        #[doc = $crate::__private::core::concat!(
+            "A named flag of [`",
+            $crate::__private::core::stringify!($BitFlags),
+            "`].",
+        )]
+        $($derives)*
+        $vis enum $Kind {
+            $(
+                $(#[$inner $($args)*])*
+                $Flag,
+            )*
+        }
+
+        #[allow(
+            deprecated,
+            unused_doc_comments,
+            unused_attributes,
+            unused_variables,
+            unreachable_code,
+            irrefutable_let_patterns,
+        )]
+        const _: () = {
+            impl $crate::__private::core::convert::From<$Kind> for $BitFlags {
+                fn from(kind: $Kind) -> Self {
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                if let $Kind::$Flag = kind {
+                                    return $BitFlags::$Flag;
+                                }
+                            }}
+                        );
+                    )*
+
+                    // Every variant is handled above
+                    $crate::__private::core::unreachable!()
+                }
+            }
+
+            impl $crate::__private::core::convert::TryFrom<$BitFlags> for $Kind {
+                type Error = $BitFlags;
+
+                fn try_from(flags: $BitFlags) -> $crate::__private::core::result::Result<Self, Self::Error> {
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                if flags.bits() == $BitFlags::$Flag.bits() {
+                                    return $crate::__private::core::result::Result::Ok($Kind::$Flag);
+                                }
+                            }}
+                        );
+                    )*
+
+                    $crate::__private::core::result::Result::Err(flags)
+                }
+            }
+
+            impl $BitFlags {
+                /// Yield the named flags contained in this value as variants
+                #[doc = $crate::__private::core::concat!(
+                    "of [`",
+                    $crate::__private::core::stringify!($Kind),
+                    "`].",
+                )]
+                #[inline]
+                pub fn iter_kinds(&self) -> impl $crate::__private::core::iter::Iterator<Item = $Kind> {
+                    $crate::Flags::iter(self).filter_map(|flag| {
+                        <$Kind as $crate::__private::core::convert::TryFrom<$BitFlags>>::try_from(flag).ok()
+                    })
+                }
+            }
+
+            // Named so that it appears in the error when a named flag isn't a single bit
+            const KIND_FLAGS_MUST_BE_SINGLE_BITS: () = {
+                let flags = <$BitFlags as $crate::Flags>::FLAGS;
+
+                let mut not_single_bit = false;
+
+                let mut i = 0;
+                while i < flags.len() {
+                    if flags[i].is_named() {
+                        not_single_bit = not_single_bit || flags[i].value().bits().count_ones() != 1;
+                    }
+
+                    i += 1;
+                }
+
+                // If any named flag isn't a single bit then fail to compile. This indexes
+                // out-of-bounds instead of panicking so it's supported by older compilers
+                let flags_not_single_bits = [()];
+                flags_not_single_bits[not_single_bit as usize]
+            };
+
+            KIND_FLAGS_MUST_BE_SINGLE_BITS
+        };
+    };
+}
+
+/// Generate a flags type in `struct` mode.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __bitflags_struct {
+    (
+        options: [$($option:tt)*],
+        attrs: [$(#[$($outer:tt)*])*],
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        // Declared in the scope of the `bitflags!` call
+
+        // This type appears in the end-user's API
+        __declare_public_bitflags! {
+            $(#[$($outer)*])*
+            $vis struct $BitFlags
+        }
+
+        __bitflags_kind! {
+            options: [$($option)*],
+            attrs: [$(#[$($outer)*])*],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag = $value;
+                    )*
+                }
+            },
+        }
+
+        // Workaround for: https://github.com/bitflags/bitflags/issues/320
+        __impl_public_bitflags_consts! {
+            options: [$($option)*],
+            $BitFlags: $T {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag = $value;
+                )*
+            }
+        }
+
+        #[allow(
+            dead_code,
+            deprecated,
+            unused_doc_comments,
+            unused_attributes,
+            unused_mut,
+            unused_imports,
+            non_upper_case_globals,
+            clippy::assign_op_pattern,
+            clippy::indexing_slicing,
+            clippy::same_name_method,
+            clippy::iter_without_into_iter,
+        )]
+        const _: () = {
+            // Declared in a "hidden" scope that can't be reached directly
+            // These types don't appear in the end-user's API
+            __declare_internal_bitflags! {
+                options: [$($option)*],
+                $vis struct InternalBitFlags: $T
+            }
+
+            __impl_internal_bitflags! {
+                options: [$($option)*],
+                InternalBitFlags: $T, $BitFlags {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag = $value;
+                    )*
+                }
+            }
+
+            // This is where new library trait implementations can be added
+            // They can rely on the bits type being a primitive integer
+            __bitflags_custom_bits! {
+                options: [$($option)*],
+                primitive: {
+                    __impl_external_bitflags! {
+                        InternalBitFlags: $T, $BitFlags {
+                            $(
+                                $(#[$inner $($args)*])*
+                                const $Flag;
+                            )*
+                        }
+                    }
+                },
+                custom: {
+                    __bitflags_custom_bits_options! {
+                        options: [$($option)*],
+                    }
+                },
+            }
+
+            __impl_public_bitflags_forward! {
+                options: [$($option)*],
+                $BitFlags: $T, InternalBitFlags
+            }
+
+            __bitflags_ops! {
+                options: [$($option)*],
+                $BitFlags
+            }
+
+            __impl_public_bitflags_iter! {
+                options: [$($option)*],
+                $BitFlags: $T, $BitFlags
+            }
+
+            __impl_public_bitflags_io! {
+                options: [$($option)*],
+                $BitFlags: $T
+            }
+
+            __impl_public_bitflags_options! {
+                $BitFlags: $T {
+                    $($option)*
+                }
+            }
+        };
+
+    };
+}
+
 #[macro_use]
 mod public;
 #[macro_use]
@@ -0,0 +1,178 @@
+/*!
+Storing flags values with a niche.
+
+A [`NonZeroFlags`] stores a flags value in the non-zero integer matching its bits type, like
+[`NonZeroU32`](core::num::NonZeroU32) for a flags type with `u32` bits. That means
+`Option<NonZeroFlags<F>>` is the same size as `F`:
+
+```
+use core::mem::size_of;
+
+use bitflags::{bitflags, non_zero::NonZeroFlags};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Flags: u32 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!(size_of::<Flags>(), size_of::<Option<NonZeroFlags<Flags>>>());
+
+let stored = NonZeroFlags::new(Flags::A | Flags::B).unwrap();
+
+assert_eq!(Flags::A | Flags::B, stored.get());
+```
+
+The bits are stored inverted, so an empty flags value doesn't need a zero integer. The only
+flags value that can't be stored is one with every bit set, like `Flags::from_bits_retain(!0)`.
+That's only possible through unknown bits, unless the flags type defines every bit.
+*/
+
+use core::{fmt, hash, marker::PhantomData, num};
+
+use crate::{Bits, Flags};
+
+/**
+A bits type that has a matching non-zero integer type.
+*/
+pub trait NonZeroBits: Bits {
+    /// The non-zero integer type that stores these bits.
+    type NonZero: Copy + Eq + hash::Hash;
+
+    /// Convert bits into a non-zero integer, returning `None` if no bits are set.
+    fn to_non_zero(self) -> Option<Self::NonZero>;
+
+    /// Convert a non-zero integer back into bits.
+    fn from_non_zero(non_zero: Self::NonZero) -> Self;
+}
+
+macro_rules! impl_non_zero_bits {
+    ($($bits:ty => $non_zero:ident,)*) => {
+        $(
+            impl NonZeroBits for $bits {
+                type NonZero = num::$non_zero;
+
+                fn to_non_zero(self) -> Option<Self::NonZero> {
+                    num::$non_zero::new(self)
+                }
+
+                fn from_non_zero(non_zero: Self::NonZero) -> Self {
+                    non_zero.get()
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_bits! {
+    u8 => NonZeroU8,
+    i8 => NonZeroI8,
+    u16 => NonZeroU16,
+    i16 => NonZeroI16,
+    u32 => NonZeroU32,
+    i32 => NonZeroI32,
+    u64 => NonZeroU64,
+    i64 => NonZeroI64,
+    u128 => NonZeroU128,
+    i128 => NonZeroI128,
+    usize => NonZeroUsize,
+    isize => NonZeroIsize,
+}
+
+/**
+A flags value stored so that `Option<NonZeroFlags<F>>` is the same size as `F`.
+*/
+pub struct NonZeroFlags<F: Flags>
+where
+    F::Bits: NonZeroBits,
+{
+    inverted: <F::Bits as NonZeroBits>::NonZero,
+    _marker: PhantomData<fn() -> F>,
+}
+
+impl<F: Flags> NonZeroFlags<F>
+where
+    F::Bits: NonZeroBits,
+{
+    /**
+    Store a flags value.
+
+    This method will return `None` if every bit in the flags value is set.
+    */
+    pub fn new(flags: F) -> Option<Self> {
+        (!flags.bits()).to_non_zero().map(|inverted| NonZeroFlags {
+            inverted,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Store a flags value with all bits unset.
+    pub fn empty() -> Self {
+        match Self::new(F::empty()) {
+            Some(flags) => flags,
+            // An empty flags value is stored with all bits set, so it's never zero
+            None => unreachable!(),
+        }
+    }
+
+    /// Get the stored flags value.
+    pub fn get(self) -> F {
+        F::from_bits_retain(self.bits())
+    }
+
+    /// Get the underlying bits value of the stored flags value.
+    pub fn bits(self) -> F::Bits {
+        !<F::Bits as NonZeroBits>::from_non_zero(self.inverted)
+    }
+}
+
+impl<F: Flags> Clone for NonZeroFlags<F>
+where
+    F::Bits: NonZeroBits,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Flags> Copy for NonZeroFlags<F> where F::Bits: NonZeroBits {}
+
+impl<F: Flags> PartialEq for NonZeroFlags<F>
+where
+    F::Bits: NonZeroBits,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inverted == other.inverted
+    }
+}
+
+impl<F: Flags> Eq for NonZeroFlags<F> where F::Bits: NonZeroBits {}
+
+impl<F: Flags> hash::Hash for NonZeroFlags<F>
+where
+    F::Bits: NonZeroBits,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.inverted.hash(state)
+    }
+}
+
+impl<F: Flags> Default for NonZeroFlags<F>
+where
+    F::Bits: NonZeroBits,
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<F: Flags + fmt::Debug> fmt::Debug for NonZeroFlags<F>
+where
+    F::Bits: NonZeroBits,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NonZeroFlags").field(&self.get()).finish()
+    }
+}
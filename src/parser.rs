@@ -6,7 +6,7 @@ Format and parse a flags value as text using the following grammar:
 - _Flags:_ (_Whitespace_ _Flag_ _Whitespace_)`|`*
 - _Flag:_ _Name_ | _Hex Number_
 - _Name:_ The name of any defined flag
-- _Hex Number_: `0x`([0-9a-fA-F])*
+- _Hex Number_: (`0x` | `0X`)([0-9a-fA-F])*
 - _Whitespace_: (\s)*
 
 As an example, this is how `Flags::A | Flags::B | 0x0c` can be represented as text:
@@ -26,11 +26,65 @@ Note that identifiers are *case-sensitive*, so the following is *not equivalent*
 ```text
 a|b|0x0C
 ```
+
+An empty flags value is formatted as an empty string, so it roundtrips through formats like
+`serde`'s human-readable form. The `Debug` output of generated flags types writes `0x0`
+instead, so an empty flags value doesn't disappear from logs. Both forms parse to an empty
+flags value:
+
+```
+# use bitflags::{bitflags, parser};
+# bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     struct Flags: u8 {
+#         const A = 1;
+#     }
+# }
+let mut text = String::new();
+parser::to_writer(&Flags::empty(), &mut text).unwrap();
+
+assert_eq!("", text);
+assert_eq!("Flags(0x0)", format!("{:?}", Flags::empty()));
+
+assert_eq!(Flags::empty(), parser::from_str::<Flags>("").unwrap());
+assert_eq!(Flags::empty(), parser::from_str::<Flags>("0x0").unwrap());
+```
+
+The `empty = ".."` option on a generated flags type formats an empty flags value as the given text
+instead, in both its text and `Debug` output. The text is parsed back to an empty flags value:
+
+```
+# use bitflags::{bitflags, parser};
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(empty = "none")]
+    struct Flags: u8 {
+        const A = 1;
+    }
+}
+
+let mut text = String::new();
+parser::to_writer(&Flags::empty(), &mut text).unwrap();
+
+assert_eq!("none", text);
+assert_eq!("Flags(none)", format!("{:?}", Flags::empty()));
+
+assert_eq!(Flags::empty(), parser::from_str::<Flags>("none").unwrap());
+assert_eq!(Flags::empty(), parser::from_str::<Flags>("").unwrap());
+```
+
+Use [`from_str_ignore_case`] to parse names without regard to ASCII case.
+
+Use [`from_str_with`] and [`to_writer_with`] to parse and format flags with a separator other than `|`.
 */
 
 #![allow(clippy::let_unit_value)]
 
-use core::fmt::{self, Write};
+use core::{
+    fmt::{self, Write},
+    num::{IntErrorKind, ParseIntError},
+    ops::Range,
+};
 
 use crate::{Bits, Flags};
 
@@ -38,8 +92,43 @@ use crate::{Bits, Flags};
 Write a flags value as text.
 
 Any bits that aren't part of a contained flag will be formatted as a hex number.
+
+This function doesn't allocate, so it can write into a fixed-capacity buffer.
+Any error returned by the writer, like running out of space, is returned as a [`fmt::Error`].
+The `Display` implementation and `serde` support of generated flags types both use this function.
+*/
+pub fn to_writer<B: Flags>(flags: &B, writer: impl Write) -> Result<(), fmt::Error>
+where
+    B::Bits: WriteHex,
+{
+    to_writer_with(flags, " | ", writer)
+}
+
+/**
+Write a flags value as text, using a custom separator between flags.
+
+This function is like [`to_writer`], except flags are separated by `separator` instead of ` | `.
+The separator is written as-is, so any whitespace around it needs to be included.
+
+```
+# bitflags::bitflags! {
+#     struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+let mut s = String::new();
+bitflags::parser::to_writer_with(&(Flags::A | Flags::B), ",", &mut s)?;
+
+assert_eq!("A,B", s);
+# Ok::<(), std::fmt::Error>(())
+```
 */
-pub fn to_writer<B: Flags>(flags: &B, mut writer: impl Write) -> Result<(), fmt::Error>
+pub fn to_writer_with<B: Flags>(
+    flags: &B,
+    separator: &str,
+    mut writer: impl Write,
+) -> Result<(), fmt::Error>
 where
     B::Bits: WriteHex,
 {
@@ -51,12 +140,17 @@ where
     // followed by a hex number of any remaining bits that are set
     // but don't correspond to any flags.
 
+    // An empty flags value is written as its own text, which may also be empty
+    if flags.bits() == B::Bits::EMPTY {
+        return writer.write_str(B::EMPTY_NAME);
+    }
+
     // Iterate over known flag values
     let mut first = true;
     let mut iter = flags.iter_names();
     for (name, _) in &mut iter {
         if !first {
-            writer.write_str(" | ")?;
+            writer.write_str(separator)?;
         }
 
         first = false;
@@ -67,7 +161,7 @@ where
     let remaining = iter.remaining().bits();
     if remaining != B::Bits::EMPTY {
         if !first {
-            writer.write_str(" | ")?;
+            writer.write_str(separator)?;
         }
 
         writer.write_str("0x")?;
@@ -77,8 +171,10 @@ where
     fmt::Result::Ok(())
 }
 
+#[cfg(feature = "serde")]
 pub(crate) struct AsDisplay<'a, B>(pub(crate) &'a B);
 
+#[cfg(feature = "serde")]
 impl<'a, B: Flags> fmt::Display for AsDisplay<'a, B>
 where
     B::Bits: WriteHex,
@@ -88,6 +184,242 @@ where
     }
 }
 
+/// Format a flags value the same way as the `Debug` implementation of generated flags types.
+#[doc(hidden)]
+pub struct AsDebug<'a, B>(pub &'a B);
+
+impl<'a, B: Flags> fmt::Debug for AsDebug<'a, B>
+where
+    B::Bits: WriteHex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bits = self.0.bits();
+
+        if f.alternate() {
+            // The alternate form breaks the flags value down into its bits
+            // and whether each named flag is contained in it
+            let mut map = f.debug_map();
+
+            map.entry(&format_args!("bits"), &AsHex(bits));
+
+            for flag in B::FLAGS {
+                if flag.is_unnamed() {
+                    continue;
+                }
+
+                let flag_bits = flag.value().bits();
+
+                map.entry(
+                    &format_args!("{}", flag.name()),
+                    &(bits & flag_bits == flag_bits),
+                );
+            }
+
+            map.finish()
+        } else if bits == B::Bits::EMPTY && !B::EMPTY_NAME.is_empty() {
+            f.write_str(B::EMPTY_NAME)
+        } else if bits == B::Bits::EMPTY {
+            // If no flags are set then write an empty hex flag to avoid
+            // writing an empty string. In some contexts, like serialization,
+            // an empty string is preferable, but it may be unexpected in
+            // others for a format not to produce any output.
+            //
+            // We can remove this `0x0` and remain compatible with `FromStr`,
+            // because an empty string will still parse to an empty set of flags,
+            // just like `0x0` does.
+            fmt::Debug::fmt(&AsHex(bits), f)
+        } else {
+            to_writer(self.0, f)
+        }
+    }
+}
+
+struct AsHex<B>(B);
+
+impl<B: WriteHex> fmt::Debug for AsHex<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.write_hex(f)
+    }
+}
+
+/// Whether two strings are equal, in a `const` context.
+#[doc(hidden)]
+pub const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Whether a string can be parsed as the name of a flag, in a `const` context.
+///
+/// A name can't be empty, contain a `|`, start or end with whitespace, or start like a hex number.
+#[doc(hidden)]
+pub const fn is_valid_name(name: &str) -> bool {
+    let name = name.as_bytes();
+
+    if name.is_empty()
+        || name[0].is_ascii_whitespace()
+        || name[name.len() - 1].is_ascii_whitespace()
+    {
+        return false;
+    }
+
+    if name.len() >= 2 && name[0] == b'0' && (name[1] == b'x' || name[1] == b'X') {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < name.len() {
+        if name[i] == b'|' {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Find the flag starting at `cursor` in a string of `|` separated flags, in a `const` context.
+///
+/// Returns the range of the flag with any ASCII whitespace around it trimmed, and where the flag
+/// after it starts, if there is one.
+#[doc(hidden)]
+pub const fn const_next_flag(input: &str, cursor: usize) -> (usize, usize, Option<usize>) {
+    let input = input.as_bytes();
+
+    let mut end = cursor;
+    while end < input.len() && input[end] != b'|' {
+        end += 1;
+    }
+
+    let next = if end < input.len() {
+        Some(end + 1)
+    } else {
+        None
+    };
+
+    let mut start = cursor;
+    while start < end && input[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && input[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+
+    (start, end, next)
+}
+
+/// Whether a string is empty or only ASCII whitespace, in a `const` context.
+#[doc(hidden)]
+pub const fn const_is_blank(input: &str) -> bool {
+    let input = input.as_bytes();
+
+    let mut i = 0;
+    while i < input.len() {
+        if !input[i].is_ascii_whitespace() {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Whether a range of a string is equal to `name`, in a `const` context.
+#[doc(hidden)]
+pub const fn const_range_eq(input: &str, start: usize, end: usize, name: &str) -> bool {
+    let (input, name) = (input.as_bytes(), name.as_bytes());
+
+    if end - start != name.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < name.len() {
+        if input[start + i] != name[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Parse a range of a string as a hex number like `0x1f`, in a `const` context.
+///
+/// Returns `None` if the range doesn't start with `0x` or `0X`, so it's a name. Returns `Some(None)`
+/// if it's not a valid hex number, or it doesn't fit in a bits type of `size` bytes.
+#[doc(hidden)]
+pub const fn const_parse_hex(
+    input: &str,
+    start: usize,
+    end: usize,
+    size: usize,
+) -> Option<Option<u128>> {
+    let input = input.as_bytes();
+
+    if end - start < 2
+        || input[start] != b'0'
+        || (input[start + 1] != b'x' && input[start + 1] != b'X')
+    {
+        return None;
+    }
+
+    // An empty hex number, like `0x`, is invalid
+    if end - start == 2 {
+        return Some(None);
+    }
+
+    let mut bits = 0u128;
+    let mut i = start + 2;
+    while i < end {
+        let digit = match input[i] {
+            b'0'..=b'9' => input[i] - b'0',
+            b'a'..=b'f' => input[i] - b'a' + 10,
+            b'A'..=b'F' => input[i] - b'A' + 10,
+            _ => return Some(None),
+        };
+
+        // Any set bits that would be shifted out don't fit
+        if bits >> 124 != 0 {
+            return Some(None);
+        }
+
+        bits = bits << 4 | digit as u128;
+        i += 1;
+    }
+
+    if size < 16 && bits >> (size * 8) != 0 {
+        return Some(None);
+    }
+
+    Some(Some(bits))
+}
+
+pub(crate) mod __private {
+    pub use super::{
+        const_is_blank, const_next_flag, const_parse_hex, const_range_eq, is_valid_name, str_eq,
+        AsDebug,
+    };
+}
+
 /**
 Parse a flags value from text.
 
@@ -95,6 +427,92 @@ This function will fail on any names that don't correspond to defined flags.
 Unknown bits will be retained.
 */
 pub fn from_str<B: Flags>(input: &str) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    from_str_with(input, '|')
+}
+
+/**
+Parse a flags value from text, using a custom separator between flags.
+
+This function is like [`from_str`], except flags are separated by `separator` instead of `|`.
+Whitespace around each flag is trimmed in the same way.
+
+```
+# bitflags::bitflags! {
+#     #[derive(Debug, PartialEq, Eq)]
+#     struct Flags: u8 {
+#         const A = 1;
+#         const B = 1 << 1;
+#     }
+# }
+let flags: Flags = bitflags::parser::from_str_with("A, B", ',')?;
+
+assert_eq!(Flags::A | Flags::B, flags);
+# Ok::<(), bitflags::parser::ParseError>(())
+```
+*/
+pub fn from_str_with<B: Flags>(input: &str, separator: char) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    parse(input, separator, |flag| {
+        B::from_name(flag).ok_or(ParseError::from_kind(ParseErrorKind::InvalidNamedFlag))
+    })
+}
+
+/**
+Parse a flags value from text, matching names without regard to ASCII case.
+
+This function is like [`from_str`], except a name like `a` will match a flag named `A`.
+Aliases are matched in the same way. If a name exactly matches a defined flag or alias then
+that flag is used. Otherwise, the flags and aliases the name matches when ignoring case must all
+have the same value, or parsing will fail.
+*/
+pub fn from_str_ignore_case<B: Flags>(input: &str) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    parse(input, '|', |flag| {
+        // Prefer an exact match so flags that only differ by case can still be parsed
+        if let Some(parsed) = B::from_name(flag) {
+            return Ok(parsed);
+        }
+
+        match match_ignore_case(flag) {
+            Ok(Some(parsed)) => Ok(parsed),
+            Ok(None) => Err(ParseError::from_kind(ParseErrorKind::InvalidNamedFlag)),
+            Err(()) => Err(ParseError::from_kind(ParseErrorKind::AmbiguousNamedFlag)),
+        }
+    })
+}
+
+/// Find the flag whose name or alias matches `name` when ignoring ASCII case.
+///
+/// A name is ambiguous if it matches flags or aliases with different values, which is an error.
+pub(crate) fn match_ignore_case<B: Flags>(name: &str) -> Result<Option<B>, ()> {
+    let mut matched: Option<B> = None;
+
+    for defined in B::FLAGS.iter().chain(B::ALIASES) {
+        if defined.is_unnamed() || !defined.name().eq_ignore_ascii_case(name) {
+            continue;
+        }
+
+        match matched {
+            Some(ref matched) if matched.bits() != defined.value().bits() => return Err(()),
+            _ => matched = Some(B::from_bits_retain(defined.value().bits())),
+        }
+    }
+
+    Ok(matched)
+}
+
+pub(crate) fn parse<B: Flags>(
+    input: &str,
+    separator: char,
+    mut parse_name: impl FnMut(&str) -> Result<B, ParseError>,
+) -> Result<B, ParseError>
 where
     B::Bits: ParseHex,
 {
@@ -105,28 +523,42 @@ where
         return Ok(parsed_flags);
     }
 
-    for flag in input.split('|') {
-        let flag = flag.trim();
+    let mut start = 0;
+
+    for flag in input.split(separator) {
+        let end = start + flag.len();
+
+        // Track where the trimmed flag sits in the input so errors can point at it
+        let trimmed = flag.trim_start();
+        let flag_start = start + (flag.len() - trimmed.len());
+        let flag = trimmed.trim_end();
+        let span = flag_start..flag_start + flag.len();
+
+        start = end + separator.len_utf8();
 
         // If the flag is empty then we've got missing input
         if flag.is_empty() {
-            return Err(ParseError::empty_flag());
+            return Err(ParseError::empty_flag().with_span(span));
         }
 
         // If the flag starts with `0x` then it's a hex number
         // Parse it directly to the underlying bits type
-        let parsed_flag = if let Some(flag) = flag.strip_prefix("0x") {
-            let bits =
-                <B::Bits>::parse_hex(flag).map_err(|_| ParseError::invalid_hex_flag(flag))?;
+        let parsed_flag =
+            if let Some(hex) = flag.strip_prefix("0x").or_else(|| flag.strip_prefix("0X")) {
+                let bits = <B::Bits>::parse_hex(hex).map_err(|e| e.with_span(span))?;
 
-            B::from_bits_retain(bits)
-        }
-        // Otherwise the flag is a name
-        // The generated flags type will determine whether
-        // or not it's a valid identifier
-        else {
-            B::from_name(flag).ok_or_else(|| ParseError::invalid_named_flag(flag))?
-        };
+                B::from_bits_retain(bits)
+            }
+            // If the flag is the text of an empty flags value then it doesn't set any bits
+            else if flag == B::EMPTY_NAME {
+                B::empty()
+            }
+            // Otherwise the flag is a name
+            // The generated flags type will determine whether
+            // or not it's a valid identifier
+            else {
+                parse_name(flag).map_err(|e| e.with_span(span))?
+            };
 
         parsed_flags.insert(parsed_flag);
     }
@@ -155,88 +587,234 @@ pub trait ParseHex {
 }
 
 /// An error encountered while parsing flags from text.
+///
+/// Use [`ParseError::kind`] to find out what went wrong, and [`ParseError::span`] or
+/// [`ParseError::token`] to find the offending flag in the original input.
+///
+/// Errors returned by the functions in this module don't allocate, so they only have a span into
+/// the input rather than a copy of the offending flag. Errors constructed directly, like with
+/// [`ParseError::invalid_named_flag`], keep a copy of the flag for [`ParseError::got`] and their
+/// `Display` output when the `std` feature is enabled. Use [`ParseError::display_in`] to include the
+/// offending flag when displaying an error returned by this module.
 #[derive(Debug)]
-pub struct ParseError(ParseErrorKind);
+pub struct ParseError {
+    kind: ParseErrorKind,
+    #[cfg(feature = "std")]
+    got: Option<String>,
+    span: Option<Range<usize>>,
+}
 
-#[derive(Debug)]
-#[allow(clippy::enum_variant_names)]
-enum ParseErrorKind {
+/**
+The kind of error encountered while parsing flags from text.
+
+New kinds of errors may be added in the future, so this enum is non-exhaustive.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A hex or named flag wasn't found between separators.
     EmptyFlag,
-    InvalidNamedFlag {
-        #[cfg(not(feature = "std"))]
-        got: (),
-        #[cfg(feature = "std")]
-        got: String,
-    },
-    InvalidHexFlag {
-        #[cfg(not(feature = "std"))]
-        got: (),
-        #[cfg(feature = "std")]
-        got: String,
-    },
+    /// A named flag that doesn't correspond to any on the flags type was encountered.
+    InvalidNamedFlag,
+    /// An invalid hex flag was encountered.
+    InvalidHexFlag,
+    /// A named flag that matches multiple flags on the flags type when ignoring case was encountered.
+    AmbiguousNamedFlag,
+    /// A hex flag that doesn't fit in the bits type was encountered.
+    OverflowingHexFlag,
 }
 
 impl ParseError {
-    /// An invalid hex flag was encountered.
-    pub fn invalid_hex_flag(flag: impl fmt::Display) -> Self {
+    fn new(kind: ParseErrorKind, flag: impl fmt::Display) -> Self {
         let _flag = flag;
 
-        let got = {
+        ParseError {
+            kind,
             #[cfg(feature = "std")]
-            {
-                _flag.to_string()
-            }
-        };
+            got: Some(_flag.to_string()),
+            span: None,
+        }
+    }
 
-        ParseError(ParseErrorKind::InvalidHexFlag { got })
+    pub(crate) const fn from_kind(kind: ParseErrorKind) -> Self {
+        ParseError {
+            kind,
+            #[cfg(feature = "std")]
+            got: None,
+            span: None,
+        }
     }
 
-    /// A named flag that doesn't correspond to any on the flags type was encountered.
-    pub fn invalid_named_flag(flag: impl fmt::Display) -> Self {
-        let _flag = flag;
+    /// An invalid hex flag was encountered.
+    pub fn invalid_hex_flag(flag: impl fmt::Display) -> Self {
+        ParseError::new(ParseErrorKind::InvalidHexFlag, flag)
+    }
 
-        let got = {
-            #[cfg(feature = "std")]
-            {
-                _flag.to_string()
+    /// A named flag that matches multiple flags on the flags type when ignoring case was encountered.
+    pub fn ambiguous_named_flag(flag: impl fmt::Display) -> Self {
+        ParseError::new(ParseErrorKind::AmbiguousNamedFlag, flag)
+    }
+
+    /// A hex flag that doesn't fit in the bits type was encountered.
+    pub fn overflowing_hex_flag(flag: impl fmt::Display) -> Self {
+        ParseError::new(ParseErrorKind::OverflowingHexFlag, flag)
+    }
+
+    pub(crate) fn from_hex_error(err: ParseIntError) -> Self {
+        match err.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                ParseError::from_kind(ParseErrorKind::OverflowingHexFlag)
             }
-        };
+            _ => ParseError::from_kind(ParseErrorKind::InvalidHexFlag),
+        }
+    }
 
-        ParseError(ParseErrorKind::InvalidNamedFlag { got })
+    /// A named flag that doesn't correspond to any on the flags type was encountered.
+    pub fn invalid_named_flag(flag: impl fmt::Display) -> Self {
+        ParseError::new(ParseErrorKind::InvalidNamedFlag, flag)
     }
 
     /// A hex or named flag wasn't found between separators.
     pub const fn empty_flag() -> Self {
-        ParseError(ParseErrorKind::EmptyFlag)
+        ParseError::from_kind(ParseErrorKind::EmptyFlag)
     }
-}
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.0 {
-            ParseErrorKind::InvalidNamedFlag { got } => {
-                let _got = got;
+    /// The kind of error that was encountered.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
 
-                write!(f, "unrecognized named flag")?;
+    /**
+    The flag that caused the error, as it was given to a constructor like [`ParseError::invalid_named_flag`].
 
-                #[cfg(feature = "std")]
-                {
-                    write!(f, " `{}`", _got)?;
-                }
-            }
-            ParseErrorKind::InvalidHexFlag { got } => {
-                let _got = got;
+    This is only available when the `std` feature is enabled, and isn't available on errors returned
+    by the functions in this module, which don't allocate. Use [`ParseError::token`] to find the flag
+    in the original input instead.
+    */
+    pub fn got(&self) -> Option<&str> {
+        #[cfg(feature = "std")]
+        {
+            self.got.as_deref()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            None
+        }
+    }
+
+    /**
+    The byte range of the flag that caused the error in the original input.
+
+    Leading and trailing whitespace around the flag isn't included in the range.
+    For an empty flag the range is empty, and points to where the flag was expected.
+
+    This is only available on errors returned by the functions in this module;
+    errors constructed directly don't have a span.
+    */
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
 
-                write!(f, "invalid hex flag")?;
+    /**
+    The byte offset of the flag that caused the error in the original input.
 
-                #[cfg(feature = "std")]
-                {
-                    write!(f, " `{}`", _got)?;
+    This is the start of [`ParseError::span`], so it's only available on errors returned
+    by the functions in this module.
+    */
+    pub fn offset(&self) -> Option<usize> {
+        self.span.as_ref().map(|span| span.start)
+    }
+
+    /**
+    The flag that caused the error, as a slice of the original input.
+
+    This method returns `None` if the error doesn't have a [`ParseError::span`],
+    or if the span doesn't fit in `input`.
+
+    ```
+    # use bitflags::{bitflags, parser};
+    # bitflags! {
+    #     #[derive(Debug)]
+    #     struct Flags: u8 {
+    #         const A = 1;
+    #         const C = 1 << 2;
+    #     }
+    # }
+    let input = "A | BOGUS | C";
+    let err = parser::from_str::<Flags>(input).unwrap_err();
+
+    assert_eq!(Some(4), err.offset());
+    assert_eq!(Some("BOGUS"), err.token(input));
+    ```
+    */
+    pub fn token<'a>(&self, input: &'a str) -> Option<&'a str> {
+        input.get(self.span.clone()?)
+    }
+
+    pub(crate) fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /**
+    Display the error along with the flag that caused it, found in the original input.
+
+    Errors returned by the functions in this module don't keep a copy of the flag that caused them,
+    so their `Display` output doesn't include it. This method finds the flag using [`ParseError::token`]
+    instead, so the output looks like ``unrecognized named flag `BOGUS` ``.
+
+    ```
+    # use bitflags::{bitflags, parser};
+    # bitflags! {
+    #     #[derive(Debug)]
+    #     struct Flags: u8 {
+    #         const A = 1;
+    #     }
+    # }
+    let input = "A | BOGUS";
+    let err = parser::from_str::<Flags>(input).unwrap_err();
+
+    assert_eq!("unrecognized named flag", err.to_string());
+    assert_eq!("unrecognized named flag `BOGUS`", err.display_in(input).to_string());
+    ```
+    */
+    pub fn display_in<'a>(&'a self, input: &'a str) -> impl fmt::Display + 'a {
+        struct DisplayIn<'a> {
+            err: &'a ParseError,
+            input: &'a str,
+        }
+
+        impl<'a> fmt::Display for DisplayIn<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self.err, f)?;
+
+                if self.err.got().is_none() {
+                    match self.err.token(self.input) {
+                        Some(token) if !token.is_empty() => write!(f, " `{}`", token)?,
+                        _ => (),
+                    }
                 }
+
+                Ok(())
             }
-            ParseErrorKind::EmptyFlag => {
-                write!(f, "encountered empty flag")?;
-            }
+        }
+
+        DisplayIn { err: self, input }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidNamedFlag => write!(f, "unrecognized named flag")?,
+            ParseErrorKind::InvalidHexFlag => write!(f, "invalid hex flag")?,
+            ParseErrorKind::AmbiguousNamedFlag => write!(f, "ambiguous named flag")?,
+            ParseErrorKind::OverflowingHexFlag => write!(f, "overflowing hex flag")?,
+            ParseErrorKind::EmptyFlag => write!(f, "encountered empty flag")?,
+        }
+
+        if let Some(got) = self.got() {
+            write!(f, " `{}`", got)?;
         }
 
         Ok(())
@@ -26,9 +26,11 @@ macro_rules! __declare_public_bitflags {
 #[doc(hidden)]
 macro_rules! __impl_public_bitflags_forward {
     (
+        options: [$($option:tt)*],
         $PublicBitFlags:ident: $T:ty, $InternalBitFlags:ident
     ) => {
         __impl_bitflags! {
+            options: [$($option)*],
             $PublicBitFlags: $T {
                 fn empty() {
                     Self($InternalBitFlags::empty())
@@ -38,6 +40,10 @@ macro_rules! __impl_public_bitflags_forward {
                     Self($InternalBitFlags::all())
                 }
 
+                fn all_named() {
+                    Self($InternalBitFlags::all_named())
+                }
+
                 fn bits(f) {
                     f.0.bits()
                 }
@@ -57,6 +63,24 @@ macro_rules! __impl_public_bitflags_forward {
                     Self($InternalBitFlags::from_bits_retain(bits))
                 }
 
+                fn from_bits_strict(bits) {
+                    match $InternalBitFlags::from_bits_strict(bits) {
+                        $crate::__private::core::result::Result::Ok(bits) => $crate::__private::core::result::Result::Ok(Self(bits)),
+                        $crate::__private::core::result::Result::Err(unknown) => $crate::__private::core::result::Result::Err(unknown),
+                    }
+                }
+
+                fn from_bit_index(index) {
+                    match $InternalBitFlags::from_bit_index(index) {
+                        $crate::__private::core::option::Option::Some(bits) => $crate::__private::core::option::Option::Some(Self(bits)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
+                fn mask_range(range) {
+                    Self($InternalBitFlags::mask_range(range))
+                }
+
                 fn from_name(name) {
                     match $InternalBitFlags::from_name(name) {
                         $crate::__private::core::option::Option::Some(bits) => $crate::__private::core::option::Option::Some(Self(bits)),
@@ -64,6 +88,13 @@ macro_rules! __impl_public_bitflags_forward {
                     }
                 }
 
+                fn from_name_ignore_case(name) {
+                    match $InternalBitFlags::from_name_ignore_case(name) {
+                        $crate::__private::core::option::Option::Some(bits) => $crate::__private::core::option::Option::Some(Self(bits)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
                 fn is_empty(f) {
                     f.0.is_empty()
                 }
@@ -72,6 +103,55 @@ macro_rules! __impl_public_bitflags_forward {
                     f.0.is_all()
                 }
 
+                fn contains_unknown_bits(f) {
+                    f.0.contains_unknown_bits()
+                }
+
+                fn unknown_bits(f) {
+                    f.0.unknown_bits()
+                }
+
+                fn split_known(f) {
+                    let (known, unknown) = f.0.split_known();
+                    (Self(known), unknown)
+                }
+
+                fn with_unknown(f, bits) {
+                    Self(f.0.with_unknown(bits))
+                }
+
+                fn count(f) {
+                    f.0.count()
+                }
+
+                fn first(f) {
+                    match f.0.first() {
+                        $crate::__private::core::option::Option::Some(first) => $crate::__private::core::option::Option::Some(Self(first)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
+                fn last(f) {
+                    match f.0.last() {
+                        $crate::__private::core::option::Option::Some(last) => $crate::__private::core::option::Option::Some(Self(last)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
+                fn pop_first(f) {
+                    match f.0.pop_first() {
+                        $crate::__private::core::option::Option::Some(first) => $crate::__private::core::option::Option::Some(Self(first)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
+                fn pop_last(f) {
+                    match f.0.pop_last() {
+                        $crate::__private::core::option::Option::Some(last) => $crate::__private::core::option::Option::Some(Self(last)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
                 fn intersects(f, other) {
                     f.0.intersects(other.0)
                 }
@@ -80,6 +160,22 @@ macro_rules! __impl_public_bitflags_forward {
                     f.0.contains(other.0)
                 }
 
+                fn is_subset(f, other) {
+                    f.0.is_subset(other.0)
+                }
+
+                fn is_superset(f, other) {
+                    f.0.is_superset(other.0)
+                }
+
+                fn is_disjoint(f, other) {
+                    f.0.is_disjoint(other.0)
+                }
+
+                fn implies(f, other) {
+                    f.0.implies(other.0)
+                }
+
                 fn insert(f, other) {
                     f.0.insert(other.0)
                 }
@@ -88,14 +184,55 @@ macro_rules! __impl_public_bitflags_forward {
                     f.0.remove(other.0)
                 }
 
+                fn insert_changed(f, other) {
+                    f.0.insert_changed(other.0)
+                }
+
+                fn remove_changed(f, other) {
+                    f.0.remove_changed(other.0)
+                }
+
+                fn replace(f, new) {
+                    Self(f.0.replace(new.0))
+                }
+
+                fn fetch_insert(f, other) {
+                    Self(f.0.fetch_insert(other.0))
+                }
+
+                fn fetch_remove(f, other) {
+                    Self(f.0.fetch_remove(other.0))
+                }
+
+                fn fetch_toggle(f, other) {
+                    Self(f.0.fetch_toggle(other.0))
+                }
+
                 fn toggle(f, other) {
                     f.0.toggle(other.0)
                 }
 
+                fn invert_within(f, mask) {
+                    f.0.invert_within(mask.0)
+                }
+
                 fn set(f, other, value) {
                     f.0.set(other.0, value)
                 }
 
+                fn clear(f) {
+                    f.0.clear()
+                }
+
+                fn truncate(f) {
+                    f.0.truncate()
+                }
+
+                fn retain(f, predicate) {
+                    let mut predicate = predicate;
+                    f.0.retain(|flag| predicate(Self(flag)))
+                }
+
                 fn intersection(f, other) {
                     Self(f.0.intersection(other.0))
                 }
@@ -115,6 +252,10 @@ macro_rules! __impl_public_bitflags_forward {
                 fn complement(f) {
                     Self(f.0.complement())
                 }
+
+                fn complement_of(f, mask) {
+                    Self(f.0.complement_of(mask.0))
+                }
             }
         }
     };
@@ -128,6 +269,7 @@ macro_rules! __impl_public_bitflags_forward {
 #[doc(hidden)]
 macro_rules! __impl_public_bitflags {
     (
+        options: [$($option:tt)*],
         $BitFlags:ident: $T:ty, $PublicBitFlags:ident {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -136,6 +278,7 @@ macro_rules! __impl_public_bitflags {
         }
     ) => {
         __impl_bitflags! {
+            options: [$($option)*],
             $BitFlags: $T {
                 fn empty() {
                     Self(<$T as $crate::Bits>::EMPTY)
@@ -161,6 +304,36 @@ macro_rules! __impl_public_bitflags {
                     Self::from_bits_retain(truncated)
                 }
 
+                fn all_named() {
+                    let mut named = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let flag = &<$PublicBitFlags as $crate::Flags>::FLAGS[i];
+
+                                if flag.is_named() {
+                                    named = named | flag.value().bits();
+                                }
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    // Flags imported from other flags types come after the flags declared here
+                    let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+                    while i < flags.len() {
+                        if flags[i].is_named() {
+                            named = named | flags[i].value().bits();
+                        }
+                        i += 1;
+                    }
+
+                    Self::from_bits_retain(named)
+                }
+
                 fn bits(f) {
                     f.0
                 }
@@ -183,6 +356,44 @@ macro_rules! __impl_public_bitflags {
                     Self(bits)
                 }
 
+                fn from_bits_strict(bits) {
+                    let unknown = bits & !Self::all().bits();
+
+                    if unknown == <$T as $crate::Bits>::EMPTY {
+                        $crate::__private::core::result::Result::Ok(Self(bits))
+                    } else {
+                        $crate::__private::core::result::Result::Err($crate::UnknownBits::new(unknown))
+                    }
+                }
+
+                fn from_bit_index(index) {
+                    match <$T>::checked_shl(1, index) {
+                        $crate::__private::core::option::Option::Some(bits) => $crate::__private::core::option::Option::Some(Self(bits)),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
+                fn mask_range(range) {
+                    let (start, end) = (*range.start(), *range.end());
+
+                    if start > end {
+                        Self(<$T as $crate::Bits>::EMPTY)
+                    } else {
+                        // The end of the range must fit in the bits type
+                        // Indexing past the end panics, or fails compilation in a const context
+                        let () = [()][(end >= <$T>::BITS) as usize];
+
+                        let width = end - start + 1;
+                        let ones = if width == <$T>::BITS {
+                            <$T as $crate::Bits>::ALL
+                        } else {
+                            <$T>::wrapping_sub(1 << width, 1)
+                        };
+
+                        Self(ones << start)
+                    }
+                }
+
                 fn from_name(name) {
                     $(
                         __bitflags_flag!({
@@ -191,7 +402,7 @@ macro_rules! __impl_public_bitflags {
                                 __bitflags_expr_safe_attrs!(
                                     $(#[$inner $($args)*])*
                                     {
-                                        if name == $crate::__private::core::stringify!($Flag) {
+                                        if name == __bitflags_flag_name!($Flag $(#[$inner $($args)*])*) {
                                             return $crate::__private::core::option::Option::Some(Self($PublicBitFlags::$Flag.bits()));
                                         }
                                     }
@@ -201,10 +412,44 @@ macro_rules! __impl_public_bitflags {
                         });
                     )*
 
-                    let _ = name;
+                    // Flags imported from other flags types come after the flags declared here
+                    let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    while i < flags.len() {
+                        if flags[i].is_named() && flags[i].name() == name {
+                            return $crate::__private::core::option::Option::Some(Self(flags[i].value().bits()));
+                        }
+                        i += 1;
+                    }
+
+                    for alias in <$PublicBitFlags as $crate::Flags>::ALIASES {
+                        if alias.name() == name {
+                            return $crate::__private::core::option::Option::Some(Self(alias.value().bits()));
+                        }
+                    }
+
                     $crate::__private::core::option::Option::None
                 }
 
+                fn from_name_ignore_case(name) {
+                    // Matching names without regard to case isn't `const`, so the trait
+                    // method is used. It considers aliases, and flags imported from other flags types
+                    match <$PublicBitFlags as $crate::Flags>::from_name_ignore_case(name) {
+                        $crate::__private::core::option::Option::Some(flags) => $crate::__private::core::option::Option::Some(Self(flags.bits())),
+                        $crate::__private::core::option::Option::None => $crate::__private::core::option::Option::None,
+                    }
+                }
+
                 fn is_empty(f) {
                     f.bits() == <$T as $crate::Bits>::EMPTY
                 }
@@ -215,63 +460,336 @@ macro_rules! __impl_public_bitflags {
                     Self::all().bits() | f.bits() == f.bits()
                 }
 
-                fn intersects(f, other) {
-                    f.bits() & other.bits() != <$T as $crate::Bits>::EMPTY
+                fn contains_unknown_bits(f) {
+                    f.unknown_bits() != <$T as $crate::Bits>::EMPTY
                 }
 
-                fn contains(f, other) {
-                    f.bits() & other.bits() == other.bits()
+                fn unknown_bits(f) {
+                    f.bits() & !Self::all().bits()
                 }
 
-                fn insert(f, other) {
-                    *f = Self::from_bits_retain(f.bits()).union(other);
+                fn split_known(f) {
+                    (Self::from_bits_truncate(f.bits()), f.unknown_bits())
                 }
 
-                fn remove(f, other) {
-                    *f = Self::from_bits_retain(f.bits()).difference(other);
+                fn with_unknown(f, bits) {
+                    Self::from_bits_retain(f.bits() | bits)
                 }
 
-                fn toggle(f, other) {
-                    *f = Self::from_bits_retain(f.bits()).symmetric_difference(other);
+                fn count(f) {
+                    let mut single = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let flag = &<$PublicBitFlags as $crate::Flags>::FLAGS[i];
+
+                                // Only count named flags with a single bit, so the bits of
+                                // composite and unnamed flags aren't counted on their own
+                                if flag.is_named() && flag.value().bits().count_ones() == 1 {
+                                    single = single | flag.value().bits();
+                                }
+
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    let _ = i;
+                    (f.bits() & single).count_ones()
                 }
 
-                fn set(f, other, value) {
-                    if value {
-                        f.insert(other);
+                fn first(f) {
+                    let source = f.bits();
+                    let mut first = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let flag = &<$PublicBitFlags as $crate::Flags>::FLAGS[i];
+                                let bits = flag.value().bits();
+
+                                if flag.is_named()
+                                    && bits != <$T as $crate::Bits>::EMPTY
+                                    && source & bits == bits
+                                {
+                                    // Prefer the flag with the lowest bit, then the one with the fewest bits
+                                    if first == <$T as $crate::Bits>::EMPTY
+                                        || bits.trailing_zeros() < first.trailing_zeros()
+                                        || (bits.trailing_zeros() == first.trailing_zeros()
+                                            && bits.count_ones() < first.count_ones())
+                                    {
+                                        first = bits;
+                                    }
+                                }
+
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    let _ = i;
+                    let _ = source;
+
+                    if first == <$T as $crate::Bits>::EMPTY {
+                        $crate::__private::core::option::Option::None
                     } else {
-                        f.remove(other);
+                        $crate::__private::core::option::Option::Some(Self::from_bits_retain(first))
                     }
                 }
 
-                fn intersection(f, other) {
-                    Self::from_bits_retain(f.bits() & other.bits())
+                fn last(f) {
+                    let source = f.bits();
+                    let mut last = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let flag = &<$PublicBitFlags as $crate::Flags>::FLAGS[i];
+                                let bits = flag.value().bits();
+
+                                if flag.is_named()
+                                    && bits != <$T as $crate::Bits>::EMPTY
+                                    && source & bits == bits
+                                {
+                                    // Prefer the flag with the highest bit, then the one with the fewest bits
+                                    if last == <$T as $crate::Bits>::EMPTY
+                                        || bits.leading_zeros() < last.leading_zeros()
+                                        || (bits.leading_zeros() == last.leading_zeros()
+                                            && bits.count_ones() < last.count_ones())
+                                    {
+                                        last = bits;
+                                    }
+                                }
+
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    let _ = i;
+                    let _ = source;
+
+                    if last == <$T as $crate::Bits>::EMPTY {
+                        $crate::__private::core::option::Option::None
+                    } else {
+                        $crate::__private::core::option::Option::Some(Self::from_bits_retain(last))
+                    }
                 }
 
-                fn union(f, other) {
-                    Self::from_bits_retain(f.bits() | other.bits())
+                fn pop_first(f) {
+                    let mut single = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let flag = &<$PublicBitFlags as $crate::Flags>::FLAGS[i];
+
+                                if flag.is_named() && flag.value().bits().count_ones() == 1 {
+                                    single |= flag.value().bits();
+                                }
+
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    let _ = i;
+                    let single = f.bits() & single;
+
+                    if single == <$T as $crate::Bits>::EMPTY {
+                        $crate::__private::core::option::Option::None
+                    } else {
+                        // Isolate the lowest set bit
+                        let first = single & single.wrapping_neg();
+
+                        *f = Self::from_bits_retain(f.bits() & !first);
+                        $crate::__private::core::option::Option::Some(Self::from_bits_retain(first))
+                    }
                 }
 
-                fn difference(f, other) {
-                    Self::from_bits_retain(f.bits() & !other.bits())
+                fn pop_last(f) {
+                    let mut single = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let flag = &<$PublicBitFlags as $crate::Flags>::FLAGS[i];
+
+                                if flag.is_named() && flag.value().bits().count_ones() == 1 {
+                                    single |= flag.value().bits();
+                                }
+
+                                i += 1;
+                            }}
+                        );
+                    )*
+
+                    let _ = i;
+                    let single = f.bits() & single;
+
+                    if single == <$T as $crate::Bits>::EMPTY {
+                        $crate::__private::core::option::Option::None
+                    } else {
+                        // Isolate the highest set bit
+                        let last = 1 << (<$T>::BITS - 1 - single.leading_zeros());
+
+                        *f = Self::from_bits_retain(f.bits() & !last);
+                        $crate::__private::core::option::Option::Some(Self::from_bits_retain(last))
+                    }
                 }
 
-                fn symmetric_difference(f, other) {
-                    Self::from_bits_retain(f.bits() ^ other.bits())
+                fn intersects(f, other) {
+                    f.bits() & other.bits() != <$T as $crate::Bits>::EMPTY
                 }
 
-                fn complement(f) {
-                    Self::from_bits_truncate(!f.bits())
+                fn contains(f, other) {
+                    f.bits() & other.bits() == other.bits()
                 }
-            }
-        }
-    };
-}
 
-/// Implement iterators on the public (user-facing) bitflags type.
-#[macro_export(local_inner_macros)]
-#[doc(hidden)]
+                fn is_subset(f, other) {
+                    f.bits() & other.bits() == f.bits()
+                }
+
+                fn is_superset(f, other) {
+                    f.bits() & other.bits() == other.bits()
+                }
+
+                fn is_disjoint(f, other) {
+                    f.bits() & other.bits() == <$T as $crate::Bits>::EMPTY
+                }
+
+                fn implies(f, other) {
+                    f.bits() & other.bits() == other.bits()
+                }
+
+                fn insert(f, other) {
+                    *f = Self::from_bits_retain(f.bits()).union(other);
+                }
+
+                fn remove(f, other) {
+                    *f = Self::from_bits_retain(f.bits()).difference(other);
+                }
+
+                fn insert_changed(f, other) {
+                    let changed = f.bits() & other.bits() != other.bits();
+                    f.insert(other);
+                    changed
+                }
+
+                fn remove_changed(f, other) {
+                    let changed = f.bits() & other.bits() != <$T as $crate::Bits>::EMPTY;
+                    f.remove(other);
+                    changed
+                }
+
+                fn replace(f, new) {
+                    $crate::__private::core::mem::replace(f, new)
+                }
+
+                fn fetch_insert(f, other) {
+                    let previous = Self::from_bits_retain(f.bits());
+                    f.insert(other);
+                    previous
+                }
+
+                fn fetch_remove(f, other) {
+                    let previous = Self::from_bits_retain(f.bits());
+                    f.remove(other);
+                    previous
+                }
+
+                fn fetch_toggle(f, other) {
+                    let previous = Self::from_bits_retain(f.bits());
+                    f.toggle(other);
+                    previous
+                }
+
+                fn toggle(f, other) {
+                    *f = Self::from_bits_retain(f.bits()).symmetric_difference(other);
+                }
+
+                fn invert_within(f, mask) {
+                    *f = Self::from_bits_retain(f.bits() ^ mask.bits());
+                }
+
+                fn set(f, other, value) {
+                    if value {
+                        f.insert(other);
+                    } else {
+                        f.remove(other);
+                    }
+                }
+
+                fn clear(f) {
+                    *f = Self::empty();
+                }
+
+                fn truncate(f) {
+                    *f = Self::from_bits_truncate(f.bits());
+                }
+
+                fn retain(f, predicate) {
+                    let mut predicate = predicate;
+                    let mut retained = <$T as $crate::Bits>::EMPTY;
+
+                    let mut iter = f.iter_names();
+                    for (_, flag) in &mut iter {
+                        if predicate(Self::from_bits_retain(flag.bits())) {
+                            retained |= flag.bits();
+                        }
+                    }
+
+                    // Keep any bits that weren't yielded as named flags
+                    *f = Self::from_bits_retain(retained | iter.remaining().bits());
+                }
+
+                fn intersection(f, other) {
+                    Self::from_bits_retain(f.bits() & other.bits())
+                }
+
+                fn union(f, other) {
+                    Self::from_bits_retain(f.bits() | other.bits())
+                }
+
+                fn difference(f, other) {
+                    Self::from_bits_retain(f.bits() & !other.bits())
+                }
+
+                fn symmetric_difference(f, other) {
+                    Self::from_bits_retain(f.bits() ^ other.bits())
+                }
+
+                fn complement(f) {
+                    Self::from_bits_truncate(!f.bits())
+                }
+
+                fn complement_of(f, mask) {
+                    mask.difference(f)
+                }
+            }
+        }
+    };
+}
+
+/// Implement iterators on the public (user-facing) bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
 macro_rules! __impl_public_bitflags_iter {
-    ($BitFlags:ident: $T:ty, $PublicBitFlags:ident) => {
+    (
+        options: [$($option:tt)*],
+        $BitFlags:ident: $T:ty, $PublicBitFlags:ident
+    ) => {
         impl $BitFlags {
             /// Yield a set of contained flags values.
             ///
@@ -300,6 +818,77 @@ macro_rules! __impl_public_bitflags_iter {
             }
         }
 
+        __bitflags_inherent_methods! {
+            options: [$($option)*],
+            {
+                impl $BitFlags {
+                    /// Yield a set of named flags values that aren't contained in this flags value.
+                    ///
+                    /// This method is like [`iter_names`](#method.iter_names), except it yields defined flags that aren't set.
+                    /// Composite flags, like `ABC` when `A`, `B`, and `C` are also defined, won't be yielded.
+                    #[inline]
+                    pub const fn iter_missing(&self) -> $crate::iter::IterMissing<$PublicBitFlags> {
+                        $crate::iter::IterMissing::__private_const_new(
+                            <$PublicBitFlags as $crate::Flags>::FLAGS,
+                            $PublicBitFlags::from_bits_retain(self.bits()),
+                            $PublicBitFlags::empty(),
+                        )
+                    }
+
+                    /// Yield the ranges of bit positions in each run of contiguous set bits, like `3..=7`.
+                    ///
+                    /// Names aren't considered, so any unknown bits are yielded in the same way as the bits of defined flags.
+                    #[inline]
+                    pub const fn iter_ranges(&self) -> $crate::iter::IterRanges {
+                        $crate::iter::IterRanges::__private_const_new(
+                            self.bits() as u128,
+                            $crate::__private::core::mem::size_of::<$T>(),
+                        )
+                    }
+
+                    /// Yield the position of each unknown bit in this flags value, like `7` for `0x80`.
+                    ///
+                    /// Unknown bits are the same as in `unknown_bits`, so if the flags type defines an unnamed flag
+                    /// like `const _ = !0` then nothing will be yielded.
+                    #[inline]
+                    pub const fn iter_unknown_bits(&self) -> $crate::iter::IterUnknownBits {
+                        $crate::iter::IterUnknownBits::__private_const_new(
+                            self.unknown_bits() as u128,
+                            $crate::__private::core::mem::size_of::<$T>(),
+                        )
+                    }
+
+                    /// Remove the defined flags with a single bit from this flags value, and yield them.
+                    ///
+                    /// The flags are removed eagerly, before the first one is yielded, so if iteration stops early
+                    /// because of a `break` or a panic then any flags that weren't yielded are still removed.
+                    /// Flags inserted into this flags value while iterating aren't yielded. Unknown bits, and bits
+                    /// that are only defined as part of a composite flag, are left as-is, like with `pop_first`.
+                    #[inline]
+                    pub fn drain(&mut self) -> $crate::iter::Drain<$PublicBitFlags> {
+                        let mut remaining = $PublicBitFlags::from_bits_retain(self.bits());
+                        while remaining.pop_first().is_some() {}
+
+                        let drained = $PublicBitFlags::from_bits_retain(self.bits() & !remaining.bits());
+                        *self = Self::from_bits_retain(remaining.bits());
+
+                        $crate::iter::Drain::__private_new(drained, $PublicBitFlags::pop_first)
+                    }
+
+                    /// Yield the position of each set bit in this flags value, like `0` and `7` for `0x81`.
+                    ///
+                    /// Names aren't considered, so any unknown bits are yielded in the same way as the bits of defined flags.
+                    #[inline]
+                    pub const fn bit_indices(&self) -> $crate::iter::IterBitIndices {
+                        $crate::iter::IterBitIndices::__private_const_new(
+                            self.bits() as u128,
+                            $crate::__private::core::mem::size_of::<$T>(),
+                        )
+                    }
+                }
+            }
+        }
+
         impl $crate::__private::core::iter::IntoIterator for $BitFlags {
             type Item = $PublicBitFlags;
             type IntoIter = $crate::iter::Iter<$PublicBitFlags>;
@@ -311,45 +900,123 @@ macro_rules! __impl_public_bitflags_iter {
     };
 }
 
-/// Implement traits on the public (user-facing) bitflags type.
+/// Implement reading and writing bytes on the public (user-facing) bitflags type.
+///
+/// Like external libraries, this macro is defined twice, because whether the `std`
+/// feature is enabled isn't known in the end-user's library.
 #[macro_export(local_inner_macros)]
 #[doc(hidden)]
-macro_rules! __impl_public_bitflags_ops {
-    ($PublicBitFlags:ident) => {
-        impl $crate::__private::core::fmt::Binary for $PublicBitFlags {
-            fn fmt(
-                &self,
-                f: &mut $crate::__private::core::fmt::Formatter,
-            ) -> $crate::__private::core::fmt::Result {
-                $crate::__private::core::fmt::Binary::fmt(&self.0, f)
-            }
-        }
+#[cfg(feature = "std")]
+macro_rules! __impl_public_bitflags_io {
+    (
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident: $T:ty
+    ) => {
+        __bitflags_inherent_methods! {
+            options: [$($option)*],
+            {
+                impl $PublicBitFlags {
+                    /// Write the bits of this flags value as bytes, in the given byte order.
+                    ///
+                    /// Exactly as many bytes as the bits type has are written, including any unknown bits.
+                    #[inline]
+                    pub fn write_to<W: $crate::__private::std::io::Write>(
+                        &self,
+                        mut writer: W,
+                        endian: $crate::io::Endian,
+                    ) -> $crate::__private::std::io::Result<()> {
+                        let bytes = match endian {
+                            $crate::io::Endian::Little => self.bits().to_le_bytes(),
+                            $crate::io::Endian::Big => self.bits().to_be_bytes(),
+                        };
+
+                        writer.write_all(&bytes)
+                    }
 
-        impl $crate::__private::core::fmt::Octal for $PublicBitFlags {
-            fn fmt(
-                &self,
-                f: &mut $crate::__private::core::fmt::Formatter,
-            ) -> $crate::__private::core::fmt::Result {
-                $crate::__private::core::fmt::Octal::fmt(&self.0, f)
+                    /// Read a flags value from bytes, in the given byte order.
+                    ///
+                    /// Exactly as many bytes as the bits type has are read, and any unknown bits are retained.
+                    /// If there aren't enough bytes then an error with the `UnexpectedEof` kind is returned.
+                    #[inline]
+                    pub fn read_from<R: $crate::__private::std::io::Read>(
+                        mut reader: R,
+                        endian: $crate::io::Endian,
+                    ) -> $crate::__private::std::io::Result<Self> {
+                        let mut bytes = [0; $crate::__private::core::mem::size_of::<$T>()];
+                        reader.read_exact(&mut bytes)?;
+
+                        let bits = match endian {
+                            $crate::io::Endian::Little => <$T>::from_le_bytes(bytes),
+                            $crate::io::Endian::Big => <$T>::from_be_bytes(bytes),
+                        };
+
+                        $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+                    }
+                }
             }
         }
+    };
+}
 
-        impl $crate::__private::core::fmt::LowerHex for $PublicBitFlags {
-            fn fmt(
-                &self,
-                f: &mut $crate::__private::core::fmt::Formatter,
-            ) -> $crate::__private::core::fmt::Result {
-                $crate::__private::core::fmt::LowerHex::fmt(&self.0, f)
-            }
-        }
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "std"))]
+macro_rules! __impl_public_bitflags_io {
+    (
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident: $T:ty
+    ) => {};
+}
 
-        impl $crate::__private::core::fmt::UpperHex for $PublicBitFlags {
-            fn fmt(
-                &self,
-                f: &mut $crate::__private::core::fmt::Formatter,
-            ) -> $crate::__private::core::fmt::Result {
-                $crate::__private::core::fmt::UpperHex::fmt(&self.0, f)
-            }
+/// Implement traits on the public (user-facing) bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __impl_public_bitflags_ops {
+    (
+        options: [$($option:tt)*],
+        $PublicBitFlags:ident
+    ) => {
+        // Custom bits types don't necessarily implement the formatting traits
+        __bitflags_custom_bits! {
+            options: [$($option)*],
+            primitive: {
+                impl $crate::__private::core::fmt::Binary for $PublicBitFlags {
+                    fn fmt(
+                        &self,
+                        f: &mut $crate::__private::core::fmt::Formatter,
+                    ) -> $crate::__private::core::fmt::Result {
+                        $crate::__private::core::fmt::Binary::fmt(&self.0, f)
+                    }
+                }
+
+                impl $crate::__private::core::fmt::Octal for $PublicBitFlags {
+                    fn fmt(
+                        &self,
+                        f: &mut $crate::__private::core::fmt::Formatter,
+                    ) -> $crate::__private::core::fmt::Result {
+                        $crate::__private::core::fmt::Octal::fmt(&self.0, f)
+                    }
+                }
+
+                impl $crate::__private::core::fmt::LowerHex for $PublicBitFlags {
+                    fn fmt(
+                        &self,
+                        f: &mut $crate::__private::core::fmt::Formatter,
+                    ) -> $crate::__private::core::fmt::Result {
+                        $crate::__private::core::fmt::LowerHex::fmt(&self.0, f)
+                    }
+                }
+
+                impl $crate::__private::core::fmt::UpperHex for $PublicBitFlags {
+                    fn fmt(
+                        &self,
+                        f: &mut $crate::__private::core::fmt::Formatter,
+                    ) -> $crate::__private::core::fmt::Result {
+                        $crate::__private::core::fmt::UpperHex::fmt(&self.0, f)
+                    }
+                }
+            },
+            custom: {},
         }
 
         impl $crate::__private::core::ops::BitOr for $PublicBitFlags {
@@ -472,6 +1139,7 @@ macro_rules! __impl_public_bitflags_ops {
 #[doc(hidden)]
 macro_rules! __impl_public_bitflags_consts {
     (
+        options: [$($option:tt)*],
         $PublicBitFlags:ident: $T:ty {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -484,12 +1152,16 @@ macro_rules! __impl_public_bitflags_consts {
                 __bitflags_flag!({
                     name: $Flag,
                     named: {
-                        $(#[$inner $($args)*])*
-                        #[allow(
-                            deprecated,
-                            non_upper_case_globals,
-                        )]
-                        pub const $Flag: Self = Self::from_bits_retain($value);
+                        __bitflags_item_attrs! {
+                            #[allow(
+                                deprecated,
+                                non_upper_case_globals,
+                            )]
+                            $(#[$inner $($args)*])*
+                            {
+                                const $Flag: Self = Self::from_bits_retain($value);
+                            }
+                        }
                     },
                     unnamed: {},
                 });
@@ -497,37 +1169,103 @@ macro_rules! __impl_public_bitflags_consts {
         }
 
         impl $crate::Flags for $PublicBitFlags {
-            const FLAGS: &'static [$crate::Flag<$PublicBitFlags>] = &[
-                $(
-                    __bitflags_flag!({
-                        name: $Flag,
-                        named: {
-                            __bitflags_expr_safe_attrs!(
-                                $(#[$inner $($args)*])*
-                                {
-                                    #[allow(
-                                        deprecated,
-                                        non_upper_case_globals,
-                                    )]
-                                    $crate::Flag::new($crate::__private::core::stringify!($Flag), $PublicBitFlags::$Flag)
-                                }
-                            )
-                        },
-                        unnamed: {
-                            __bitflags_expr_safe_attrs!(
-                                $(#[$inner $($args)*])*
-                                {
-                                    #[allow(
-                                        deprecated,
-                                        non_upper_case_globals,
-                                    )]
-                                    $crate::Flag::new("", $PublicBitFlags::from_bits_retain($value))
-                                }
-                            )
-                        },
-                    }),
-                )*
-            ];
+            const FLAGS: &'static [$crate::Flag<$PublicBitFlags>] = __bitflags_import_flags! {
+                options: [$($option)*],
+                imports: [],
+                name: $PublicBitFlags,
+                flags: &[
+                    $(
+                        __bitflags_flag!({
+                            name: $Flag,
+                            named: {
+                                __bitflags_expr_safe_attrs!(
+                                    $(#[$inner $($args)*])*
+                                    {
+                                        #[allow(
+                                            deprecated,
+                                            non_upper_case_globals,
+                                        )]
+                                        $crate::Flag::new(__bitflags_flag_name!($Flag $(#[$inner $($args)*])*), $PublicBitFlags::$Flag)
+                                    }
+                                )
+                            },
+                            unnamed: {
+                                __bitflags_expr_safe_attrs!(
+                                    $(#[$inner $($args)*])*
+                                    {
+                                        #[allow(
+                                            deprecated,
+                                            non_upper_case_globals,
+                                        )]
+                                        $crate::Flag::new("", $PublicBitFlags::from_bits_retain($value))
+                                    }
+                                )
+                            },
+                        }),
+                    )*
+                ],
+            };
+
+            __bitflags_empty_name! {
+                options: [$($option)*],
+            }
+
+            #[allow(deprecated, unused_mut, unused_variables, unused_assignments)]
+            const ALIASES: &'static [$crate::Flag<$PublicBitFlags>] = {
+                const LEN: usize = {
+                    let mut len = 0;
+
+                    $(
+                        __bitflags_flag!({
+                            name: $Flag,
+                            named: {
+                                __bitflags_expr_safe_attrs!(
+                                    $(#[$inner $($args)*])*
+                                    {{
+                                        let aliases: &[&str] = &__bitflags_flag_aliases!($(#[$inner $($args)*])*);
+                                        len += aliases.len();
+                                    }}
+                                );
+                            },
+                            unnamed: {},
+                        });
+                    )*
+
+                    len
+                };
+
+                const TABLE: [$crate::Flag<$PublicBitFlags>; LEN] = {
+                    const UNNAMED: $crate::Flag<$PublicBitFlags> =
+                        $crate::Flag::new("", $PublicBitFlags::empty());
+
+                    let mut table = [UNNAMED; LEN];
+                    let mut i = 0;
+
+                    $(
+                        __bitflags_flag!({
+                            name: $Flag,
+                            named: {
+                                __bitflags_expr_safe_attrs!(
+                                    $(#[$inner $($args)*])*
+                                    {{
+                                        // The flag is only mentioned if it has aliases, so flags
+                                        // numbered from each other don't all need to be evaluated
+                                        __bitflags_flag_aliases! {
+                                            insert: [table[i] = $PublicBitFlags::$Flag],
+                                            $(#[$inner $($args)*])*
+                                        }
+                                    }}
+                                );
+                            },
+                            unnamed: {},
+                        });
+                    )*
+
+                    table
+                };
+
+                &TABLE
+            };
 
             type Bits = $T;
 
@@ -538,6 +1276,674 @@ macro_rules! __impl_public_bitflags_consts {
             fn from_bits_retain(bits: $T) -> $PublicBitFlags {
                 $PublicBitFlags::from_bits_retain(bits)
             }
+
+            fn from_name(name: &str) -> $crate::__private::core::option::Option<$PublicBitFlags> {
+                // The generated method also considers aliases
+                $PublicBitFlags::from_name(name)
+            }
         }
+
+        #[allow(deprecated, unused_mut, unused_variables)]
+        const _: () = {
+            // Named so that it appears in the error when an alias isn't unique
+            const FLAG_ALIASES_MUST_BE_UNIQUE: () = {
+                let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+                let empty = <$PublicBitFlags as $crate::Flags>::EMPTY_NAME;
+
+                let aliases = <$PublicBitFlags as $crate::Flags>::ALIASES;
+
+                let mut conflict = false;
+
+                let mut a = 0;
+                while a < aliases.len() {
+                    conflict = conflict || aliases[a].name().is_empty();
+                    conflict = conflict || $crate::__private::str_eq(aliases[a].name(), empty);
+
+                    let mut i = 0;
+                    while i < flags.len() {
+                        conflict = conflict || $crate::__private::str_eq(aliases[a].name(), flags[i].name());
+                        i += 1;
+                    }
+
+                    a += 1;
+                }
+
+                // If an alias is empty, the name of a flag, or the text of an empty flags value
+                // then fail to compile. This indexes
+                // out-of-bounds instead of panicking so it's supported by older compilers
+                let alias_conflicts = [()];
+                alias_conflicts[conflict as usize]
+            };
+
+            // Named so that it appears in the error when a renamed flag can't be parsed
+            const FLAG_NAMES_MUST_BE_VALID: () = {
+                let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+
+                let mut invalid = false;
+
+                $(
+                    __bitflags_flag!({
+                        name: $Flag,
+                        named: {
+                            __bitflags_expr_safe_attrs!(
+                                $(#[$inner $($args)*])*
+                                {{
+                                    let name: &str = __bitflags_flag_name!($Flag $(#[$inner $($args)*])*);
+
+                                    invalid = invalid || !$crate::__private::is_valid_name(name);
+
+                                    let mut matches = 0;
+                                    let mut i = 0;
+                                    while i < flags.len() {
+                                        if $crate::__private::str_eq(name, flags[i].name()) {
+                                            matches += 1;
+                                        }
+                                        i += 1;
+                                    }
+
+                                    invalid = invalid || matches != 1;
+                                }}
+                            );
+                        },
+                        unnamed: {},
+                    });
+                )*
+
+                // If a name can't be parsed or is shared by multiple flags then fail to compile
+                let invalid_names = [()];
+                invalid_names[invalid as usize]
+            };
+
+            // Named so that it appears in the error when the `empty` option can't be parsed
+            const EMPTY_NAME_MUST_BE_VALID: () = {
+                let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+                let empty = <$PublicBitFlags as $crate::Flags>::EMPTY_NAME;
+
+                let mut invalid = false;
+
+                // The text is empty by default, like the names of unnamed flags
+                if !empty.is_empty() {
+                    invalid = !$crate::__private::is_valid_name(empty);
+
+                    let mut i = 0;
+                    while i < flags.len() {
+                        invalid = invalid || $crate::__private::str_eq(empty, flags[i].name());
+                        i += 1;
+                    }
+                }
+
+                // If the text of an empty flags value can't be parsed back, or is the name
+                // of a flag, then fail to compile
+                let invalid_empty = [()];
+                invalid_empty[invalid as usize]
+            };
+
+            let () = FLAG_ALIASES_MUST_BE_UNIQUE;
+            let () = EMPTY_NAME_MUST_BE_VALID;
+            FLAG_NAMES_MUST_BE_VALID
+        };
+    };
+}
+
+/// Implement opt-in traits on the public (user-facing) bitflags type.
+///
+/// Options are given as a `,` separated list in `#[bitflags(..)]` attributes.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __impl_public_bitflags_options {
+    (
+        $PublicBitFlags:ident: $T:ty {}
+    ) => {};
+    (
+        $PublicBitFlags:ident: $T:ty {
+            , $($rest:tt)*
+        }
+    ) => {
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            inherent_methods, $($rest:tt)*
+        }
+    ) => {
+        // The methods are generated alongside the others, since they need the flags
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            atomic, $($rest:tt)*
+        }
+    ) => {
+        __impl_public_bitflags_atomic! {
+            $PublicBitFlags: $T
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            bits_convert = retain, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::convert::From<$PublicBitFlags> for $T {
+            /// Get the underlying bits value.
+            #[inline]
+            fn from(flags: $PublicBitFlags) -> Self {
+                flags.bits()
+            }
+        }
+
+        impl $crate::__private::core::convert::From<$T> for $PublicBitFlags {
+            /// Convert from a bits value exactly, retaining any unknown bits.
+            #[inline]
+            fn from(bits: $T) -> Self {
+                Self::from_bits_retain(bits)
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            bits_convert, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::convert::From<$PublicBitFlags> for $T {
+            /// Get the underlying bits value.
+            #[inline]
+            fn from(flags: $PublicBitFlags) -> Self {
+                flags.bits()
+            }
+        }
+
+        impl $crate::__private::core::convert::TryFrom<$T> for $PublicBitFlags {
+            type Error = $crate::UnknownBits<$T>;
+
+            /// Convert from a bits value, returning any unknown bits as an error.
+            #[inline]
+            fn try_from(bits: $T) -> $crate::__private::core::result::Result<Self, Self::Error> {
+                <Self as $crate::Flags>::from_bits_strict(bits)
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            bits_ops, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::ops::BitOr<$T> for $PublicBitFlags {
+            type Output = Self;
+
+            /// The bitwise or (`|`) of the bits in a flags value and a bits value.
+            #[inline]
+            fn bitor(self, other: $T) -> Self {
+                self.union(Self::from_bits_retain(other))
+            }
+        }
+
+        impl $crate::__private::core::ops::BitOrAssign<$T> for $PublicBitFlags {
+            /// The bitwise or (`|`) of the bits in a flags value and a bits value.
+            #[inline]
+            fn bitor_assign(&mut self, other: $T) {
+                self.insert(Self::from_bits_retain(other));
+            }
+        }
+
+        impl $crate::__private::core::ops::BitXor<$T> for $PublicBitFlags {
+            type Output = Self;
+
+            /// The bitwise exclusive-or (`^`) of the bits in a flags value and a bits value.
+            #[inline]
+            fn bitxor(self, other: $T) -> Self {
+                self.symmetric_difference(Self::from_bits_retain(other))
+            }
+        }
+
+        impl $crate::__private::core::ops::BitXorAssign<$T> for $PublicBitFlags {
+            /// The bitwise exclusive-or (`^`) of the bits in a flags value and a bits value.
+            #[inline]
+            fn bitxor_assign(&mut self, other: $T) {
+                self.toggle(Self::from_bits_retain(other));
+            }
+        }
+
+        impl $crate::__private::core::ops::BitAnd<$T> for $PublicBitFlags {
+            type Output = Self;
+
+            /// The bitwise and (`&`) of the bits in a flags value and a bits value.
+            #[inline]
+            fn bitand(self, other: $T) -> Self {
+                self.intersection(Self::from_bits_retain(other))
+            }
+        }
+
+        impl $crate::__private::core::ops::BitAndAssign<$T> for $PublicBitFlags {
+            /// The bitwise and (`&`) of the bits in a flags value and a bits value.
+            #[inline]
+            fn bitand_assign(&mut self, other: $T) {
+                *self = Self::from_bits_retain(self.bits()).intersection(Self::from_bits_retain(other));
+            }
+        }
+
+        impl $crate::__private::core::ops::BitOr<$PublicBitFlags> for $T {
+            type Output = $PublicBitFlags;
+
+            /// The bitwise or (`|`) of the bits in a bits value and a flags value.
+            #[inline]
+            fn bitor(self, other: $PublicBitFlags) -> $PublicBitFlags {
+                $PublicBitFlags::from_bits_retain(self).union(other)
+            }
+        }
+
+        impl $crate::__private::core::ops::BitXor<$PublicBitFlags> for $T {
+            type Output = $PublicBitFlags;
+
+            /// The bitwise exclusive-or (`^`) of the bits in a bits value and a flags value.
+            #[inline]
+            fn bitxor(self, other: $PublicBitFlags) -> $PublicBitFlags {
+                $PublicBitFlags::from_bits_retain(self).symmetric_difference(other)
+            }
+        }
+
+        impl $crate::__private::core::ops::BitAnd<$PublicBitFlags> for $T {
+            type Output = $PublicBitFlags;
+
+            /// The bitwise and (`&`) of the bits in a bits value and a flags value.
+            #[inline]
+            fn bitand(self, other: $PublicBitFlags) -> $PublicBitFlags {
+                $PublicBitFlags::from_bits_retain(self).intersection(other)
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            custom_bits, $($rest:tt)*
+        }
+    ) => {
+        // The methods are generated without `const` by `__bitflags_const_fn`
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            debug, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::fmt::Debug for $PublicBitFlags {
+            fn fmt(
+                &self,
+                f: &mut $crate::__private::core::fmt::Formatter,
+            ) -> $crate::__private::core::fmt::Result {
+                f.debug_tuple($crate::__private::core::stringify!($PublicBitFlags))
+                    .field(&$crate::__private::AsDebug(self))
+                    .finish()
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            default = $default:expr, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::default::Default for $PublicBitFlags {
+            #[inline]
+            fn default() -> Self {
+                $default
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            default, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::default::Default for $PublicBitFlags {
+            #[inline]
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            deny_overlap, $($rest:tt)*
+        }
+    ) => {
+        const _: () = {
+            // Named so that it appears in the error when flags share bits
+            const FLAGS_MUST_NOT_SHARE_BITS: () = {
+                let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+
+                let mut overlap = false;
+                let mut seen = <$T as $crate::Bits>::EMPTY;
+
+                let mut i = 0;
+                while i < flags.len() {
+                    let bits = flags[i].value().bits();
+
+                    if flags[i].is_named() && bits != <$T as $crate::Bits>::EMPTY {
+                        // A flag is composite if it's made entirely of other, smaller flags
+                        let mut parts = <$T as $crate::Bits>::EMPTY;
+
+                        let mut j = 0;
+                        while j < flags.len() {
+                            let other = flags[j].value().bits();
+
+                            if flags[j].is_named() && other != bits && bits & other == other {
+                                parts = parts | other;
+                            }
+
+                            j += 1;
+                        }
+
+                        if parts != bits {
+                            overlap = overlap || bits & seen != <$T as $crate::Bits>::EMPTY;
+                            seen = seen | bits;
+                        }
+                    }
+
+                    i += 1;
+                }
+
+                // If any flags share bits then fail to compile. This indexes out-of-bounds
+                // instead of panicking so it's supported by older compilers
+                let flags_share_bits = [()];
+                flags_share_bits[overlap as usize]
+            };
+
+            FLAGS_MUST_NOT_SHARE_BITS
+        };
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            display, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::fmt::Display for $PublicBitFlags {
+            fn fmt(
+                &self,
+                f: &mut $crate::__private::core::fmt::Formatter,
+            ) -> $crate::__private::core::fmt::Result {
+                $crate::parser::to_writer(self, f)
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            empty = $empty:literal, $($rest:tt)*
+        }
+    ) => {
+        // The text is used by the `Flags` implementation from `__impl_public_bitflags_consts`
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            import = $Base:ty, $($rest:tt)*
+        }
+    ) => {
+        // The flags are imported by `__impl_public_bitflags_consts`
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            exhaustive, $($rest:tt)*
+        }
+    ) => {
+        const _: () = {
+            // Named so that it appears in the error when some bits aren't defined
+            const ALL_BITS_MUST_BE_DEFINED: () = {
+                let missing = $PublicBitFlags::all().bits() != <$T as $crate::Bits>::ALL;
+
+                // If any bits aren't part of a defined flag then fail to compile. This indexes
+                // out-of-bounds instead of panicking so it's supported by older compilers
+                let bits_not_defined = [()];
+                bits_not_defined[missing as usize]
+            };
+
+            ALL_BITS_MUST_BE_DEFINED
+        };
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            groups($($Group:ident),* $(,)?), $($rest:tt)*
+        }
+    ) => {
+        impl $PublicBitFlags {
+            /// The groups of flags declared by the `groups` option.
+            pub const GROUPS: &'static [$crate::Flag<$PublicBitFlags>] = &[
+                $(
+                    $crate::Flag::new(
+                        $crate::__private::core::stringify!($Group),
+                        $PublicBitFlags::$Group,
+                    ),
+                )*
+            ];
+
+            /// Yield the name of each group along with the intersection of this value and the group.
+            ///
+            /// Every group is yielded, even if the intersection is empty.
+            #[inline]
+            pub fn groups(
+                &self,
+            ) -> impl $crate::__private::core::iter::Iterator<Item = (&'static str, Self)> {
+                let bits = self.bits();
+
+                Self::GROUPS.iter().map(move |group| {
+                    (
+                        group.name(),
+                        Self::from_bits_retain(bits & group.value().bits()),
+                    )
+                })
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            kind = $Kind:ident, $($rest:tt)*
+        }
+    ) => {
+        // The enum is generated by `__bitflags_kind`
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            no_ops, $($rest:tt)*
+        }
+    ) => {
+        // The operators aren't generated by `__bitflags_ops`
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            from_str, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::str::FromStr for $PublicBitFlags {
+            type Err = $crate::parser::ParseError;
+
+            fn from_str(s: &str) -> $crate::__private::core::result::Result<Self, Self::Err> {
+                $crate::parser::from_str::<$PublicBitFlags>(s)
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            subset_ord, $($rest:tt)*
+        }
+    ) => {
+        impl $crate::__private::core::cmp::PartialOrd for $PublicBitFlags {
+            /// Compare flags values by set inclusion, so a value is less than another if it's a strict subset of it.
+            ///
+            /// Flags values that aren't subsets of each other are incomparable.
+            fn partial_cmp(
+                &self,
+                other: &Self,
+            ) -> $crate::__private::core::option::Option<$crate::__private::core::cmp::Ordering> {
+                let (a, b) = (self.bits(), other.bits());
+
+                if a == b {
+                    $crate::__private::core::option::Option::Some($crate::__private::core::cmp::Ordering::Equal)
+                } else if a & b == a {
+                    $crate::__private::core::option::Option::Some($crate::__private::core::cmp::Ordering::Less)
+                } else if a & b == b {
+                    $crate::__private::core::option::Option::Some($crate::__private::core::cmp::Ordering::Greater)
+                } else {
+                    $crate::__private::core::option::Option::None
+                }
+            }
+        }
+
+        __impl_public_bitflags_options! {
+            $PublicBitFlags: $T {
+                $($rest)*
+            }
+        }
+    };
+    (
+        $PublicBitFlags:ident: $T:ty {
+            $option:tt $($rest:tt)*
+        }
+    ) => {
+        $crate::__private::core::compile_error!($crate::__private::core::concat!(
+            "unrecognized `bitflags` option `",
+            $crate::__private::core::stringify!($option),
+            "`"
+        ));
+    };
+}
+
+/// Implement the `atomic` option on the public (user-facing) bitflags type.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(feature = "atomic")]
+macro_rules! __impl_public_bitflags_atomic {
+    (
+        $PublicBitFlags:ident: $T:ty
+    ) => {
+        impl $PublicBitFlags {
+            /// Create an atomic flags value.
+            ///
+            /// Unlike `AtomicFlags::new`, this method is `const`, so it can be used
+            /// to initialize a `static`.
+            #[inline]
+            pub const fn into_atomic(self) -> $crate::atomic::AtomicFlags<Self> {
+                $crate::atomic::AtomicFlags::from_atomic(
+                    <<$T as $crate::atomic::AtomicBits>::Atomic>::new(self.bits()),
+                )
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+#[cfg(not(feature = "atomic"))]
+macro_rules! __impl_public_bitflags_atomic {
+    (
+        $PublicBitFlags:ident: $T:ty
+    ) => {
+        $crate::__private::core::compile_error!(
+            "the `atomic` option requires the `atomic` feature of `bitflags`"
+        );
     };
 }
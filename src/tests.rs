@@ -1,30 +1,78 @@
+mod aliases;
 mod all;
+mod all_named;
+#[cfg(feature = "atomic")]
+mod atomic;
+mod bit_indices;
 mod bits;
+mod clear;
 mod complement;
+mod complement_of;
 mod contains;
+mod contains_unknown_bits;
+mod count;
+mod diff;
 mod difference;
+mod display;
+mod drain;
 mod empty;
 mod eq;
 mod extend;
+mod fetch;
+mod first;
 mod flags;
 mod fmt;
+mod from_bit_index;
 mod from_bits;
 mod from_bits_retain;
+mod from_bits_strict;
 mod from_bits_truncate;
 mod from_name;
+mod from_name_ignore_case;
+mod get_by_name;
+mod implies;
 mod insert;
+mod insert_changed;
 mod intersection;
+mod intersection_all;
 mod intersects;
+mod invert_within;
+#[cfg(feature = "std")]
+mod io;
 mod is_all;
+mod is_disjoint;
 mod is_empty;
+mod is_subset;
+mod is_superset;
 mod iter;
+mod iter_ranges;
+mod iter_unknown_bits;
+mod last;
+mod mask_range;
+mod non_zero;
 mod parser;
+mod pop_first;
+mod pop_last;
 mod remove;
+mod remove_changed;
+mod rename;
+mod replace;
+mod repr;
+mod retain;
+mod set;
+mod set_by_name;
+mod split_known;
 mod symmetric_difference;
+mod toggle;
+mod truncate;
 mod union;
+mod union_all;
+mod unknown_bits;
+mod wide;
 
 bitflags! {
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(display, from_str, inherent_methods)]
     pub struct TestFlags: u8 {
         /// 1
         const A = 1;
@@ -40,6 +88,7 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(display, from_str, inherent_methods)]
     pub struct TestFlagsInvert: u8 {
         /// 1 | (1 << 1) | (1 << 2)
         const ABC = Self::A.bits() | Self::B.bits() | Self::C.bits();
@@ -55,12 +104,14 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(display, from_str, inherent_methods)]
     pub struct TestZero: u8 {
         /// 0
         const ZERO = 0;
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(inherent_methods)]
     pub struct TestZeroOne: u8 {
         /// 0
         const ZERO = 0;
@@ -70,6 +121,7 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(inherent_methods)]
     pub struct TestUnicode: u8 {
         /// 1
         const 一 = 1;
@@ -79,9 +131,11 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(inherent_methods)]
     pub struct TestEmpty: u8 {}
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(inherent_methods)]
     pub struct TestOverlapping: u8 {
         /// 1 | (1 << 1)
         const AB = 1 | (1 << 1);
@@ -91,6 +145,7 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(inherent_methods)]
     pub struct TestOverlappingFull: u8 {
         /// 1
         const A = 1;
@@ -106,6 +161,7 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(display, from_str, inherent_methods)]
     pub struct TestExternal: u8 {
         /// 1
         const A = 1;
@@ -124,6 +180,7 @@ bitflags! {
     }
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(inherent_methods)]
     pub struct TestExternalFull: u8 {
         /// External
         const _ = !0;
@@ -0,0 +1,153 @@
+use crate::{
+    parser::{from_str, from_str_ignore_case, ParseErrorKind},
+    Flag, Flags,
+};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[bitflags(display, from_str)]
+    struct TestAliases: u8 {
+        const READ = 1;
+
+        /// Renamed from `WRITE`
+        #[bitflags(alias = "WRITE")]
+        const MODIFY = 1 << 1;
+
+        #[bitflags(alias = "X", alias = "EXECUTE")]
+        #[bitflags(alias = "RUN")]
+        const EXEC = 1 << 2;
+
+        #[cfg(any())]
+        #[bitflags(alias = "NEVER")]
+        const CONFIGURED_OUT = 1 << 3;
+    }
+}
+
+#[test]
+fn cases() {
+    case(Some(TestAliases::MODIFY), "MODIFY");
+    case(Some(TestAliases::MODIFY), "WRITE");
+
+    case(Some(TestAliases::EXEC), "EXEC");
+    case(Some(TestAliases::EXEC), "X");
+    case(Some(TestAliases::EXEC), "EXECUTE");
+    case(Some(TestAliases::EXEC), "RUN");
+
+    case(None, "NEVER");
+    case(None, "write");
+    case(None, "");
+}
+
+#[track_caller]
+fn case(expected: Option<TestAliases>, name: &str) {
+    assert_eq!(
+        expected,
+        TestAliases::from_name(name),
+        "TestAliases::from_name({:?})",
+        name
+    );
+    assert_eq!(
+        expected,
+        <TestAliases as Flags>::from_name(name),
+        "Flags::from_name({:?})",
+        name
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        from_str::<TestAliases>("READ | MODIFY").unwrap(),
+        from_str::<TestAliases>("READ | WRITE").unwrap()
+    );
+    assert_eq!(
+        TestAliases::EXEC | TestAliases::MODIFY,
+        "RUN | WRITE".parse().unwrap()
+    );
+
+    // Aliases are never formatted
+    assert_eq!(
+        "MODIFY | EXEC",
+        (TestAliases::EXEC | TestAliases::MODIFY).to_string()
+    );
+    assert_eq!(
+        "TestAliases(MODIFY)",
+        format!("{:?}", TestAliases::from_name("WRITE").unwrap())
+    );
+    assert!(TestAliases::FLAGS.iter().all(|flag| flag.name() != "WRITE"));
+}
+
+#[test]
+fn ignore_case() {
+    assert_eq!(
+        Some(TestAliases::MODIFY),
+        TestAliases::from_name_ignore_case("write")
+    );
+    assert_eq!(
+        Some(TestAliases::EXEC),
+        <TestAliases as Flags>::from_name_ignore_case("Execute")
+    );
+    assert_eq!(None, TestAliases::from_name_ignore_case("never"));
+
+    assert_eq!(
+        TestAliases::MODIFY | TestAliases::EXEC,
+        from_str_ignore_case::<TestAliases>("write | run").unwrap()
+    );
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct TestAmbiguousAliases: u8 {
+        #[bitflags(alias = "Open")]
+        const READ = 1;
+
+        const OPEN = 1 << 1;
+
+        #[bitflags(alias = "read")]
+        const ACCESS = 1;
+    }
+}
+
+#[test]
+fn ignore_case_ambiguous() {
+    // Exact matches are still preferred
+    assert_eq!(
+        Some(TestAmbiguousAliases::READ),
+        TestAmbiguousAliases::from_name_ignore_case("Open")
+    );
+
+    // An alias matches a flag with a different value when ignoring case
+    assert_eq!(None, TestAmbiguousAliases::from_name_ignore_case("open"));
+    assert_eq!(
+        ParseErrorKind::AmbiguousNamedFlag,
+        from_str_ignore_case::<TestAmbiguousAliases>("open")
+            .unwrap_err()
+            .kind()
+    );
+
+    // Names and aliases of flags with the same value aren't ambiguous
+    assert_eq!(
+        Some(TestAmbiguousAliases::READ),
+        TestAmbiguousAliases::from_name_ignore_case("Read")
+    );
+}
+
+#[test]
+fn aliases_const() {
+    let aliases: Vec<_> = TestAliases::ALIASES
+        .iter()
+        .map(|alias| (alias.name(), alias.value().bits()))
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("WRITE", 1 << 1),
+            ("X", 1 << 2),
+            ("EXECUTE", 1 << 2),
+            ("RUN", 1 << 2)
+        ],
+        aliases
+    );
+
+    let _: &[Flag<TestAliases>] = TestAliases::ALIASES;
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = assert!(TestExternal::all_named().bits() == 1 | 1 << 1 | 1 << 2);
+
+#[test]
+fn cases() {
+    case(1 | 1 << 1 | 1 << 2, TestFlags::all_named);
+
+    case(0, TestZero::all_named);
+
+    case(0, TestEmpty::all_named);
+
+    case(1 | 1 << 1 | 1 << 2, TestExternal::all_named);
+
+    case(0, TestExternalFull::all_named);
+}
+
+#[test]
+fn all() {
+    // Without any unnamed flags, the named flags are all the known bits
+    assert_eq!(TestFlags::all(), TestFlags::all_named());
+    assert_eq!(TestOverlapping::all(), TestOverlapping::all_named());
+    assert_eq!(TestEmpty::all(), TestEmpty::all_named());
+
+    // Named flags are always known
+    assert_eq!(
+        0,
+        TestExternal::all_named().bits() & !TestExternal::all().bits()
+    );
+    assert_eq!(
+        0,
+        TestExternalFull::all_named().bits() & !TestExternalFull::all().bits()
+    );
+    assert_ne!(TestExternal::all(), TestExternal::all_named());
+}
+
+#[track_caller]
+fn case<T: Flags>(expected: T::Bits, inherent: impl FnOnce() -> T)
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(expected, inherent().bits(), "T::all_named()");
+    assert_eq!(expected, T::all_named().bits(), "Flags::all_named()");
+}
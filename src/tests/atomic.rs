@@ -0,0 +1,114 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use super::*;
+
+use crate::atomic::AtomicFlags;
+
+static FLAGS: AtomicFlags<TestFlags> = AtomicFlags::from_atomic(AtomicU8::new(1));
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(atomic)]
+    struct TestAtomicOption: i64 {
+        const A = 1;
+        const B = 1 << 40;
+    }
+}
+
+static OPTION_FLAGS: AtomicFlags<TestAtomicOption> =
+    TestAtomicOption::A.union(TestAtomicOption::B).into_atomic();
+
+#[test]
+fn cases() {
+    let flags = AtomicFlags::new(TestFlags::A);
+
+    assert_eq!(TestFlags::A, flags.insert(TestFlags::B, Ordering::Relaxed));
+    assert_eq!(TestFlags::A | TestFlags::B, flags.load(Ordering::Relaxed));
+
+    assert_eq!(
+        TestFlags::A | TestFlags::B,
+        flags.remove(TestFlags::A, Ordering::Relaxed)
+    );
+    assert_eq!(TestFlags::B, flags.load(Ordering::Relaxed));
+
+    assert_eq!(
+        TestFlags::B,
+        flags.toggle(TestFlags::ABC, Ordering::Relaxed)
+    );
+    assert_eq!(TestFlags::A | TestFlags::C, flags.load(Ordering::Relaxed));
+
+    assert_eq!(
+        TestFlags::A | TestFlags::C,
+        flags.swap(TestFlags::from_bits_retain(1 << 3), Ordering::Relaxed)
+    );
+    assert_eq!(
+        TestFlags::from_bits_retain(1 << 3),
+        flags.load(Ordering::Relaxed)
+    );
+
+    flags.set(TestFlags::C, true, Ordering::Relaxed);
+    flags.store(
+        flags.load(Ordering::Relaxed) | TestFlags::A,
+        Ordering::Relaxed,
+    );
+    assert_eq!(
+        TestFlags::from_bits_retain(1 | 1 << 2 | 1 << 3),
+        flags.into_inner()
+    );
+
+    assert_eq!(
+        TestFlags::empty(),
+        AtomicFlags::<TestFlags>::default().into_inner()
+    );
+}
+
+#[test]
+fn static_flags() {
+    assert_eq!(TestFlags::A, FLAGS.load(Ordering::Relaxed));
+    assert_eq!("AtomicFlags(TestFlags(A))", format!("{:?}", FLAGS));
+}
+
+#[test]
+fn atomic_option() {
+    assert_eq!(
+        TestAtomicOption::A | TestAtomicOption::B,
+        OPTION_FLAGS.remove(TestAtomicOption::A, Ordering::Relaxed)
+    );
+    assert_eq!(TestAtomicOption::B, OPTION_FLAGS.load(Ordering::Relaxed));
+
+    assert_eq!(
+        TestAtomicOption::A,
+        TestAtomicOption::A.into_atomic().into_inner()
+    );
+}
+
+#[test]
+fn concurrent_updates() {
+    const THREADS: u8 = 8;
+    const ITERATIONS: usize = 10_000;
+
+    let flags = AtomicFlags::new(TestFlags::empty());
+
+    // Each thread owns a single bit and flips it on and off,
+    // so any lost update would leave another thread's bit in the wrong state
+    std::thread::scope(|s| {
+        for i in 0..THREADS {
+            let flags = &flags;
+            s.spawn(move || {
+                let bit = TestFlags::from_bits_retain(1 << i);
+
+                for _ in 0..ITERATIONS {
+                    assert!(!flags.insert(bit, Ordering::AcqRel).contains(bit));
+                    assert!(flags.remove(bit, Ordering::AcqRel).contains(bit));
+                }
+
+                flags.insert(bit, Ordering::AcqRel);
+            });
+        }
+    });
+
+    assert_eq!(
+        TestFlags::from_bits_retain(u8::MAX),
+        flags.load(Ordering::Acquire)
+    );
+}
@@ -0,0 +1,134 @@
+use super::*;
+
+use crate::iter::IterBitIndices;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), &[], TestFlags::bit_indices);
+    case(TestFlags::A, &[0], TestFlags::bit_indices);
+    case(TestFlags::ABC, &[0, 1, 2], TestFlags::bit_indices);
+
+    // Unknown bits are yielded like any others
+    case(
+        TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        &[3, 7],
+        TestFlags::bit_indices,
+    );
+    case(
+        TestFlags::B | TestFlags::from_bits_retain(1 << 5),
+        &[1, 5],
+        TestFlags::bit_indices,
+    );
+
+    case(TestZero::ZERO, &[], TestZero::bit_indices);
+    case(
+        TestExternal::from_bits_retain(u8::MAX),
+        &[0, 1, 2, 3, 4, 5, 6, 7],
+        TestExternal::bit_indices,
+    );
+}
+
+#[test]
+fn widths() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1;
+        }
+
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Wide: u128 {
+            const A = 1;
+        }
+
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct WideSigned: i128 {
+            const A = 1;
+        }
+    }
+
+    case(
+        Signed::from_bits_retain(i8::MIN | 1),
+        &[0, 7],
+        Signed::bit_indices,
+    );
+    case(
+        Wide::from_bits_retain(1 | 1 << 64 | 1 << 127),
+        &[0, 64, 127],
+        Wide::bit_indices,
+    );
+    case(
+        WideSigned::from_bits_retain(i128::MIN),
+        &[127],
+        WideSigned::bit_indices,
+    );
+    assert_eq!(128, Wide::from_bits_retain(!0).bit_indices().len());
+    assert_eq!(
+        (0..128).rev().collect::<Vec<_>>(),
+        Wide::from_bits_retain(!0)
+            .bit_indices()
+            .rev()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn double_ended() {
+    let mut iter = TestFlags::from_bits_retain(0b1010_0101).bit_indices();
+
+    assert_eq!(4, iter.len());
+    assert_eq!(Some(7), iter.next_back());
+    assert_eq!(Some(0), iter.next());
+    assert_eq!(Some(5), iter.next_back());
+    assert_eq!(1, iter.len());
+    assert_eq!(Some(2), iter.next());
+    assert_eq!(None, iter.next_back());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn roundtrip() {
+    for bits in 0..=u8::MAX {
+        let value = TestFlags::from_bits_retain(bits);
+
+        let mut rebuilt = TestFlags::empty();
+        for index in value.bit_indices() {
+            rebuilt |= TestFlags::from_bit_index(index).unwrap();
+        }
+
+        assert_eq!(value, rebuilt, "{:?}", value);
+        assert_eq!(
+            value.bits().count_ones() as usize,
+            value.bit_indices().len(),
+            "{:?}",
+            value
+        );
+        assert_eq!(
+            value.bit_indices().rev().collect::<Vec<_>>(),
+            {
+                let mut forward = value.bit_indices().collect::<Vec<_>>();
+                forward.reverse();
+                forward
+            },
+            "{:?}",
+            value
+        );
+    }
+}
+
+#[track_caller]
+fn case<T: std::fmt::Debug>(
+    value: T,
+    expected: &[u32],
+    inherent: impl FnOnce(&T) -> IterBitIndices,
+) {
+    assert_eq!(
+        expected,
+        inherent(&value).collect::<Vec<_>>(),
+        "{:?}.bit_indices()",
+        value
+    );
+}
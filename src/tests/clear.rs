@@ -0,0 +1,41 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), TestFlags::clear);
+    case(TestFlags::ABC, TestFlags::clear);
+    case(TestFlags::from_bits_retain(1 | 1 << 3), TestFlags::clear);
+
+    case(TestExternal::all(), TestExternal::clear);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(value: T, mut inherent: impl FnMut(&mut T))
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    assert_eq!(
+        T::empty().bits(),
+        {
+            let mut value = value;
+            inherent(&mut value);
+            value
+        }
+        .bits(),
+        "{:?}.clear()",
+        value
+    );
+    assert_eq!(
+        T::empty().bits(),
+        {
+            let mut value = value;
+            Flags::clear(&mut value);
+            value
+        }
+        .bits(),
+        "Flags::clear({:?})",
+        value
+    );
+}
@@ -2,6 +2,8 @@ use super::*;
 
 use crate::Flags;
 
+const _: () = assert!(TestFlags::A.complement().bits() == 1 << 1 | 1 << 2);
+
 #[test]
 fn cases() {
     case(0, TestFlags::all(), TestFlags::complement);
@@ -0,0 +1,91 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = assert!(TestFlags::A.complement_of(TestFlags::ABC).bits() == 1 << 1 | 1 << 2);
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::ABC, 1 << 1 | 1 << 2),
+            (TestFlags::A | TestFlags::B, 1 << 1),
+            (TestFlags::A, 0),
+            (TestFlags::empty(), 0),
+        ],
+        TestFlags::complement_of,
+    );
+
+    // Unknown bits in the mask are kept
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::from_bits_retain(1 << 3 | 1 << 4), 1 << 4),
+            (
+                TestFlags::B | TestFlags::from_bits_retain(1 << 7),
+                1 << 1 | 1 << 7,
+            ),
+            (TestFlags::from_bits_retain(!0), !(1 | 1 << 3)),
+        ],
+        TestFlags::complement_of,
+    );
+
+    // A mask that doesn't intersect the value is returned as-is
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::B, 1 << 1),
+            (TestFlags::B | TestFlags::C, 1 << 1 | 1 << 2),
+            (TestFlags::from_bits_retain(1 << 3), 1 << 3),
+        ],
+        TestFlags::complement_of,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[(TestOverlapping::BC, 1 << 2)],
+        TestOverlapping::complement_of,
+    );
+}
+
+#[test]
+fn complement() {
+    for bits in 0..=u8::MAX {
+        let value = TestFlags::from_bits_retain(bits);
+
+        assert_eq!(
+            value.complement(),
+            value.complement_of(TestFlags::all()),
+            "{:?}.complement_of(all)",
+            value
+        );
+        assert!(value.complement_of(TestFlags::ABC).is_disjoint(value));
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits)],
+    mut inherent: impl FnMut(T, T) -> T,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(value, *input).bits(),
+            "{:?}.complement_of({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::complement_of(value, *input).bits(),
+            "Flags::complement_of({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
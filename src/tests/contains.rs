@@ -2,6 +2,9 @@ use super::*;
 
 use crate::Flags;
 
+const _: () =
+    assert!(TestFlags::ABC.contains(TestFlags::A) && !TestFlags::A.contains(TestFlags::ABC));
+
 #[test]
 fn cases() {
     case(
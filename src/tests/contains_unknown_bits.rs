@@ -0,0 +1,55 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = assert!(TestFlags::from_bits_retain(1 << 3).contains_unknown_bits());
+
+#[test]
+fn cases() {
+    case(false, TestFlags::empty(), TestFlags::contains_unknown_bits);
+    case(false, TestFlags::ABC, TestFlags::contains_unknown_bits);
+    case(
+        true,
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::contains_unknown_bits,
+    );
+    case(
+        true,
+        TestFlags::A | TestFlags::from_bits_retain(1 << 7),
+        TestFlags::contains_unknown_bits,
+    );
+
+    case(
+        true,
+        TestZero::from_bits_retain(1),
+        TestZero::contains_unknown_bits,
+    );
+
+    // `const _ = !0` makes every bit known
+    case(
+        false,
+        TestExternal::from_bits_retain(u8::MAX),
+        TestExternal::contains_unknown_bits,
+    );
+    case(
+        false,
+        TestExternalFull::from_bits_retain(1 << 3),
+        TestExternalFull::contains_unknown_bits,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(expected: bool, value: T, inherent: impl FnOnce(&T) -> bool) {
+    assert_eq!(
+        expected,
+        inherent(&value),
+        "{:?}.contains_unknown_bits()",
+        value
+    );
+    assert_eq!(
+        expected,
+        Flags::contains_unknown_bits(&value),
+        "Flags::contains_unknown_bits({:?})",
+        value
+    );
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+const _: () = assert!(TestFlags::ABC.count() == 3);
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty(), TestFlags::count);
+    case(1, TestFlags::A, TestFlags::count);
+    case(2, TestFlags::A | TestFlags::C, TestFlags::count);
+    case(3, TestFlags::ABC, TestFlags::count);
+
+    // Unknown bits aren't counted
+    case(0, TestFlags::from_bits_retain(1 << 3), TestFlags::count);
+    case(
+        1,
+        TestFlags::B | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        TestFlags::count,
+    );
+
+    // Composite flags aren't counted on their own
+    case(3, TestFlagsInvert::ABC, TestFlagsInvert::count);
+    case(1, TestFlagsInvert::B, TestFlagsInvert::count);
+
+    // Multi-bit flags aren't single-bit flags
+    case(
+        0,
+        TestOverlapping::AB | TestOverlapping::BC,
+        TestOverlapping::count,
+    );
+
+    // Flags with the same bits are only counted once
+    case(2, TestOverlappingFull::all(), TestOverlappingFull::count);
+
+    case(0, TestZero::ZERO, TestZero::count);
+    case(0, TestEmpty::from_bits_retain(u8::MAX), TestEmpty::count);
+
+    // Unnamed flags aren't counted
+    case(3, TestExternal::all(), TestExternal::count);
+    case(
+        3,
+        TestExternal::from_bits_retain(u8::MAX),
+        TestExternal::count,
+    );
+    case(0, TestExternalFull::all(), TestExternalFull::count);
+}
+
+#[test]
+fn signed() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1;
+            const MIN = i8::MIN;
+            const ALL = !0;
+        }
+    }
+
+    case(2, Signed::all(), Signed::count);
+    case(1, Signed::MIN, Signed::count);
+    case(0, Signed::from_bits_retain(1 << 1), Signed::count);
+}
+
+#[track_caller]
+fn case<T: std::fmt::Debug>(expected: u32, value: T, inherent: impl FnOnce(&T) -> u32) {
+    assert_eq!(expected, inherent(&value), "{:?}.count()", value);
+}
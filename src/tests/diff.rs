@@ -0,0 +1,177 @@
+use super::*;
+
+use crate::{Bits, Flags};
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), TestFlags::empty(), 0, 0, "");
+    case(
+        TestFlags::A | TestFlags::B,
+        TestFlags::A | TestFlags::B,
+        0,
+        0,
+        "",
+    );
+
+    case(TestFlags::A, TestFlags::A | TestFlags::B, 1 << 1, 0, "+B");
+    case(
+        TestFlags::A | TestFlags::C,
+        TestFlags::A | TestFlags::B,
+        1 << 1,
+        1 << 2,
+        "+B -C",
+    );
+    case(
+        TestFlags::ABC,
+        TestFlags::empty(),
+        0,
+        1 | 1 << 1 | 1 << 2,
+        "-A -B -C",
+    );
+
+    // Composites are named in the same way as `iter_names`, so by their defined flags here
+    case(
+        TestFlags::empty(),
+        TestFlags::ABC,
+        1 | 1 << 1 | 1 << 2,
+        0,
+        "+A +B +C",
+    );
+    case(TestFlags::A, TestFlags::ABC, 1 << 1 | 1 << 2, 0, "+B +C");
+    case(TestFlags::ABC, TestFlags::B, 0, 1 | 1 << 2, "-A -C");
+
+    case(
+        TestFlagsInvert::empty(),
+        TestFlagsInvert::ABC,
+        1 | 1 << 1 | 1 << 2,
+        0,
+        "+ABC",
+    );
+    case(
+        TestFlagsInvert::ABC,
+        TestFlagsInvert::B,
+        0,
+        1 | 1 << 2,
+        "-A -C",
+    );
+
+    // Unknown bits are compared like any others
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        TestFlags::B | TestFlags::from_bits_retain(1 << 7),
+        1 << 1 | 1 << 7,
+        1 | 1 << 3,
+        "+B +0x80 -A -0x8",
+    );
+    case(
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::from_bits_retain(1 << 3),
+        0,
+        0,
+        "",
+    );
+
+    case(
+        TestOverlapping::AB,
+        TestOverlapping::BC,
+        1 << 2,
+        1,
+        "+0x4 -0x1",
+    );
+
+    case(
+        TestExternal::A,
+        TestExternal::from_bits_retain(1 << 5),
+        1 << 5,
+        1,
+        "+0x20 -A",
+    );
+}
+
+#[test]
+fn names() {
+    let changes = Flags::diff(TestFlags::A | TestFlags::C, TestFlags::ABC);
+
+    assert_eq!(
+        vec!["B"],
+        changes
+            .added_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(0, changes.removed_names().count());
+
+    let changes = Flags::diff(TestFlags::ABC, TestFlags::from_bits_retain(1 << 4));
+
+    assert_eq!(0, changes.added_names().count());
+    assert_eq!(
+        vec!["A", "B", "C"],
+        changes
+            .removed_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn roundtrip() {
+    for old in 0..=u8::MAX {
+        for new in [0, 1, 1 << 3, 1 << 1 | 1 << 7, u8::MAX] {
+            let (old, new) = (
+                TestFlags::from_bits_retain(old),
+                TestFlags::from_bits_retain(new),
+            );
+            let changes = Flags::diff(old, new);
+
+            assert!(changes.added().is_disjoint(*changes.removed()));
+
+            let mut applied = old;
+            applied.insert(*changes.added());
+            applied.remove(*changes.removed());
+
+            assert_eq!(new, applied, "Flags::diff({:?}, {:?})", old, new);
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    old: T,
+    new: T,
+    added: T::Bits,
+    removed: T::Bits,
+    display: &str,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy + crate::parser::WriteHex,
+{
+    let changes = Flags::diff(old, new);
+
+    assert_eq!(
+        added,
+        changes.added().bits(),
+        "Flags::diff({:?}, {:?}).added()",
+        old,
+        new
+    );
+    assert_eq!(
+        removed,
+        changes.removed().bits(),
+        "Flags::diff({:?}, {:?}).removed()",
+        old,
+        new
+    );
+    assert_eq!(
+        added == T::Bits::EMPTY && removed == T::Bits::EMPTY,
+        changes.is_empty(),
+        "Flags::diff({:?}, {:?}).is_empty()",
+        old,
+        new
+    );
+    assert_eq!(
+        display,
+        changes.to_string(),
+        "Flags::diff({:?}, {:?})",
+        old,
+        new
+    );
+}
@@ -2,6 +2,8 @@ use super::*;
 
 use crate::Flags;
 
+const _: () = assert!(TestFlags::ABC.difference(TestFlags::B).bits() == 1 | 1 << 2);
+
 #[test]
 fn cases() {
     case(
@@ -0,0 +1,55 @@
+use super::*;
+
+use core::str::FromStr;
+
+#[test]
+#[cfg(not(miri))] // Very slow in miri
+fn roundtrip() {
+    for a in 0u8..=255 {
+        for b in 0u8..=255 {
+            let f = TestFlags::from_bits_retain(a | b);
+            assert_eq!(f, TestFlags::from_str(&f.to_string()).unwrap());
+
+            let f = TestExternal::from_bits_retain(a | b);
+            assert_eq!(f, TestExternal::from_str(&f.to_string()).unwrap());
+        }
+    }
+}
+
+#[test]
+fn cases() {
+    case("", TestFlags::empty());
+    case("A", TestFlags::A);
+    case("A | B | C", TestFlags::ABC);
+    case(
+        "A | 0x8",
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+    );
+    case("0x8", TestFlags::from_bits_retain(1 << 3));
+
+    case("ABC", TestFlagsInvert::ABC);
+
+    case("", TestZero::ZERO);
+
+    case("A | B | C | 0xf8", TestExternal::all());
+}
+
+#[test]
+fn invalid() {
+    assert!(TestFlags::from_str("A | D").is_err());
+    assert!(TestFlags::from_str("A |").is_err());
+}
+
+#[track_caller]
+fn case<T: std::fmt::Display + FromStr + std::fmt::Debug + PartialEq>(expected: &str, value: T)
+where
+    T::Err: std::fmt::Debug,
+{
+    assert_eq!(expected, value.to_string(), "{:?}.to_string()", value);
+    assert_eq!(
+        value,
+        T::from_str(expected).unwrap(),
+        "T::from_str({:?})",
+        expected
+    );
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+use std::panic;
+
+#[test]
+fn cases() {
+    let mut value = TestFlags::ABC;
+    assert_eq!(
+        vec![TestFlags::A, TestFlags::B, TestFlags::C],
+        value.drain().collect::<Vec<_>>()
+    );
+    assert_eq!(TestFlags::empty(), value);
+
+    // Unknown bits are left as-is
+    let mut value = TestFlags::B | TestFlags::from_bits_retain(1 << 3 | 1 << 7);
+    assert_eq!(vec![TestFlags::B], value.drain().collect::<Vec<_>>());
+    assert_eq!(TestFlags::from_bits_retain(1 << 3 | 1 << 7), value);
+
+    // Bits only defined by composite flags are left as-is
+    let mut value = TestOverlapping::AB;
+    assert_eq!(0, value.drain().count());
+    assert_eq!(TestOverlapping::AB, value);
+
+    // Unnamed flags are left as-is
+    let mut value = TestExternal::from_bits_retain(1 << 2 | 1 << 5);
+    assert_eq!(vec![TestExternal::C], value.drain().collect::<Vec<_>>());
+    assert_eq!(TestExternal::from_bits_retain(1 << 5), value);
+
+    let mut value = TestEmpty::from_bits_retain(u8::MAX);
+    assert_eq!(0, value.drain().count());
+    assert_eq!(TestEmpty::from_bits_retain(u8::MAX), value);
+}
+
+#[test]
+fn inserted_while_draining() {
+    let mut value = TestFlags::A | TestFlags::B;
+
+    let mut drained = Vec::new();
+    for flag in value.drain() {
+        drained.push(flag);
+
+        // Flags inserted while draining aren't yielded
+        value.insert(TestFlags::C);
+    }
+
+    assert_eq!(vec![TestFlags::A, TestFlags::B], drained);
+    assert_eq!(TestFlags::C, value);
+}
+
+#[test]
+fn early_break() {
+    let mut value = TestFlags::ABC;
+
+    let mut drained = Vec::new();
+    for flag in value.drain() {
+        drained.push(flag);
+
+        if flag == TestFlags::B {
+            break;
+        }
+    }
+
+    assert_eq!(vec![TestFlags::A, TestFlags::B], drained);
+
+    // Flags are removed up front, so the ones that weren't yielded are removed too
+    assert_eq!(TestFlags::empty(), value);
+}
+
+#[test]
+fn panic_safety() {
+    let mut value = TestFlags::ABC | TestFlags::from_bits_retain(1 << 3);
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        for flag in value.drain() {
+            if flag == TestFlags::B {
+                panic!("failed to handle {:?}", flag);
+            }
+        }
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(TestFlags::from_bits_retain(1 << 3), value);
+}
+
+#[test]
+fn pop_first() {
+    for bits in 0..=u8::MAX {
+        let mut drained = TestFlags::from_bits_retain(bits);
+        let mut popped = TestFlags::from_bits_retain(bits);
+
+        for flag in drained.drain() {
+            assert_eq!(Some(flag), popped.pop_first(), "{:?}", flag);
+        }
+
+        assert_eq!(None, popped.pop_first());
+        assert_eq!(popped, drained);
+    }
+}
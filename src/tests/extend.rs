@@ -17,6 +17,34 @@ fn cases() {
     assert_eq!(TestFlags::ABC | TestFlags::from_bits_retain(1 << 5), flags);
 }
 
+#[test]
+fn collect() {
+    assert_eq!(
+        TestFlags::empty(),
+        core::iter::empty::<TestFlags>().collect::<TestFlags>()
+    );
+
+    // Duplicates don't change the result
+    assert_eq!(
+        TestFlags::A | TestFlags::C,
+        vec![TestFlags::A, TestFlags::C, TestFlags::A]
+            .into_iter()
+            .collect::<TestFlags>()
+    );
+
+    assert_eq!(
+        TestFlags::ABC | TestFlags::from_bits_retain(1 << 5),
+        [
+            TestFlags::A,
+            TestFlags::B | TestFlags::C,
+            TestFlags::from_bits_retain(1 << 5),
+        ]
+        .iter()
+        .copied()
+        .collect::<TestFlags>()
+    );
+}
+
 mod external {
     use super::*;
 
@@ -0,0 +1,79 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    for value in [
+        TestFlags::empty(),
+        TestFlags::A,
+        TestFlags::ABC,
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+    ] {
+        for other in [
+            TestFlags::empty(),
+            TestFlags::A,
+            TestFlags::B | TestFlags::C,
+            TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        ] {
+            case(value, other, TestFlags::fetch_insert, TestFlags::insert);
+            case(value, other, TestFlags::fetch_remove, TestFlags::remove);
+            case(value, other, TestFlags::fetch_toggle, TestFlags::toggle);
+
+            case(value, other, Flags::fetch_insert, Flags::insert);
+            case(value, other, Flags::fetch_remove, Flags::remove);
+            case(value, other, Flags::fetch_toggle, Flags::toggle);
+        }
+    }
+
+    case(
+        TestOverlapping::AB,
+        TestOverlapping::BC,
+        TestOverlapping::fetch_remove,
+        TestOverlapping::remove,
+    );
+}
+
+#[test]
+fn previous() {
+    let mut flags = TestFlags::A;
+
+    assert_eq!(TestFlags::A, flags.fetch_insert(TestFlags::B));
+    assert_eq!(
+        TestFlags::A | TestFlags::B,
+        flags.fetch_toggle(TestFlags::ABC)
+    );
+    assert_eq!(TestFlags::C, flags.fetch_remove(TestFlags::C));
+    assert_eq!(TestFlags::empty(), flags);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    other: T,
+    mut fetch: impl FnMut(&mut T, T) -> T,
+    mut mutate: impl FnMut(&mut T, T),
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    let mut fetched = value;
+    let previous = fetch(&mut fetched, other);
+
+    let mut mutated = value;
+    mutate(&mut mutated, other);
+
+    assert_eq!(
+        value.bits(),
+        previous.bits(),
+        "fetch({:?}, {:?})",
+        value,
+        other
+    );
+    assert_eq!(
+        mutated.bits(),
+        fetched.bits(),
+        "fetch({:?}, {:?})",
+        value,
+        other
+    );
+}
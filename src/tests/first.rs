@@ -0,0 +1,82 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = assert!(TestFlags::ABC.first().unwrap().bits() == 1);
+
+#[test]
+fn cases() {
+    case(None, TestFlags::empty(), TestFlags::first);
+    case(Some(1), TestFlags::ABC, TestFlags::first);
+    case(Some(1 << 1), TestFlags::B | TestFlags::C, TestFlags::first);
+
+    // Unknown bits are ignored
+    case(None, TestFlags::from_bits_retain(1 << 3), TestFlags::first);
+    case(
+        Some(1 << 2),
+        TestFlags::C | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        TestFlags::first,
+    );
+
+    // Composite flags are only returned if they're the only flag with the lowest bit
+    case(Some(1), TestFlagsInvert::ABC, TestFlagsInvert::first);
+    case(
+        Some(1 | 1 << 1),
+        TestOverlapping::AB | TestOverlapping::BC,
+        TestOverlapping::first,
+    );
+    case(
+        Some(1 << 1 | 1 << 2),
+        TestOverlapping::BC,
+        TestOverlapping::first,
+    );
+
+    // Flags with the same bits return the first defined
+    case(
+        Some(1),
+        TestOverlappingFull::all(),
+        TestOverlappingFull::first,
+    );
+
+    case(None, TestZero::ZERO, TestZero::first);
+    case(None, TestEmpty::from_bits_retain(u8::MAX), TestEmpty::first);
+
+    // Unnamed flags are ignored
+    case(
+        None,
+        TestExternal::from_bits_retain(1 << 3),
+        TestExternal::first,
+    );
+    case(None, TestExternalFull::all(), TestExternalFull::first);
+}
+
+#[test]
+fn signed() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1 << 1;
+            const MIN = i8::MIN;
+        }
+    }
+
+    case(Some(1 << 1), Signed::all(), Signed::first);
+    case(Some(i8::MIN), Signed::MIN, Signed::first);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(
+    expected: Option<T::Bits>,
+    value: T,
+    inherent: impl FnOnce(&T) -> Option<T>,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        inherent(&value).map(|f| f.bits()),
+        "{:?}.first()",
+        value
+    );
+}
@@ -19,7 +19,37 @@ fn cases() {
         flags,
     );
 
-    assert_eq!(0, TestEmpty::FLAGS.iter().count());
+    assert_eq!(0, TestEmpty::FLAGS.len());
+}
+
+#[test]
+fn composite() {
+    let composite = TestFlags::FLAGS
+        .iter()
+        .filter(|flag| flag.is_composite())
+        .map(|flag| flag.name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["ABC"], composite);
+
+    let composite = TestFlagsInvert::FLAGS
+        .iter()
+        .filter(|flag| flag.is_composite())
+        .map(|flag| flag.name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["ABC"], composite);
+
+    // Overlapping flags don't contain each other, and flags with the same bits aren't composite
+    assert!(!TestOverlapping::FLAGS
+        .iter()
+        .any(|flag| flag.is_composite()));
+    assert!(!TestOverlappingFull::FLAGS
+        .iter()
+        .any(|flag| flag.is_composite()));
+
+    // Zero-bit flags aren't part of other flags
+    assert!(!TestZeroOne::FLAGS.iter().any(|flag| flag.is_composite()));
 }
 
 mod external {
@@ -42,5 +72,9 @@ mod external {
             ],
             flags,
         );
+
+        // The unnamed flag isn't composite, even though it contains every other flag
+        assert!(!TestExternal::FLAGS[4].is_composite());
+        assert!(TestExternal::FLAGS[3].is_composite());
     }
 }
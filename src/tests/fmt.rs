@@ -78,6 +78,159 @@ fn cases() {
     );
 }
 
+#[test]
+fn unknown_bits_roundtrip() {
+    // The text inside `Debug` output parses back to the same flags value, including unknown bits
+    for bits in 0u8..=255 {
+        let value = TestFlags::from_bits_retain(bits);
+
+        let debug = format!("{:?}", value);
+        let text = debug
+            .strip_prefix("TestFlags(")
+            .and_then(|text| text.strip_suffix(')'))
+            .unwrap();
+
+        assert_eq!(
+            value,
+            crate::parser::from_str::<TestFlags>(text).unwrap(),
+            "{}",
+            debug
+        );
+    }
+}
+
+#[test]
+fn alternate() {
+    assert_eq!(
+        "TestFlags(\n    {\n        bits: 0x9,\n        A: true,\n        B: false,\n        C: false,\n        ABC: false,\n    },\n)",
+        format!("{:#?}", TestFlags::A | TestFlags::from_bits_retain(1 << 3))
+    );
+    assert_eq!(
+        "TestFlags(A | 0x8)",
+        format!("{:?}", TestFlags::A | TestFlags::from_bits_retain(1 << 3))
+    );
+
+    // Unnamed flags aren't listed
+    assert_eq!(
+        "TestExternal(\n    {\n        bits: 0xff,\n        A: true,\n        B: true,\n        C: true,\n        ABC: true,\n    },\n)",
+        format!("{:#?}", TestExternal::all())
+    );
+    assert_eq!(
+        "TestEmpty(\n    {\n        bits: 0x0,\n    },\n)",
+        format!("{:#?}", TestEmpty::empty())
+    );
+
+    // The breakdown is indented along with the rest of a containing value
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Register {
+        id: u8,
+        flags: TestFlags,
+    }
+
+    assert_eq!(
+        "Register {\n    id: 1,\n    flags: TestFlags(\n        {\n            bits: 0x7,\n            A: true,\n            B: true,\n            C: true,\n            ABC: true,\n        },\n    ),\n}",
+        format!(
+            "{:#?}",
+            Register {
+                id: 1,
+                flags: TestFlags::ABC,
+            }
+        )
+    );
+    assert_eq!(
+        "Register { id: 1, flags: TestFlags(A | B | C) }",
+        format!(
+            "{:?}",
+            Register {
+                id: 1,
+                flags: TestFlags::ABC,
+            }
+        )
+    );
+}
+
+#[test]
+fn options() {
+    bitflags! {
+        #[derive(Debug)]
+        struct U8: u8 {
+            const A = 1;
+            const B = 1 << 7;
+        }
+
+        #[derive(Debug)]
+        struct U16: u16 {
+            const A = 1;
+            const B = 1 << 15;
+        }
+
+        #[derive(Debug)]
+        struct U32: u32 {
+            const A = 1;
+            const B = 1 << 31;
+        }
+
+        #[derive(Debug)]
+        struct U64: u64 {
+            const A = 1;
+            const B = 1 << 63;
+        }
+
+        #[derive(Debug)]
+        struct I8: i8 {
+            const A = 1;
+            const B = i8::MIN;
+        }
+    }
+
+    // The alternate flag, width, and zero-padding are passed through to the bits
+    assert_eq!(
+        "0x0a",
+        format!(
+            "{:#04x}",
+            TestFlags::B | TestFlags::from_bits_retain(1 << 3)
+        )
+    );
+    assert_eq!(
+        "0x0000000a",
+        format!("{:#010x}", TestFlags::from_bits_retain(0xa))
+    );
+    assert_eq!("  5", format!("{:3X}", TestFlags::A | TestFlags::C));
+
+    assert_eq!("81", format!("{:x}", U8::all()));
+    assert_eq!("0x81", format!("{:#X}", U8::all()));
+    assert_eq!("0b10000001", format!("{:#b}", U8::all()));
+    assert_eq!("00000201", format!("{:08o}", U8::all()));
+
+    assert_eq!("8001", format!("{:x}", U16::all()));
+    assert_eq!("0x8001", format!("{:#X}", U16::all()));
+    assert_eq!("0b1000000000000001", format!("{:#b}", U16::all()));
+    assert_eq!("00100001", format!("{:08o}", U16::all()));
+
+    assert_eq!("80000001", format!("{:x}", U32::all()));
+    assert_eq!("0x80000001", format!("{:#X}", U32::all()));
+    assert_eq!(
+        "0b10000000000000000000000000000001",
+        format!("{:#b}", U32::all())
+    );
+    assert_eq!("20000000001", format!("{:08o}", U32::all()));
+
+    assert_eq!("8000000000000001", format!("{:x}", U64::all()));
+    assert_eq!("0x8000000000000001", format!("{:#X}", U64::all()));
+    assert_eq!(
+        "0b1000000000000000000000000000000000000000000000000000000000000001",
+        format!("{:#b}", U64::all())
+    );
+    assert_eq!("1000000000000000000001", format!("{:08o}", U64::all()));
+
+    // Signed bits are formatted as their bit pattern, without a sign
+    assert_eq!("81", format!("{:x}", I8::all()));
+    assert_eq!("0x81", format!("{:#X}", I8::all()));
+    assert_eq!("0b10000001", format!("{:#b}", I8::all()));
+    assert_eq!("00000201", format!("{:08o}", I8::all()));
+}
+
 #[track_caller]
 fn case<
     T: std::fmt::Debug + std::fmt::UpperHex + std::fmt::LowerHex + std::fmt::Octal + std::fmt::Binary,
@@ -0,0 +1,74 @@
+use super::*;
+
+const _: () = assert!(
+    matches!(TestFlags::from_bit_index(1), Some(flags) if flags.bits() == TestFlags::B.bits())
+        && TestFlags::from_bit_index(8).is_none()
+);
+
+#[test]
+fn cases() {
+    assert_eq!(Some(TestFlags::A), TestFlags::from_bit_index(0));
+    assert_eq!(Some(TestFlags::C), TestFlags::from_bit_index(2));
+
+    // Bits that don't correspond to a defined flag are still set
+    assert_eq!(
+        Some(TestFlags::from_bits_retain(1 << 3)),
+        TestFlags::from_bit_index(3)
+    );
+
+    assert_eq!(None, TestFlags::from_bit_index(u32::MAX));
+}
+
+#[test]
+fn widths() {
+    macro_rules! widths {
+        ($($T:ident: $Bits:ty,)*) => {
+            $(
+                bitflags! {
+                    #[derive(Debug, PartialEq, Eq)]
+                    #[bitflags(inherent_methods)]
+                    struct $T: $Bits {
+                        const A = 1;
+                    }
+                }
+
+                let top = <$Bits>::BITS - 1;
+
+                assert_eq!(Some($T::A), $T::from_bit_index(0), "{}", stringify!($Bits));
+                assert_eq!(
+                    Some($T::from_bits_retain(1 << top)),
+                    $T::from_bit_index(top),
+                    "{}",
+                    stringify!($Bits),
+                );
+                assert_eq!(None, $T::from_bit_index(top + 1), "{}", stringify!($Bits));
+            )*
+        };
+    }
+
+    widths! {
+        U8: u8,
+        U16: u16,
+        U32: u32,
+        U64: u64,
+        U128: u128,
+        Usize: usize,
+        I8: i8,
+        I16: i16,
+        I32: i32,
+        I64: i64,
+        I128: i128,
+        Isize: isize,
+    }
+
+    // The top bit of a signed bits type is its sign bit
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const MIN = i8::MIN;
+        }
+    }
+
+    assert_eq!(Some(Signed::MIN), Signed::from_bit_index(7));
+}
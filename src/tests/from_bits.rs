@@ -22,6 +22,56 @@ fn cases() {
     case(Some(1 << 5), 1 << 5, TestExternal::from_bits);
 }
 
+mod widths {
+    use super::*;
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Usize: usize {
+            const A = 1;
+            const TOP = 1 << 31;
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Isize: isize {
+            const A = 1;
+
+            const _ = !0;
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct U64: u64 {
+            const A = 1;
+            const TOP = 1 << 31;
+        }
+    }
+
+    #[test]
+    fn usize() {
+        case(Some(1 | 1 << 31), 1 | 1 << 31, Usize::from_bits);
+        case(None, 1 << 1, Usize::from_bits);
+
+        // Bits from a fixed-width type need to fit in `usize` before they can be checked
+        let bits = (U64::A | U64::TOP).bits();
+        assert_eq!(
+            Some(Usize::A | Usize::TOP),
+            usize::try_from(bits).ok().and_then(Usize::from_bits)
+        );
+        assert_eq!(bits, (Usize::A | Usize::TOP).bits() as u64);
+    }
+
+    #[test]
+    fn isize() {
+        case(Some(-1), -1, Isize::from_bits);
+        case(Some(isize::MIN), isize::MIN, Isize::from_bits);
+
+        assert_eq!(
+            Some(Isize::all()),
+            isize::try_from(-1i64).ok().and_then(Isize::from_bits)
+        );
+    }
+}
+
 #[track_caller]
 fn case<T: Flags>(
     expected: Option<T::Bits>,
@@ -0,0 +1,93 @@
+use super::*;
+
+use crate::{Flags, UnknownBits};
+
+#[test]
+fn cases() {
+    case(Ok(0), 0, TestFlags::from_bits_strict);
+    case(Ok(1), 1, TestFlags::from_bits_strict);
+    case(
+        Ok(1 | 1 << 1 | 1 << 2),
+        1 | 1 << 1 | 1 << 2,
+        TestFlags::from_bits_strict,
+    );
+
+    case(Err(1 << 3), 1 << 3, TestFlags::from_bits_strict);
+    case(
+        Err(1 << 3 | 1 << 7),
+        1 | 1 << 3 | 1 << 7,
+        TestFlags::from_bits_strict,
+    );
+
+    case(
+        Ok(1 | 1 << 1),
+        1 | 1 << 1,
+        TestOverlapping::from_bits_strict,
+    );
+
+    case(
+        Err(1 << 3),
+        1 << 1 | 1 << 3,
+        TestOverlapping::from_bits_strict,
+    );
+
+    case(Ok(1 << 5), 1 << 5, TestExternal::from_bits_strict);
+}
+
+#[test]
+fn fmt() {
+    assert_eq!(
+        "unknown bits: 0x80 (bit 7)",
+        TestFlags::from_bits_strict(1 << 7).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "unknown bits: 0x88 (bits 3, 7)",
+        TestFlags::from_bits_strict(1 | 1 << 3 | 1 << 7)
+            .unwrap_err()
+            .to_string()
+    );
+
+    assert_eq!(
+        "unknown bits: 0x80 (bit 7)",
+        UnknownBits::new(i8::MIN).to_string()
+    );
+    assert_eq!(
+        "unknown bits: 0x80000000000000000000000000000001 (bits 0, 127)",
+        UnknownBits::new(1u128 | 1 << 127).to_string()
+    );
+    assert_eq!("unknown bits: 0x0", UnknownBits::new(0u8).to_string());
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(
+    expected: Result<T::Bits, T::Bits>,
+    input: T::Bits,
+    inherent: impl FnOnce(T::Bits) -> Result<T, UnknownBits<T::Bits>>,
+) where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        inherent(input)
+            .map(|f| f.bits())
+            .map_err(|unknown| *unknown.bits()),
+        "T::from_bits_strict({:?})",
+        input
+    );
+    assert_eq!(
+        expected,
+        T::from_bits_strict(input)
+            .map(|f| f.bits())
+            .map_err(|unknown| *unknown.bits()),
+        "Flags::from_bits_strict({:?})",
+        input
+    );
+
+    // A successful strict conversion is the same as `from_bits`
+    assert_eq!(
+        expected.ok(),
+        T::from_bits(input).map(|f| f.bits()),
+        "Flags::from_bits({:?})",
+        input
+    );
+}
@@ -12,6 +12,10 @@ fn cases() {
     case(None, "a", TestFlags::from_name);
     case(None, "0x1", TestFlags::from_name);
     case(None, "A | B", TestFlags::from_name);
+    case(None, "A,B", TestFlags::from_name);
+    case(None, " A", TestFlags::from_name);
+    case(None, "A ", TestFlags::from_name);
+    case(None, "1", TestFlags::from_name);
 
     case(Some(0), "ZERO", TestZero::from_name);
 
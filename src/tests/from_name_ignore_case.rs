@@ -0,0 +1,66 @@
+use super::*;
+
+use crate::Flags;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Mixed: u8 {
+        const A = 1;
+        const LOWER = 1 << 1;
+        const lower = 1 << 2;
+        const Upper = 1 << 3;
+        const UPPER = 1 << 4;
+    }
+}
+
+#[test]
+fn cases() {
+    case(Some(1), "A", TestFlags::from_name_ignore_case);
+    case(Some(1), "a", TestFlags::from_name_ignore_case);
+    case(Some(1 << 1), "b", TestFlags::from_name_ignore_case);
+    case(
+        Some(1 | 1 << 1 | 1 << 2),
+        "aBc",
+        TestFlags::from_name_ignore_case,
+    );
+
+    case(None, "", TestFlags::from_name_ignore_case);
+    case(None, " a", TestFlags::from_name_ignore_case);
+    case(None, "a ", TestFlags::from_name_ignore_case);
+    case(None, "0x1", TestFlags::from_name_ignore_case);
+    case(None, "1", TestFlags::from_name_ignore_case);
+    case(None, "a | b", TestFlags::from_name_ignore_case);
+    case(None, "a,b", TestFlags::from_name_ignore_case);
+
+    case(Some(0), "zero", TestZero::from_name_ignore_case);
+
+    case(Some(2), "二", TestUnicode::from_name_ignore_case);
+
+    case(None, "_", TestExternal::from_name_ignore_case);
+
+    // Exact matches are preferred over ambiguous ones
+    case(Some(1 << 1), "LOWER", Mixed::from_name_ignore_case);
+    case(Some(1 << 2), "lower", Mixed::from_name_ignore_case);
+    case(None, "Lower", Mixed::from_name_ignore_case);
+    case(Some(1 << 4), "UPPER", Mixed::from_name_ignore_case);
+    case(None, "upper", Mixed::from_name_ignore_case);
+}
+
+#[track_caller]
+fn case<T: Flags>(expected: Option<T::Bits>, input: &str, inherent: impl FnOnce(&str) -> Option<T>)
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        inherent(input).map(|f| f.bits()),
+        "T::from_name_ignore_case({:?})",
+        input
+    );
+    assert_eq!(
+        expected,
+        T::from_name_ignore_case(input).map(|f| f.bits()),
+        "Flags::from_name_ignore_case({:?})",
+        input
+    );
+}
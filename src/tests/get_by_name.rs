@@ -0,0 +1,36 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(Some(true), TestFlags::A, "A");
+    case(Some(false), TestFlags::A, "B");
+
+    // Composite flags need all their bits set
+    case(Some(false), TestFlags::A | TestFlags::B, "ABC");
+    case(Some(true), TestFlags::ABC, "ABC");
+
+    case(None, TestFlags::ABC, "");
+    case(None, TestFlags::ABC, "a");
+    case(None, TestFlags::ABC, "A | B");
+
+    // Unknown bits can't be looked up by name
+    case(None, TestExternal::all(), "_");
+    case(
+        Some(true),
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        "A",
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(expected: Option<bool>, value: T, name: &str) {
+    assert_eq!(
+        expected,
+        value.get_by_name(name),
+        "Flags::get_by_name({:?}, {:?})",
+        value,
+        name
+    );
+}
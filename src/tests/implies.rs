@@ -0,0 +1,88 @@
+use super::*;
+
+use crate::Flags;
+
+const_assert_flags!(TestFlags::ABC.implies(TestFlags::A));
+const_assert_flags!(!TestFlags::A.implies(TestFlags::ABC));
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, false),
+            (TestFlags::from_bits_retain(1 << 3), false),
+        ],
+        TestFlags::implies,
+    );
+
+    case(
+        TestFlags::ABC,
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), false),
+        ],
+        TestFlags::implies,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 | (1 << 3)),
+        &[
+            (TestFlags::A, true),
+            (TestFlags::B, false),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::implies,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[
+            (TestOverlapping::AB, true),
+            (TestOverlapping::BC, false),
+            (TestOverlapping::from_bits_retain(1 << 1), true),
+        ],
+        TestOverlapping::implies,
+    );
+}
+
+#[test]
+fn properties() {
+    for a in 0..=u8::MAX {
+        for b in 0..=u8::MAX {
+            let (a, b) = (
+                TestFlags::from_bits_retain(a),
+                TestFlags::from_bits_retain(b),
+            );
+
+            assert_eq!(a.contains(b), a.implies(b), "{:?}.implies({:?})", a, b);
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool)],
+    mut inherent: impl FnMut(&T, T) -> bool,
+) {
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(&value, *input),
+            "{:?}.implies({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::implies(&value, *input),
+            "Flags::implies({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::A, 1, true),
+            (TestFlags::empty(), 0, false),
+            (TestFlags::from_bits_retain(1 << 3), 1 << 3, true),
+        ],
+        TestFlags::insert_changed,
+    );
+
+    // Inserting bits that are all already set doesn't change anything
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::A, 1 | 1 << 3, false),
+            (TestFlags::from_bits_retain(1 << 3), 1 | 1 << 3, false),
+            (TestFlags::empty(), 1 | 1 << 3, false),
+        ],
+        TestFlags::insert_changed,
+    );
+
+    // Any bit being unset is a change, even if others were already set
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::A | TestFlags::B, 1 | 1 << 1, true),
+            (TestFlags::ABC, 1 | 1 << 1 | 1 << 2, true),
+            (TestFlags::from_bits_retain(1 | 1 << 7), 1 | 1 << 7, true),
+        ],
+        TestFlags::insert_changed,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[(TestOverlapping::BC, 1 | 1 << 1 | 1 << 2, true)],
+        TestOverlapping::insert_changed,
+    );
+}
+
+#[test]
+fn matches_insert() {
+    for bits in 0..=u8::MAX {
+        for other in [0, 1, 1 << 1 | 1 << 2, 1 << 3, 1 << 7, u8::MAX] {
+            let value = TestFlags::from_bits_retain(bits);
+            let other = TestFlags::from_bits_retain(other);
+
+            let mut inserted = value;
+            inserted.insert(other);
+
+            let mut changed = value;
+            assert_eq!(
+                inserted != value,
+                changed.insert_changed(other),
+                "{:?}.insert_changed({:?})",
+                value,
+                other
+            );
+            assert_eq!(inserted, changed);
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits, bool)],
+    mut inherent: impl FnMut(&mut T, T) -> bool,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected, expected_changed) in inputs {
+        let mut inserted = value;
+        assert_eq!(
+            *expected_changed,
+            inherent(&mut inserted, *input),
+            "{:?}.insert_changed({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            inserted.bits(),
+            "{:?}.insert_changed({:?})",
+            value,
+            input
+        );
+
+        let mut inserted = value;
+        assert_eq!(
+            *expected_changed,
+            Flags::insert_changed(&mut inserted, *input),
+            "Flags::insert_changed({:?}, {:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            inserted.bits(),
+            "Flags::insert_changed({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
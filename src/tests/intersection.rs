@@ -2,6 +2,8 @@ use super::*;
 
 use crate::Flags;
 
+const _: () = assert!(TestFlags::ABC.intersection(TestFlags::B).bits() == 1 << 1);
+
 #[test]
 fn cases() {
     case(
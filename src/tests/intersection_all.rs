@@ -0,0 +1,38 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    // The intersection of no flags values is all known bits
+    case::<TestFlags>(1 | 1 << 1 | 1 << 2, &[]);
+    case::<TestExternal>(u8::MAX, &[]);
+
+    case(1, &[TestFlags::A]);
+    case(1 << 3, &[TestFlags::from_bits_retain(1 << 3)]);
+    case(0, &[TestFlags::A, TestFlags::B]);
+    case(
+        1 << 1,
+        &[TestFlags::ABC, TestFlags::B, TestFlags::A | TestFlags::B],
+    );
+    case(
+        1 | 1 << 3,
+        &[
+            TestFlags::from_bits_retain(1 | 1 << 3),
+            TestFlags::from_bits_retain(1 | 1 << 3 | 1 << 4),
+        ],
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(expected: T::Bits, inputs: &[T])
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    assert_eq!(
+        expected,
+        T::intersection_all(inputs.iter().copied()).bits(),
+        "Flags::intersection_all({:?})",
+        inputs
+    );
+}
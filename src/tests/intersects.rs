@@ -2,6 +2,9 @@ use super::*;
 
 use crate::Flags;
 
+const _: () =
+    assert!(TestFlags::ABC.intersects(TestFlags::A) && !TestFlags::A.intersects(TestFlags::B));
+
 #[test]
 fn cases() {
     case(
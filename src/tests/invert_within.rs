@@ -0,0 +1,110 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::ABC, 1 << 1 | 1 << 2),
+            (TestFlags::A | TestFlags::B, 1 << 1),
+            (TestFlags::empty(), 1),
+        ],
+        TestFlags::invert_within,
+    );
+
+    // Unknown bits in the mask are flipped too
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::from_bits_retain(1 << 3 | 1 << 4), 1 | 1 << 4),
+            (TestFlags::ABC, 1 << 1 | 1 << 2 | 1 << 3),
+        ],
+        TestFlags::invert_within,
+    );
+
+    // Bits outside a mask that doesn't intersect the value are left as-is
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::B, 1 | 1 << 1),
+            (TestFlags::from_bits_retain(1 << 3), 1 | 1 << 3),
+        ],
+        TestFlags::invert_within,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[(TestOverlapping::BC, 1 | 1 << 2)],
+        TestOverlapping::invert_within,
+    );
+}
+
+#[test]
+fn complement_of() {
+    for bits in 0..=u8::MAX {
+        let value = TestFlags::from_bits_retain(bits);
+
+        for mask in [
+            TestFlags::A | TestFlags::B,
+            TestFlags::all(),
+            TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        ] {
+            let mut inverted = value;
+            inverted.invert_within(mask);
+
+            // The bits within the mask are inverted and the rest are unchanged
+            assert_eq!(
+                value.complement_of(mask),
+                inverted.intersection(mask),
+                "{:?}.invert_within({:?})",
+                value,
+                mask
+            );
+            assert_eq!(
+                value.difference(mask),
+                inverted.difference(mask),
+                "{:?}.invert_within({:?})",
+                value,
+                mask
+            );
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits)],
+    mut inherent: impl FnMut(&mut T, T),
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                inherent(&mut value, *input);
+                value
+            }
+            .bits(),
+            "{:?}.invert_within({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                Flags::invert_within(&mut value, *input);
+                value
+            }
+            .bits(),
+            "Flags::invert_within({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+use std::io;
+
+use crate::io::Endian;
+
+#[test]
+fn cases() {
+    let mut bytes = Vec::new();
+    TestFlags::ABC.write_to(&mut bytes, Endian::Little).unwrap();
+    assert_eq!(vec![0b111], bytes);
+
+    // Unknown bits are written and read as-is
+    let mut bytes = Vec::new();
+    let value = TestFlags::A | TestFlags::from_bits_retain(1 << 7);
+    value.write_to(&mut bytes, Endian::Big).unwrap();
+    assert_eq!(vec![0x81], bytes);
+    assert_eq!(
+        value,
+        TestFlags::read_from(&bytes[..], Endian::Big).unwrap()
+    );
+
+    // Only the bytes for a single flags value are read
+    let mut reader = &[0b1, 0b10][..];
+    assert_eq!(
+        TestFlags::A,
+        TestFlags::read_from(&mut reader, Endian::Little).unwrap()
+    );
+    assert_eq!(
+        TestFlags::B,
+        TestFlags::read_from(&mut reader, Endian::Little).unwrap()
+    );
+    assert_eq!(
+        io::ErrorKind::UnexpectedEof,
+        TestFlags::read_from(&mut reader, Endian::Little)
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn widths() {
+    macro_rules! widths {
+        ($($T:ident: $Bits:ty,)*) => {
+            $(
+                bitflags! {
+                    #[derive(Debug, PartialEq, Eq)]
+                    #[bitflags(inherent_methods)]
+                    struct $T: $Bits {
+                        const A = 1;
+                    }
+                }
+
+                let size = std::mem::size_of::<$Bits>();
+                let value = $T::A | $T::from_bits_retain(1 << (<$Bits>::BITS - 1));
+
+                let mut le = Vec::new();
+                value.write_to(&mut le, Endian::Little).unwrap();
+
+                let mut be = Vec::new();
+                value.write_to(&mut be, Endian::Big).unwrap();
+
+                assert_eq!(size, le.len(), "{}", stringify!($Bits));
+                assert_eq!(value.bits().to_le_bytes().to_vec(), le, "{}", stringify!($Bits));
+                assert_eq!(value.bits().to_be_bytes().to_vec(), be, "{}", stringify!($Bits));
+
+                assert_eq!(value, $T::read_from(&le[..], Endian::Little).unwrap(), "{}", stringify!($Bits));
+                assert_eq!(value, $T::read_from(&be[..], Endian::Big).unwrap(), "{}", stringify!($Bits));
+
+                // A short read fails without producing a value
+                let err = $T::read_from(&le[..size - 1], Endian::Little).unwrap_err();
+                assert_eq!(io::ErrorKind::UnexpectedEof, err.kind(), "{}", stringify!($Bits));
+            )*
+        };
+    }
+
+    widths! {
+        U8: u8,
+        U16: u16,
+        U32: u32,
+        U64: u64,
+        U128: u128,
+        Usize: usize,
+        I8: i8,
+        I16: i16,
+        I32: i32,
+        I64: i64,
+        I128: i128,
+        Isize: isize,
+    }
+}
@@ -0,0 +1,117 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () =
+    assert!(TestFlags::A.is_disjoint(TestFlags::B) && !TestFlags::A.is_disjoint(TestFlags::ABC));
+
+#[test]
+fn cases() {
+    // The empty flags value is disjoint from everything, including itself
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::is_disjoint,
+    );
+
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, false),
+            (TestFlags::B, true),
+            (TestFlags::ABC, false),
+            (TestFlags::from_bits_retain(1 << 3), true),
+            (TestFlags::from_bits_retain(1 | (1 << 3)), false),
+        ],
+        TestFlags::is_disjoint,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), false),
+        ],
+        TestFlags::is_disjoint,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[
+            (TestOverlapping::AB, false),
+            (TestOverlapping::BC, false),
+            (TestOverlapping::from_bits_retain(1 << 2), true),
+        ],
+        TestOverlapping::is_disjoint,
+    );
+}
+
+#[test]
+fn properties() {
+    for a in 0..=u8::MAX {
+        for b in 0..=u8::MAX {
+            let (a, b) = (
+                TestFlags::from_bits_retain(a),
+                TestFlags::from_bits_retain(b),
+            );
+
+            assert_eq!(
+                !a.intersects(b),
+                a.is_disjoint(b),
+                "{:?}.is_disjoint({:?})",
+                a,
+                b
+            );
+            assert_eq!(
+                b.is_disjoint(a),
+                a.is_disjoint(b),
+                "{:?}.is_disjoint({:?})",
+                a,
+                b
+            );
+            assert_eq!(
+                a.intersection(b).is_empty(),
+                a.is_disjoint(b),
+                "{:?}.is_disjoint({:?})",
+                a,
+                b
+            );
+            assert!(
+                a.difference(b).is_disjoint(b),
+                "{:?}.is_disjoint({:?})",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool)],
+    mut inherent: impl FnMut(&T, T) -> bool,
+) {
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(&value, *input),
+            "{:?}.is_disjoint({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::is_disjoint(&value, *input),
+            "Flags::is_disjoint({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () =
+    assert!(TestFlags::A.is_subset(TestFlags::ABC) && !TestFlags::ABC.is_subset(TestFlags::A));
+
+#[test]
+fn cases() {
+    // The empty flags value is a subset of everything, including itself
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::is_subset,
+    );
+
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::empty(), false),
+            (TestFlags::A, true),
+            (TestFlags::B, false),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), false),
+            (TestFlags::from_bits_retain(1 | (1 << 3)), true),
+        ],
+        TestFlags::is_subset,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::empty(), false),
+            (TestFlags::ABC, false),
+            (TestFlags::all(), false),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::is_subset,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[
+            (TestOverlapping::AB, true),
+            (TestOverlapping::BC, false),
+            (TestOverlapping::all(), true),
+        ],
+        TestOverlapping::is_subset,
+    );
+}
+
+#[test]
+fn properties() {
+    for a in 0..=u8::MAX {
+        for b in 0..=u8::MAX {
+            let (a, b) = (
+                TestFlags::from_bits_retain(a),
+                TestFlags::from_bits_retain(b),
+            );
+
+            assert_eq!(b.contains(a), a.is_subset(b), "{:?}.is_subset({:?})", a, b);
+            assert_eq!(
+                b.is_superset(a),
+                a.is_subset(b),
+                "{:?}.is_subset({:?})",
+                a,
+                b
+            );
+            assert_eq!(
+                a == b,
+                a.is_subset(b) && b.is_subset(a),
+                "{:?}.is_subset({:?})",
+                a,
+                b
+            );
+            assert!(a.intersection(b).is_subset(a), "{:?}.is_subset({:?})", a, b);
+            assert!(a.is_subset(a.union(b)), "{:?}.is_subset({:?})", a, b);
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool)],
+    mut inherent: impl FnMut(&T, T) -> bool,
+) {
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(&value, *input),
+            "{:?}.is_subset({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::is_subset(&value, *input),
+            "Flags::is_subset({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () =
+    assert!(TestFlags::ABC.is_superset(TestFlags::A) && !TestFlags::A.is_superset(TestFlags::ABC));
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, false),
+            (TestFlags::from_bits_retain(1 << 3), false),
+        ],
+        TestFlags::is_superset,
+    );
+
+    // Every flags value is a superset of the empty flags value
+    case(
+        TestFlags::ABC,
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::B, true),
+            (TestFlags::C, true),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), false),
+        ],
+        TestFlags::is_superset,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 | (1 << 3)),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::B, false),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::is_superset,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[
+            (TestOverlapping::AB, true),
+            (TestOverlapping::BC, false),
+            (TestOverlapping::from_bits_retain(1 << 1), true),
+        ],
+        TestOverlapping::is_superset,
+    );
+}
+
+#[test]
+fn properties() {
+    for a in 0..=u8::MAX {
+        for b in 0..=u8::MAX {
+            let (a, b) = (
+                TestFlags::from_bits_retain(a),
+                TestFlags::from_bits_retain(b),
+            );
+
+            assert_eq!(
+                a.contains(b),
+                a.is_superset(b),
+                "{:?}.is_superset({:?})",
+                a,
+                b
+            );
+            assert_eq!(
+                b.is_subset(a),
+                a.is_superset(b),
+                "{:?}.is_superset({:?})",
+                a,
+                b
+            );
+            assert!(a.union(b).is_superset(b), "{:?}.is_superset({:?})", a, b);
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool)],
+    mut inherent: impl FnMut(&T, T) -> bool,
+) {
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(&value, *input),
+            "{:?}.is_superset({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::is_superset(&value, *input),
+            "Flags::is_superset({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-use crate::Flags;
+use crate::{Flag, Flags};
 
 #[test]
 #[cfg(not(miri))] // Very slow in miri
@@ -22,6 +22,136 @@ fn roundtrip() {
     }
 }
 
+#[test]
+#[cfg(not(miri))] // Very slow in miri
+fn double_ended() {
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Partial: u8 {
+            const A = 1;
+            const AB = 1 | 1 << 1;
+            const B = 1 << 1;
+            const BC = 1 << 1 | 1 << 2;
+        }
+    }
+
+    // Yielding from the back, or from both ends at once, produces the same flags as yielding from the front
+    #[track_caller]
+    fn case<T: Flags + std::fmt::Debug + Copy>(value: T)
+    where
+        T::Bits: std::fmt::Debug + PartialEq,
+    {
+        let expected = value.iter().map(|f| f.bits()).collect::<Vec<_>>();
+        let expected_names = value
+            .iter_names()
+            .map(|(n, f)| (n, f.bits()))
+            .collect::<Vec<_>>();
+
+        for back_first in [false, true] {
+            let mut iter = value.iter();
+            let mut iter_names = value.iter_names();
+
+            let (mut front, mut back) = (Vec::new(), Vec::new());
+            let (mut front_names, mut back_names) = (Vec::new(), Vec::new());
+
+            for i in 0.. {
+                assert_eq!(expected.len() - front.len() - back.len(), iter.len());
+                assert_eq!(
+                    expected_names.len() - front_names.len() - back_names.len(),
+                    iter_names.len()
+                );
+
+                let (flag, named) = if (i % 2 == 0) == back_first {
+                    (
+                        iter.next_back().map(|f| back.push(f.bits())),
+                        iter_names
+                            .next_back()
+                            .map(|(n, f)| back_names.push((n, f.bits()))),
+                    )
+                } else {
+                    (
+                        iter.next().map(|f| front.push(f.bits())),
+                        iter_names
+                            .next()
+                            .map(|(n, f)| front_names.push((n, f.bits()))),
+                    )
+                };
+
+                if flag.is_none() && named.is_none() {
+                    break;
+                }
+            }
+
+            front.extend(back.into_iter().rev());
+            front_names.extend(back_names.into_iter().rev());
+
+            assert_eq!(expected, front, "{:?}", value);
+            assert_eq!(expected_names, front_names, "{:?}", value);
+        }
+    }
+
+    // More flags than are found at once when yielding from the back
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Many(u128);
+
+    impl Flags for Many {
+        const FLAGS: &'static [Flag<Self>] = &{
+            const UNNAMED: Flag<Many> = Flag::new("", Many(0));
+
+            let mut flags = [UNNAMED; 300];
+            let mut i = 0;
+            while i < flags.len() {
+                let name = if i % 11 == 0 { "" } else { "F" };
+                let bits = 1 << (i * 37 % 128) | 1 << ((i * 59 + 11) % 128);
+
+                flags[i] = Flag::new(name, Many(bits));
+                i += 1;
+            }
+            flags
+        };
+
+        type Bits = u128;
+
+        fn bits(&self) -> u128 {
+            self.0
+        }
+
+        fn from_bits_retain(bits: u128) -> Self {
+            Many(bits)
+        }
+    }
+
+    let mut bits = 0x2545_f491_4f6c_dd1d_u128;
+    for _ in 0..64 {
+        case(Many(bits));
+        case(Many(bits & bits >> 7));
+        bits = bits.wrapping_mul(0x5851_f42d_4c95_7f2d).wrapping_add(1);
+    }
+    case(Many(u128::MAX));
+
+    for bits in 0u8..=255 {
+        case(TestFlags::from_bits_retain(bits));
+        case(TestFlagsInvert::from_bits_retain(bits));
+        case(TestOverlapping::from_bits_retain(bits));
+        case(TestOverlappingFull::from_bits_retain(bits));
+        case(TestExternal::from_bits_retain(bits));
+        case(Partial::from_bits_retain(bits));
+    }
+
+    // Flags yielded from the back don't stop earlier flags from being yielded from the front,
+    // even if they cover all of their bits
+    let mut iter = (Partial::AB | Partial::from_bits_retain(1 << 3)).iter_names();
+
+    assert_eq!(
+        Some(("AB", 1 | 1 << 1)),
+        iter.next_back().map(|(n, f)| (n, f.bits()))
+    );
+    assert_eq!(1 << 3, iter.remaining().bits());
+    assert_eq!(Some(("A", 1)), iter.next().map(|(n, f)| (n, f.bits())));
+    assert_eq!(None, iter.next());
+    assert_eq!(1 << 3, iter.remaining().bits());
+}
+
 mod collect {
     use super::*;
 
@@ -65,6 +195,7 @@ mod collect {
     }
 }
 
+#[allow(clippy::module_inception)]
 mod iter {
     use super::*;
 
@@ -95,6 +226,12 @@ mod iter {
 
         case(&[], TestZero::ZERO, TestZero::iter);
 
+        case(
+            &[1 | 1 << 1, 1 << 1 | 1 << 2, 1 << 3],
+            TestOverlapping::all() | TestOverlapping::from_bits_retain(1 << 3),
+            TestOverlapping::iter,
+        );
+
         case(
             &[1, 1 << 1, 1 << 2, 0b1111_1000],
             TestExternal::all(),
@@ -128,6 +265,21 @@ mod iter {
             "{:?}.into_iter()",
             value
         );
+        assert_eq!(
+            expected.iter().rev().copied().collect::<Vec<_>>(),
+            Flags::iter(&value)
+                .rev()
+                .map(|f| f.bits())
+                .collect::<Vec<_>>(),
+            "Flags::iter({:?}).rev()",
+            value
+        );
+        assert_eq!(
+            expected.len(),
+            Flags::iter(&value).len(),
+            "Flags::iter({:?}).len()",
+            value
+        );
     }
 }
 
@@ -179,6 +331,19 @@ mod iter_names {
             TestOverlappingFull::A | TestOverlappingFull::D,
             TestOverlappingFull::iter_names,
         );
+
+        case(
+            &[("A", 1), ("B", 1 << 1), ("C", 1 << 2)],
+            TestExternal::all(),
+            TestExternal::iter_names,
+        );
+        case(
+            &[("B", 1 << 1)],
+            TestExternal::B | TestExternal::from_bits_retain(1 << 5),
+            TestExternal::iter_names,
+        );
+
+        case(&[], TestExternalFull::all(), TestExternalFull::iter_names);
     }
 
     #[track_caller]
@@ -205,5 +370,155 @@ mod iter_names {
             "Flags::iter_names({:?})",
             value
         );
+        assert_eq!(
+            expected.iter().rev().copied().collect::<Vec<_>>(),
+            Flags::iter_names(&value)
+                .rev()
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "Flags::iter_names({:?}).rev()",
+            value
+        );
+        assert_eq!(
+            expected.len(),
+            Flags::iter_names(&value).len(),
+            "Flags::iter_names({:?}).len()",
+            value
+        );
+    }
+}
+
+mod iter_missing {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        case(
+            &[("A", 1), ("B", 1 << 1), ("C", 1 << 2)],
+            TestFlags::empty(),
+            TestFlags::iter_missing,
+        );
+        case(
+            &[("B", 1 << 1), ("C", 1 << 2)],
+            TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+            TestFlags::iter_missing,
+        );
+        case(&[], TestFlags::ABC, TestFlags::iter_missing);
+
+        // Composite flags are skipped, wherever they're defined
+        case(
+            &[("A", 1), ("C", 1 << 2)],
+            TestFlagsInvert::B,
+            TestFlagsInvert::iter_missing,
+        );
+
+        case(&[], TestZero::empty(), TestZero::iter_missing);
+
+        // Flags with the same bits are only yielded once
+        case(
+            &[("A", 1), ("D", 1 << 1)],
+            TestOverlappingFull::empty(),
+            TestOverlappingFull::iter_missing,
+        );
+
+        // Partially contained flags are missing
+        case(
+            &[("BC", 1 << 1 | 1 << 2)],
+            TestOverlapping::AB,
+            TestOverlapping::iter_missing,
+        );
+
+        case(
+            &[("A", 1), ("C", 1 << 2)],
+            TestExternal::B | TestExternal::from_bits_retain(1 << 5),
+            TestExternal::iter_missing,
+        );
+        case(
+            &[],
+            TestExternalFull::empty(),
+            TestExternalFull::iter_missing,
+        );
+    }
+
+    #[test]
+    fn complements_iter_names() {
+        let defined = TestFlags::FLAGS
+            .iter()
+            .filter(|flag| flag.is_named() && !flag.is_composite())
+            .fold(0, |bits, flag| bits | flag.value().bits());
+
+        for bits in 0..=u8::MAX {
+            let value = TestFlags::from_bits_retain(bits);
+
+            let names = value.iter_names().fold(0, |bits, (_, f)| bits | f.bits());
+            let missing = value.iter_missing().fold(0, |bits, (_, f)| bits | f.bits());
+
+            assert_eq!(0, names & missing, "{:?}", value);
+            assert_eq!(defined, names | missing, "{:?}", value);
+        }
+    }
+
+    #[track_caller]
+    fn case<T: Flags + std::fmt::Debug>(
+        expected: &[(&'static str, T::Bits)],
+        value: T,
+        inherent: impl FnOnce(&T) -> crate::iter::IterMissing<T>,
+    ) where
+        T::Bits: std::fmt::Debug + PartialEq,
+    {
+        assert_eq!(
+            expected,
+            inherent(&value)
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "{:?}.iter_missing()",
+            value
+        );
+        assert_eq!(
+            expected,
+            Flags::iter_missing(&value)
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "Flags::iter_missing({:?})",
+            value
+        );
+    }
+}
+
+mod widths {
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct Signed: i8 {
+            const A = 1;
+            const B = 1 << 6;
+            const TOP = 1 << 7;
+        }
+
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct Wide: u128 {
+            const A = 1;
+            const B = 1 << 64;
+            const TOP = 1 << 127;
+        }
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(
+            vec![Signed::A, Signed::TOP, Signed::from_bits_retain(1 << 3)],
+            (Signed::A | Signed::TOP | Signed::from_bits_retain(1 << 3))
+                .iter()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn wide() {
+        assert_eq!(
+            vec![Wide::B, Wide::TOP, Wide::from_bits_retain(1 << 100)],
+            (Wide::B | Wide::TOP | Wide::from_bits_retain(1 << 100))
+                .iter()
+                .collect::<Vec<_>>()
+        );
     }
 }
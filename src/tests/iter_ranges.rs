@@ -0,0 +1,136 @@
+use core::ops::RangeInclusive;
+
+use super::*;
+
+use crate::iter::IterRanges;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), &[], TestFlags::iter_ranges);
+    case(TestFlags::A, &[0..=0], TestFlags::iter_ranges);
+    case(TestFlags::ABC, &[0..=2], TestFlags::iter_ranges);
+    case(
+        TestFlags::A | TestFlags::C,
+        &[0..=0, 2..=2],
+        TestFlags::iter_ranges,
+    );
+
+    // Unknown bits are yielded like any others
+    case(
+        TestFlags::from_bits_retain(0b1111_1000),
+        &[3..=7],
+        TestFlags::iter_ranges,
+    );
+    case(
+        TestFlags::from_bits_retain(u8::MAX),
+        &[0..=7],
+        TestFlags::iter_ranges,
+    );
+    case(
+        TestFlags::from_bits_retain(0b0101_0101),
+        &[0..=0, 2..=2, 4..=4, 6..=6],
+        TestFlags::iter_ranges,
+    );
+    case(
+        TestFlags::from_bits_retain(0b1010_1010),
+        &[1..=1, 3..=3, 5..=5, 7..=7],
+        TestFlags::iter_ranges,
+    );
+    case(
+        TestFlags::from_bits_retain(1 << 7),
+        &[7..=7],
+        TestFlags::iter_ranges,
+    );
+
+    case(TestExternal::all(), &[0..=7], TestExternal::iter_ranges);
+}
+
+#[test]
+fn widths() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1;
+        }
+
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Wide: u128 {
+            const A = 1;
+        }
+
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct WideSigned: i128 {
+            const A = 1;
+        }
+    }
+
+    // Signed values aren't sign-extended past their width
+    assert_eq!(
+        vec![0..=7],
+        Signed::from_bits_retain(-1)
+            .iter_ranges()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![7..=7],
+        Signed::from_bits_retain(i8::MIN)
+            .iter_ranges()
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        vec![0..=127],
+        Wide::from_bits_retain(u128::MAX)
+            .iter_ranges()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![0..=0, 64..=127],
+        Wide::from_bits_retain(1 | u128::MAX << 64)
+            .iter_ranges()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![127..=127],
+        WideSigned::from_bits_retain(i128::MIN)
+            .iter_ranges()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Vec::<RangeInclusive<u32>>::new(),
+        Wide::empty().iter_ranges().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn roundtrip() {
+    for bits in 0..=u8::MAX {
+        let mut rebuilt = 0u8;
+
+        for range in TestFlags::from_bits_retain(bits).iter_ranges() {
+            for bit in range {
+                assert_eq!(0, rebuilt & 1 << bit);
+                rebuilt |= 1 << bit;
+            }
+        }
+
+        assert_eq!(bits, rebuilt);
+    }
+}
+
+#[track_caller]
+fn case<T: std::fmt::Debug>(
+    value: T,
+    expected: &[RangeInclusive<u32>],
+    inherent: impl FnOnce(&T) -> IterRanges,
+) {
+    assert_eq!(
+        expected,
+        inherent(&value).collect::<Vec<_>>(),
+        "{:?}.iter_ranges()",
+        value
+    );
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+use crate::iter::IterUnknownBits;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), &[], TestFlags::iter_unknown_bits);
+    case(TestFlags::ABC, &[], TestFlags::iter_unknown_bits);
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[3],
+        TestFlags::iter_unknown_bits,
+    );
+    case(
+        TestFlags::from_bits_retain(u8::MAX),
+        &[3, 4, 5, 6, 7],
+        TestFlags::iter_unknown_bits,
+    );
+
+    // Every bit is known when there's an unnamed flag like `const _ = !0`
+    case(
+        TestExternal::from_bits_retain(u8::MAX),
+        &[],
+        TestExternal::iter_unknown_bits,
+    );
+    case(
+        TestExternalFull::from_bits_retain(1 << 7),
+        &[],
+        TestExternalFull::iter_unknown_bits,
+    );
+}
+
+#[test]
+fn widths() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1;
+        }
+
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Wide: u128 {
+            const A = 1;
+            const HIGH = 1 << 100;
+        }
+    }
+
+    case(
+        Signed::from_bits_retain(i8::MIN | 1),
+        &[7],
+        Signed::iter_unknown_bits,
+    );
+    case(
+        Wide::from_bits_retain(1 | 1 << 64 | 1 << 100 | 1 << 127),
+        &[64, 127],
+        Wide::iter_unknown_bits,
+    );
+    assert_eq!(126, Wide::from_bits_retain(!0).iter_unknown_bits().len());
+}
+
+#[test]
+fn unknown_bits() {
+    for bits in 0..=u8::MAX {
+        let value = TestFlags::from_bits_retain(bits);
+
+        let mut rebuilt = 0u8;
+        for position in value.iter_unknown_bits() {
+            rebuilt |= 1 << position;
+        }
+
+        assert_eq!(value.unknown_bits(), rebuilt, "{:?}", value);
+        assert_eq!(
+            value.contains_unknown_bits(),
+            value.iter_unknown_bits().next().is_some(),
+            "{:?}",
+            value
+        );
+    }
+}
+
+#[track_caller]
+fn case<T: std::fmt::Debug>(
+    value: T,
+    expected: &[u32],
+    inherent: impl FnOnce(&T) -> IterUnknownBits,
+) {
+    assert_eq!(
+        expected,
+        inherent(&value).collect::<Vec<_>>(),
+        "{:?}.iter_unknown_bits()",
+        value
+    );
+}
@@ -0,0 +1,77 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = assert!(TestFlags::ABC.last().unwrap().bits() == 1 << 2);
+
+#[test]
+fn cases() {
+    case(None, TestFlags::empty(), TestFlags::last);
+    case(Some(1 << 2), TestFlags::ABC, TestFlags::last);
+    case(Some(1 << 1), TestFlags::A | TestFlags::B, TestFlags::last);
+
+    // Unknown bits are ignored
+    case(None, TestFlags::from_bits_retain(1 << 3), TestFlags::last);
+    case(
+        Some(1),
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        TestFlags::last,
+    );
+
+    // Composite flags are only returned if they're the only flag with the highest bit
+    case(Some(1 << 2), TestFlagsInvert::ABC, TestFlagsInvert::last);
+    case(
+        Some(1 << 1 | 1 << 2),
+        TestOverlapping::AB | TestOverlapping::BC,
+        TestOverlapping::last,
+    );
+    case(Some(1 | 1 << 1), TestOverlapping::AB, TestOverlapping::last);
+
+    case(
+        Some(1 << 1),
+        TestOverlappingFull::all(),
+        TestOverlappingFull::last,
+    );
+
+    case(None, TestZero::ZERO, TestZero::last);
+    case(None, TestEmpty::from_bits_retain(u8::MAX), TestEmpty::last);
+
+    // Unnamed flags are ignored
+    case(
+        None,
+        TestExternal::from_bits_retain(1 << 3),
+        TestExternal::last,
+    );
+    case(None, TestExternalFull::all(), TestExternalFull::last);
+}
+
+#[test]
+fn signed() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1 << 1;
+            const MIN = i8::MIN;
+        }
+    }
+
+    case(Some(i8::MIN), Signed::all(), Signed::last);
+    case(Some(1 << 1), Signed::A, Signed::last);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(
+    expected: Option<T::Bits>,
+    value: T,
+    inherent: impl FnOnce(&T) -> Option<T>,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        inherent(&value).map(|f| f.bits()),
+        "{:?}.last()",
+        value
+    );
+}
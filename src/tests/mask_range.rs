@@ -0,0 +1,119 @@
+use super::*;
+
+const _: () = assert!(TestFlags::mask_range(0..=2).bits() == TestFlags::ABC.bits());
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[bitflags(inherent_methods)]
+    struct Register: u16 {
+        const ENABLED = 1;
+        const MODE = Self::mask_range(1..=3).bits();
+        const LEVEL = Self::mask_range(4..=7).bits();
+        const HIGH = Self::mask_range(8..=15).bits();
+    }
+}
+
+#[test]
+fn cases() {
+    assert_eq!(TestFlags::ABC, TestFlags::mask_range(0..=2));
+    assert_eq!(TestFlags::B | TestFlags::C, TestFlags::mask_range(1..=2));
+
+    // Bits are set regardless of whether they're defined
+    assert_eq!(
+        TestFlags::from_bits_retain(0b1111_0000),
+        TestFlags::mask_range(4..=7)
+    );
+
+    assert_eq!(0b1110, Register::MODE.bits());
+    assert_eq!(0xf0, Register::LEVEL.bits());
+    assert_eq!(0xff00, Register::HIGH.bits());
+    assert!(Register::all().is_all());
+    assert_eq!(u16::MAX, Register::all().bits());
+}
+
+#[test]
+fn single_bit() {
+    for index in 0..8 {
+        assert_eq!(
+            TestFlags::from_bit_index(index),
+            Some(TestFlags::mask_range(index..=index)),
+            "{}",
+            index
+        );
+    }
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty() {
+    assert_eq!(TestFlags::empty(), TestFlags::mask_range(7..=4));
+    assert_eq!(TestFlags::empty(), TestFlags::mask_range(1..=0));
+
+    // Empty ranges don't panic even if they're out of range
+    assert_eq!(TestFlags::empty(), TestFlags::mask_range(100..=8));
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    let _ = TestFlags::mask_range(4..=8);
+}
+
+#[test]
+fn widths() {
+    macro_rules! widths {
+        ($($T:ident: $Bits:ty,)*) => {
+            $(
+                bitflags! {
+                    #[derive(Debug, PartialEq, Eq)]
+                    #[bitflags(inherent_methods)]
+                    struct $T: $Bits {
+                        const A = 1;
+                    }
+                }
+
+                let top = <$Bits>::BITS - 1;
+
+                assert_eq!(
+                    $T::from_bits_retain(!0),
+                    $T::mask_range(0..=top),
+                    "{}",
+                    stringify!($Bits),
+                );
+                assert_eq!(
+                    $T::from_bits_retain(1 << top),
+                    $T::mask_range(top..=top),
+                    "{}",
+                    stringify!($Bits),
+                );
+                assert_eq!(
+                    $T::from_bits_retain(!0 << 1),
+                    $T::mask_range(1..=top),
+                    "{}",
+                    stringify!($Bits),
+                );
+                assert_eq!(
+                    $T::from_bits_retain(!(1 << top)),
+                    $T::mask_range(0..=top - 1),
+                    "{}",
+                    stringify!($Bits),
+                );
+            )*
+        };
+    }
+
+    widths! {
+        U8: u8,
+        U16: u16,
+        U32: u32,
+        U64: u64,
+        U128: u128,
+        Usize: usize,
+        I8: i8,
+        I16: i16,
+        I32: i32,
+        I64: i64,
+        I128: i128,
+        Isize: isize,
+    }
+}
@@ -0,0 +1,88 @@
+use core::mem::size_of;
+
+use super::*;
+
+use crate::{non_zero::NonZeroFlags, Flags};
+
+#[test]
+fn cases() {
+    assert_eq!(0, NonZeroFlags::<TestFlags>::empty().bits());
+    assert_eq!(NonZeroFlags::<TestFlags>::empty(), NonZeroFlags::default());
+
+    let flags = NonZeroFlags::new(TestFlags::A | TestFlags::from_bits_retain(1 << 3)).unwrap();
+
+    assert_eq!(1 | 1 << 3, flags.bits());
+    assert_eq!(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        flags.get()
+    );
+    assert_eq!("NonZeroFlags(TestFlags(A | 0x8))", format!("{:?}", flags));
+
+    assert_eq!(
+        None,
+        NonZeroFlags::new(TestFlags::from_bits_retain(u8::MAX))
+    );
+    assert_eq!(None, NonZeroFlags::new(TestExternalFull::all()));
+}
+
+#[test]
+fn layout() {
+    fn case<F: Flags>()
+    where
+        F::Bits: crate::non_zero::NonZeroBits,
+    {
+        assert_eq!(size_of::<F>(), size_of::<NonZeroFlags<F>>());
+        assert_eq!(size_of::<F>(), size_of::<Option<NonZeroFlags<F>>>());
+    }
+
+    bitflags! {
+        struct U16: u16 {}
+        struct I32: i32 {}
+        struct U64: u64 {}
+        struct I128: i128 {}
+        struct Usize: usize {}
+    }
+
+    case::<TestFlags>();
+    case::<U16>();
+    case::<I32>();
+    case::<U64>();
+    case::<I128>();
+    case::<Usize>();
+}
+
+#[test]
+fn roundtrip() {
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Signed: i8 {
+            const A = 1;
+        }
+
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct U16: u16 {
+            const A = 1;
+        }
+    }
+
+    for bits in 0..u8::MAX {
+        let flags = TestFlags::from_bits_retain(bits);
+
+        assert_eq!(flags, NonZeroFlags::new(flags).unwrap().get());
+    }
+
+    for bits in i8::MIN..=i8::MAX {
+        let flags = Signed::from_bits_retain(bits);
+
+        match NonZeroFlags::new(Signed::from_bits_retain(bits)) {
+            Some(stored) => assert_eq!(flags, stored.get()),
+            None => assert_eq!(-1, bits),
+        }
+    }
+
+    for bits in 0..u16::MAX {
+        let flags = U16::from_bits_retain(bits);
+
+        assert_eq!(flags, NonZeroFlags::new(flags).unwrap().get());
+    }
+}
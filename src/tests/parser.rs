@@ -1,7 +1,9 @@
 use super::*;
 
 use crate::{
-    parser::{from_str, to_writer},
+    parser::{
+        from_str, from_str_ignore_case, from_str_with, to_writer, to_writer_with, ParseErrorKind,
+    },
     Flags,
 };
 
@@ -45,6 +47,8 @@ mod from_str {
         );
 
         assert_eq!(1 << 3, from_str::<TestFlags>("0x8").unwrap().bits());
+        assert_eq!(1 << 3, from_str::<TestFlags>("0X8").unwrap().bits());
+        assert_eq!(0xf8, from_str::<TestFlags>("0xF8").unwrap().bits());
         assert_eq!(1 | 1 << 3, from_str::<TestFlags>("A | 0x8").unwrap().bits());
         assert_eq!(
             1 | 1 << 1 | 1 << 3,
@@ -72,10 +76,338 @@ mod from_str {
             .unwrap_err()
             .to_string()
             .starts_with("invalid hex flag"));
-        assert!(from_str::<TestFlags>("0xffffffffffff")
+        assert!(from_str::<TestFlags>("0x")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid hex flag"));
+        assert!(from_str::<TestFlags>("0x-1")
             .unwrap_err()
             .to_string()
             .starts_with("invalid hex flag"));
+        assert!(from_str::<TestFlags>("0xffffffffffff")
+            .unwrap_err()
+            .to_string()
+            .starts_with("overflowing hex flag"));
+        assert!(from_str::<TestFlags>("A | 0x100")
+            .unwrap_err()
+            .to_string()
+            .starts_with("overflowing hex flag"));
+    }
+
+    #[test]
+    fn signed() {
+        bitflags! {
+            #[derive(Debug, PartialEq, Eq)]
+            struct Signed: i8 {
+                const A = 1;
+            }
+        }
+
+        let mut s = String::new();
+
+        for bits in i8::MIN..=i8::MAX {
+            let f = Signed::from_bits_retain(bits);
+
+            s.clear();
+            to_writer(&f, &mut s).unwrap();
+
+            assert_eq!(f, from_str::<Signed>(&s).unwrap());
+        }
+
+        assert_eq!(-128, from_str::<Signed>("0x80").unwrap().bits());
+        assert!(from_str::<Signed>("0x100")
+            .unwrap_err()
+            .to_string()
+            .starts_with("overflowing hex flag"));
+    }
+
+    #[test]
+    fn wide() {
+        bitflags! {
+            #[derive(Debug, PartialEq, Eq)]
+            struct Wide: u128 {
+                const A = 1;
+                const TOP = 1 << 127;
+
+                const _ = !0;
+            }
+
+            #[derive(Debug, PartialEq, Eq)]
+            struct SignedWide: i128 {
+                const A = 1;
+            }
+        }
+
+        assert_eq!(u128::MAX, Wide::all().bits());
+
+        let f = Wide::A | Wide::TOP | Wide::from_bits_retain(1 << 100);
+
+        let mut s = String::new();
+        to_writer(&f, &mut s).unwrap();
+
+        assert_eq!("A | TOP | 0x10000000000000000000000000", s);
+        assert_eq!(f, from_str::<Wide>(&s).unwrap());
+
+        assert_eq!(
+            u128::MAX,
+            from_str::<Wide>("0xffffffffffffffffffffffffffffffff")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            ParseErrorKind::OverflowingHexFlag,
+            from_str::<Wide>("0x100000000000000000000000000000000")
+                .unwrap_err()
+                .kind()
+        );
+
+        assert_eq!(
+            i128::MIN,
+            from_str::<SignedWide>("0x80000000000000000000000000000000")
+                .unwrap()
+                .bits()
+        );
+
+        let f = SignedWide::A | SignedWide::from_bits_retain(i128::MIN);
+
+        s.clear();
+        to_writer(&f, &mut s).unwrap();
+
+        assert_eq!("A | 0x80000000000000000000000000000000", s);
+        assert_eq!(f, from_str::<SignedWide>(&s).unwrap());
+    }
+}
+
+mod from_str_with {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(0, from_str_with::<TestFlags>("", ',').unwrap().bits());
+
+        assert_eq!(1, from_str_with::<TestFlags>(" A ", ',').unwrap().bits());
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_with::<TestFlags>("A,B,C", ',').unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 3,
+            from_str_with::<TestFlags>("A , B\t, 0x8", ',')
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 | 1 << 1,
+            from_str_with::<TestFlags>("A→B", '→').unwrap().bits()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let err = from_str_with::<TestFlags>("A | B", ',').unwrap_err();
+        assert_eq!(ParseErrorKind::InvalidNamedFlag, err.kind());
+        assert_eq!(Some(0..5), err.span());
+
+        let err = from_str_with::<TestFlags>("A,,B", ',').unwrap_err();
+        assert_eq!(ParseErrorKind::EmptyFlag, err.kind());
+        assert_eq!(Some(2..2), err.span());
+
+        let err = from_str_with::<TestFlags>("A→0xg", '→').unwrap_err();
+        assert_eq!(ParseErrorKind::InvalidHexFlag, err.kind());
+        assert_eq!(Some(4..7), err.span());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut s = String::new();
+
+        for bits in 0u8..=255 {
+            let f = TestFlags::from_bits_retain(bits);
+
+            s.clear();
+            to_writer_with(&f, ", ", &mut s).unwrap();
+
+            assert_eq!(f, from_str_with::<TestFlags>(&s, ',').unwrap());
+        }
+    }
+}
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        case("a", ParseErrorKind::InvalidNamedFlag, 0..1);
+        case("A | B | a", ParseErrorKind::InvalidNamedFlag, 8..9);
+        case("A | BOGUS | C", ParseErrorKind::InvalidNamedFlag, 4..9);
+        case("A |  AB\t| C", ParseErrorKind::InvalidNamedFlag, 5..7);
+        case("A & B", ParseErrorKind::InvalidNamedFlag, 0..5);
+
+        case("0xg", ParseErrorKind::InvalidHexFlag, 0..3);
+        case("A | 0x", ParseErrorKind::InvalidHexFlag, 4..6);
+        case("A|0x100", ParseErrorKind::OverflowingHexFlag, 2..7);
+
+        case("A | | B", ParseErrorKind::EmptyFlag, 4..4);
+        case("|", ParseErrorKind::EmptyFlag, 0..0);
+        case("A |  ", ParseErrorKind::EmptyFlag, 5..5);
+    }
+
+    #[test]
+    fn unicode() {
+        let err = from_str::<TestUnicode>("一 | 三").unwrap_err();
+
+        assert_eq!(ParseErrorKind::InvalidNamedFlag, err.kind());
+        assert_eq!(Some(6..9), err.span());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn got() {
+        // Errors returned while parsing don't keep a copy of the flag
+        let err = from_str::<TestFlags>("A | d ").unwrap_err();
+
+        assert_eq!(None, err.got());
+        assert_eq!("unrecognized named flag", err.to_string());
+
+        let err = crate::parser::ParseError::invalid_named_flag("d");
+
+        assert_eq!(Some("d"), err.got());
+        assert_eq!("unrecognized named flag `d`", err.to_string());
+    }
+
+    #[test]
+    fn display_in() {
+        let input = "A | d ";
+        let err = from_str::<TestFlags>(input).unwrap_err();
+
+        assert_eq!(
+            "unrecognized named flag `d`",
+            err.display_in(input).to_string()
+        );
+
+        let input = "A | 0xg";
+        let err = from_str::<TestFlags>(input).unwrap_err();
+
+        assert_eq!("invalid hex flag `0xg`", err.display_in(input).to_string());
+
+        let input = "A | ";
+        let err = from_str::<TestFlags>(input).unwrap_err();
+
+        assert_eq!("encountered empty flag", err.display_in(input).to_string());
+    }
+
+    #[test]
+    fn constructed() {
+        let err = crate::parser::ParseError::invalid_named_flag("d");
+
+        assert_eq!(ParseErrorKind::InvalidNamedFlag, err.kind());
+        assert_eq!(None, err.span());
+    }
+
+    #[test]
+    fn token() {
+        let input = "A | BOGUS | C";
+        let err = from_str::<TestFlags>(input).unwrap_err();
+
+        assert_eq!(Some(4), err.offset());
+        assert_eq!(Some("BOGUS"), err.token(input));
+
+        let input = "A | B |  | C";
+        let err = from_str::<TestFlags>(input).unwrap_err();
+
+        assert_eq!(Some(9), err.offset());
+        assert_eq!(Some(""), err.token(input));
+
+        // Errors without a span don't have a token
+        let err = crate::parser::ParseError::invalid_named_flag("d");
+
+        assert_eq!(None, err.offset());
+        assert_eq!(None, err.token("d"));
+    }
+
+    #[track_caller]
+    fn case(input: &str, expected_kind: ParseErrorKind, expected_span: core::ops::Range<usize>) {
+        let err = from_str::<TestFlags>(input).unwrap_err();
+
+        assert_eq!(expected_kind, err.kind(), "{}", input);
+        assert_eq!(Some(expected_span.start), err.offset(), "{}", input);
+        assert_eq!(
+            Some(&input[expected_span.clone()]),
+            err.token(input),
+            "{}",
+            input
+        );
+        assert_eq!(Some(expected_span), err.span(), "{}", input);
+    }
+}
+
+mod from_str_ignore_case {
+    use super::*;
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Mixed: u8 {
+            const A = 1;
+            const LOWER = 1 << 1;
+            const lower = 1 << 2;
+            const Upper = 1 << 3;
+            const UPPER = 1 << 4;
+        }
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(0, from_str_ignore_case::<TestFlags>("").unwrap().bits());
+
+        assert_eq!(1, from_str_ignore_case::<TestFlags>("a").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 1 | 1 << 3,
+            from_str_ignore_case::<TestFlags>("a | B | 0x8")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_ignore_case::<TestFlags>("abc").unwrap().bits()
+        );
+
+        // Exact matches are preferred
+        assert_eq!(
+            1 << 1,
+            from_str_ignore_case::<Mixed>("LOWER").unwrap().bits()
+        );
+        assert_eq!(
+            1 << 2,
+            from_str_ignore_case::<Mixed>("lower").unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 3,
+            from_str_ignore_case::<Mixed>("a|Upper").unwrap().bits()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(from_str_ignore_case::<TestFlags>("d")
+            .unwrap_err()
+            .to_string()
+            .starts_with("unrecognized named flag"));
+        assert!(from_str_ignore_case::<TestFlags>("a |")
+            .unwrap_err()
+            .to_string()
+            .starts_with("encountered empty flag"));
+
+        assert!(from_str_ignore_case::<Mixed>("Lower")
+            .unwrap_err()
+            .to_string()
+            .starts_with("ambiguous named flag"));
+        assert!(from_str_ignore_case::<Mixed>("upper")
+            .unwrap_err()
+            .to_string()
+            .starts_with("ambiguous named flag"));
+
+        // Strict parsing is unaffected
+        assert!(from_str::<TestFlags>("a").is_err());
     }
 }
 
@@ -104,6 +436,70 @@ mod to_writer {
         );
     }
 
+    #[test]
+    fn with_separator() {
+        assert_eq!("", write_with(TestFlags::empty(), ","));
+        assert_eq!("A", write_with(TestFlags::A, ","));
+        assert_eq!("A,B,C", write_with(TestFlags::all(), ","));
+        assert_eq!(
+            "A, 0x8",
+            write_with(TestFlags::A | TestFlags::from_bits_retain(1 << 3), ", ")
+        );
+    }
+
+    #[test]
+    fn fixed_capacity() {
+        // A writer that fails instead of allocating once its buffer is full
+        struct Buf {
+            buf: [u8; 8],
+            len: usize,
+        }
+
+        impl std::fmt::Write for Buf {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                let end = self.len + s.len();
+
+                self.buf
+                    .get_mut(self.len..end)
+                    .ok_or(std::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf {
+            buf: [0; 8],
+            len: 0,
+        };
+
+        to_writer(
+            &(TestFlags::A | TestFlags::from_bits_retain(1 << 3)),
+            &mut buf,
+        )
+        .unwrap();
+        assert_eq!(b"A | 0x8", &buf.buf[..buf.len]);
+
+        let mut buf = Buf {
+            buf: [0; 8],
+            len: 0,
+        };
+
+        assert_eq!(Err(std::fmt::Error), to_writer(&TestFlags::all(), &mut buf));
+        assert_eq!(b"A | B | ", &buf.buf[..buf.len]);
+    }
+
+    fn write_with<F: Flags>(value: F, separator: &str) -> String
+    where
+        F::Bits: crate::parser::WriteHex,
+    {
+        let mut s = String::new();
+
+        to_writer_with(&value, separator, &mut s).unwrap();
+        s
+    }
+
     fn write<F: Flags>(value: F) -> String
     where
         F::Bits: crate::parser::WriteHex,
@@ -0,0 +1,107 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), &[], 0, TestFlags::pop_last);
+    case(TestFlags::ABC, &[1 << 2, 1 << 1, 1], 0, TestFlags::pop_last);
+
+    // Unknown bits are never popped
+    case(
+        TestFlags::C | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        &[1 << 2],
+        1 << 3 | 1 << 7,
+        TestFlags::pop_last,
+    );
+
+    // Bits only defined by composite flags are never popped
+    case(
+        TestOverlapping::AB | TestOverlapping::BC,
+        &[],
+        1 | 1 << 1 | 1 << 2,
+        TestOverlapping::pop_last,
+    );
+
+    // Composite flags are popped one bit at a time
+    case(
+        TestFlagsInvert::ABC,
+        &[1 << 2, 1 << 1, 1],
+        0,
+        TestFlagsInvert::pop_last,
+    );
+
+    // Flags with the same bits are popped once
+    case(
+        TestOverlappingFull::all(),
+        &[1 << 1, 1],
+        0,
+        TestOverlappingFull::pop_last,
+    );
+
+    case(TestZero::ZERO, &[], 0, TestZero::pop_last);
+    case(
+        TestEmpty::from_bits_retain(u8::MAX),
+        &[],
+        u8::MAX,
+        TestEmpty::pop_last,
+    );
+
+    // Unnamed flags are never popped
+    case(
+        TestExternal::from_bits_retain(1 | 1 << 3),
+        &[1],
+        1 << 3,
+        TestExternal::pop_last,
+    );
+}
+
+#[test]
+fn signed() {
+    bitflags! {
+        #[derive(Debug, Clone, Copy)]
+        #[bitflags(inherent_methods)]
+        struct Signed: i8 {
+            const A = 1 << 1;
+            const MIN = i8::MIN;
+        }
+    }
+
+    case(Signed::all(), &[i8::MIN, 1 << 1], 0, Signed::pop_last);
+}
+
+#[test]
+fn count() {
+    for bits in 0..=u8::MAX {
+        let mut value = TestFlags::from_bits_retain(bits);
+        let count = value.count();
+
+        let mut popped = 0;
+        while value.pop_last().is_some() {
+            popped += 1;
+        }
+
+        assert_eq!(count, popped, "{:?}", TestFlags::from_bits_retain(bits));
+        assert_eq!(0, value.count(), "{:?}", TestFlags::from_bits_retain(bits));
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    mut value: T,
+    expected: &[T::Bits],
+    remaining: T::Bits,
+    mut inherent: impl FnMut(&mut T) -> Option<T>,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    let original = value;
+
+    let mut popped = Vec::new();
+    while let Some(flag) = inherent(&mut value) {
+        popped.push(flag.bits());
+    }
+
+    assert_eq!(expected, &*popped, "{:?}.pop_last()", original);
+    assert_eq!(remaining, value.bits(), "{:?}.pop_last()", original);
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::A, 0, false),
+            (TestFlags::empty(), 0, false),
+            (TestFlags::from_bits_retain(1 << 3), 0, false),
+        ],
+        TestFlags::remove_changed,
+    );
+
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::A, 1 << 3, true),
+            (TestFlags::from_bits_retain(1 << 3), 1, true),
+            (TestFlags::B, 1 | 1 << 3, false),
+        ],
+        TestFlags::remove_changed,
+    );
+
+    // Any bit being set is a change, even if others were already unset
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::A | TestFlags::B, 0, true),
+            (TestFlags::ABC, 0, true),
+            (TestFlags::from_bits_retain(1 | 1 << 7), 0, true),
+        ],
+        TestFlags::remove_changed,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[(TestOverlapping::BC, 1, true)],
+        TestOverlapping::remove_changed,
+    );
+}
+
+#[test]
+fn matches_remove() {
+    for bits in 0..=u8::MAX {
+        for other in [0, 1, 1 << 1 | 1 << 2, 1 << 3, 1 << 7, u8::MAX] {
+            let value = TestFlags::from_bits_retain(bits);
+            let other = TestFlags::from_bits_retain(other);
+
+            let mut removed = value;
+            removed.remove(other);
+
+            let mut changed = value;
+            assert_eq!(
+                removed != value,
+                changed.remove_changed(other),
+                "{:?}.remove_changed({:?})",
+                value,
+                other
+            );
+            assert_eq!(removed, changed);
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits, bool)],
+    mut inherent: impl FnMut(&mut T, T) -> bool,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected, expected_changed) in inputs {
+        let mut removed = value;
+        assert_eq!(
+            *expected_changed,
+            inherent(&mut removed, *input),
+            "{:?}.remove_changed({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            removed.bits(),
+            "{:?}.remove_changed({:?})",
+            value,
+            input
+        );
+
+        let mut removed = value;
+        assert_eq!(
+            *expected_changed,
+            Flags::remove_changed(&mut removed, *input),
+            "Flags::remove_changed({:?}, {:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            removed.bits(),
+            "Flags::remove_changed({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
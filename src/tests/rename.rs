@@ -0,0 +1,93 @@
+use crate::{
+    parser::{from_str, from_str_ignore_case},
+    Flags,
+};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[bitflags(display, from_str)]
+    struct TestRename: u8 {
+        #[bitflags(rename = "allow-read")]
+        const ALLOW_READ = 1;
+
+        #[bitflags(rename = "allow write")]
+        const ALLOW_WRITE = 1 << 1;
+
+        const EXEC = 1 << 2;
+
+        #[bitflags(rename = "allow.all", alias = "ALL")]
+        const ALLOW_ALL = 1 | 1 << 1 | 1 << 2;
+    }
+}
+
+#[test]
+fn cases() {
+    case(Some(TestRename::ALLOW_READ), "allow-read");
+    case(Some(TestRename::ALLOW_WRITE), "allow write");
+    case(Some(TestRename::EXEC), "EXEC");
+    case(Some(TestRename::ALLOW_ALL), "allow.all");
+    case(Some(TestRename::ALLOW_ALL), "ALL");
+
+    // The identifier of a renamed flag isn't its name
+    case(None, "ALLOW_READ");
+    case(None, "ALLOW_ALL");
+}
+
+#[track_caller]
+fn case(expected: Option<TestRename>, name: &str) {
+    assert_eq!(
+        expected,
+        TestRename::from_name(name),
+        "TestRename::from_name({:?})",
+        name
+    );
+    assert_eq!(
+        expected,
+        <TestRename as Flags>::from_name(name),
+        "Flags::from_name({:?})",
+        name
+    );
+}
+
+#[test]
+fn names() {
+    assert_eq!(
+        vec!["allow-read", "allow write", "EXEC", "allow.all"],
+        TestRename::FLAGS
+            .iter()
+            .map(|flag| flag.name())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn roundtrip() {
+    for (flags, text) in [
+        (TestRename::ALLOW_READ, "allow-read"),
+        (
+            TestRename::ALLOW_WRITE | TestRename::EXEC,
+            "allow write | EXEC",
+        ),
+        (
+            TestRename::ALLOW_READ | TestRename::from_bits_retain(1 << 7),
+            "allow-read | 0x80",
+        ),
+        (TestRename::ALLOW_ALL, "allow-read | allow write | EXEC"),
+    ] {
+        assert_eq!(text, flags.to_string());
+        assert_eq!(flags, text.parse().unwrap());
+    }
+
+    assert_eq!(
+        TestRename::ALLOW_READ | TestRename::ALLOW_WRITE,
+        from_str::<TestRename>("allow-read|allow write").unwrap()
+    );
+    assert_eq!(
+        TestRename::ALLOW_READ,
+        from_str_ignore_case::<TestRename>("Allow-Read").unwrap()
+    );
+    assert_eq!(
+        TestRename::ALLOW_READ,
+        TestRename::from_name_ignore_case("ALLOW-READ").unwrap()
+    );
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[TestFlags::A, TestFlags::empty(), TestFlags::ABC],
+        TestFlags::replace,
+    );
+
+    // Unknown bits are kept in both the previous and new flags values
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[
+            TestFlags::B,
+            TestFlags::from_bits_retain(1 << 7),
+            TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        ],
+        TestFlags::replace,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[TestOverlapping::BC],
+        TestOverlapping::replace,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[T],
+    mut inherent: impl FnMut(&mut T, T) -> T,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for input in inputs {
+        let mut replaced = value;
+        assert_eq!(
+            value.bits(),
+            inherent(&mut replaced, *input).bits(),
+            "{:?}.replace({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            input.bits(),
+            replaced.bits(),
+            "{:?}.replace({:?})",
+            value,
+            input
+        );
+
+        let mut replaced = value;
+        assert_eq!(
+            value.bits(),
+            Flags::replace(&mut replaced, *input).bits(),
+            "Flags::replace({:?}, {:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            input.bits(),
+            replaced.bits(),
+            "Flags::replace({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
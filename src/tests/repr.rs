@@ -0,0 +1,84 @@
+use core::mem::{align_of, size_of};
+
+macro_rules! assert_layout {
+    ($($Flags:ident: $T:ty,)*) => {
+        $(
+            bitflags! {
+                #[repr(transparent)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                struct $Flags: $T {
+                    const A = 1;
+                }
+            }
+
+            // Static assertions: these fail to compile if the layout doesn't match
+            const _: [(); size_of::<$T>()] = [(); size_of::<$Flags>()];
+            const _: [(); align_of::<$T>()] = [(); align_of::<$Flags>()];
+        )*
+    };
+}
+
+assert_layout! {
+    U8: u8,
+    I8: i8,
+    U16: u16,
+    I16: i16,
+    U32: u32,
+    I32: i32,
+    U64: u64,
+    I64: i64,
+    U128: u128,
+    I128: i128,
+    Usize: usize,
+    Isize: isize,
+}
+
+bitflags! {
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ReprC: u32 {
+        const A = 1;
+    }
+}
+
+const _: [(); size_of::<u32>()] = [(); size_of::<ReprC>()];
+const _: [(); align_of::<u32>()] = [(); align_of::<ReprC>()];
+
+#[test]
+fn ffi_slice() {
+    // An array of flags values as it might come from a C API
+    let raw: [u32; 3] = [1, 0, 1 | 1 << 8];
+
+    // SAFETY: `U32` is `#[repr(transparent)]` over `u32`
+    let flags: &[U32] =
+        unsafe { core::slice::from_raw_parts(raw.as_ptr() as *const U32, raw.len()) };
+
+    assert_eq!(
+        &[U32::A, U32::empty(), U32::A | U32::from_bits_retain(1 << 8)],
+        flags
+    );
+
+    let mut flags = [U32::A, U32::empty()];
+
+    // SAFETY: `U32` is `#[repr(transparent)]` over `u32`
+    let raw: &mut [u32] =
+        unsafe { core::slice::from_raw_parts_mut(flags.as_mut_ptr() as *mut u32, flags.len()) };
+
+    raw[1] = 1;
+
+    assert_eq!([U32::A, U32::A], flags);
+}
+
+#[test]
+fn ffi_bits() {
+    // Without any `unsafe`, flags values are converted through their bits
+    let raw: [u32; 2] = [1, 1 << 8];
+
+    let flags: Vec<U32> = raw
+        .iter()
+        .map(|bits| U32::from_bits_retain(*bits))
+        .collect();
+    let roundtrip: Vec<u32> = flags.iter().map(|flags| flags.bits()).collect();
+
+    assert_eq!(&raw[..], &roundtrip[..]);
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        1 | 1 << 2,
+        TestFlags::ABC,
+        |f| f == TestFlags::A || f == TestFlags::C,
+        |f, p| f.retain(p),
+    );
+    case(0, TestFlags::ABC, |_| false, |f, p| f.retain(p));
+    case(
+        1 | 1 << 1 | 1 << 2,
+        TestFlags::ABC,
+        |_| true,
+        |f, p| f.retain(p),
+    );
+
+    // Unknown bits aren't passed to the predicate and are left as-is
+    case(
+        1 << 1 | 1 << 3,
+        TestFlags::B | TestFlags::from_bits_retain(1 << 3),
+        |f| f.bits() != 1 << 3,
+        |f, p| f.retain(p),
+    );
+    case(
+        1 << 3,
+        TestFlags::B | TestFlags::from_bits_retain(1 << 3),
+        |_| false,
+        |f, p| f.retain(p),
+    );
+
+    // Composite flags are yielded as a whole if they're defined first
+    case(
+        0,
+        TestFlagsInvert::ABC,
+        |f| f != TestFlagsInvert::ABC,
+        |f, p| f.retain(p),
+    );
+
+    // Rejecting one overlapping flag doesn't unset bits from another
+    case(
+        1 | 1 << 1,
+        TestOverlapping::AB | TestOverlapping::BC,
+        |f| f == TestOverlapping::AB,
+        |f, p| f.retain(p),
+    );
+
+    case(
+        1 << 5,
+        TestExternal::A | TestExternal::from_bits_retain(1 << 5),
+        |_| false,
+        |f, p| f.retain(p),
+    );
+}
+
+#[test]
+fn names() {
+    let allowed = ["A", "C"];
+
+    let mut flags = TestFlags::all();
+    flags.retain(|f| {
+        TestFlags::FLAGS
+            .iter()
+            .any(|flag| *flag.value() == f && allowed.contains(&flag.name()))
+    });
+
+    assert_eq!(TestFlags::A | TestFlags::C, flags);
+
+    let mut calls = 0;
+    TestFlags::all().retain(|_| {
+        calls += 1;
+        true
+    });
+
+    assert_eq!(3, calls);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: T::Bits,
+    value: T,
+    mut predicate: impl FnMut(T) -> bool,
+    inherent: impl FnOnce(&mut T, &mut dyn FnMut(T) -> bool),
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    assert_eq!(
+        expected,
+        {
+            let mut value = value;
+            inherent(&mut value, &mut predicate);
+            value
+        }
+        .bits(),
+        "{:?}.retain()",
+        value
+    );
+    assert_eq!(
+        expected,
+        {
+            let mut value = value;
+            Flags::retain(&mut value, &mut predicate);
+            value
+        }
+        .bits(),
+        "Flags::retain({:?})",
+        value
+    );
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::B, true, 1 | 1 << 1),
+            (TestFlags::B, false, 1),
+            (TestFlags::A, false, 0),
+            (TestFlags::ABC, true, 1 | 1 << 1 | 1 << 2),
+            (TestFlags::ABC, false, 0),
+            (TestFlags::A | TestFlags::B, false, 0),
+        ],
+        TestFlags::set,
+    );
+
+    // Unknown bits are set and unset without being truncated
+    case(
+        TestFlags::from_bits_retain(1 | 1 << 3),
+        &[
+            (
+                TestFlags::from_bits_retain(1 << 4),
+                true,
+                1 | 1 << 3 | 1 << 4,
+            ),
+            (TestFlags::from_bits_retain(1 << 3), false, 1),
+            (TestFlags::from_bits_retain(1 << 4), false, 1 | 1 << 3),
+            (TestFlags::all(), false, 1 << 3),
+        ],
+        TestFlags::set,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool, T::Bits)],
+    mut inherent: impl FnMut(&mut T, T, bool),
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, set, expected) in inputs {
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                inherent(&mut value, *input, *set);
+                value
+            }
+            .bits(),
+            "{:?}.set({:?}, {:?})",
+            value,
+            input,
+            set
+        );
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                Flags::set(&mut value, *input, *set);
+                value
+            }
+            .bits(),
+            "Flags::set({:?}, {:?}, {:?})",
+            value,
+            input,
+            set
+        );
+    }
+}
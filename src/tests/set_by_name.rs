@@ -0,0 +1,65 @@
+use super::*;
+
+use crate::{parser::ParseErrorKind, Flags};
+
+#[test]
+fn cases() {
+    case(Some(1 | 1 << 1), TestFlags::A, "B", true);
+    case(Some(0), TestFlags::A, "A", false);
+    case(Some(1 << 1), TestFlags::A | TestFlags::B, "A", false);
+
+    // Composite flags set and unset all their bits
+    case(Some(1 | 1 << 1 | 1 << 2), TestFlags::A, "ABC", true);
+    case(Some(0), TestFlags::A | TestFlags::C, "ABC", false);
+
+    // Unknown bits are left as-is
+    case(
+        Some(1 << 1 | 1 << 3),
+        TestFlags::from_bits_retain(1 << 3),
+        "B",
+        true,
+    );
+    case(
+        Some(1 << 3),
+        TestFlags::ABC | TestFlags::from_bits_retain(1 << 3),
+        "ABC",
+        false,
+    );
+
+    case(None, TestFlags::A, "", true);
+    case(None, TestFlags::A, "D", true);
+    case(None, TestFlags::A, "a", false);
+    case(None, TestExternal::A, "_", true);
+}
+
+#[test]
+fn error() {
+    let mut flags = TestFlags::A;
+
+    let err = flags.set_by_name("D", true).unwrap_err();
+
+    assert_eq!(ParseErrorKind::InvalidNamedFlag, err.kind());
+    assert_eq!(TestFlags::A, flags);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: Option<T::Bits>,
+    value: T,
+    name: &str,
+    set: bool,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    let mut flags = value;
+    let result = flags.set_by_name(name, set);
+
+    assert_eq!(
+        expected,
+        result.ok().map(|()| flags.bits()),
+        "Flags::set_by_name({:?}, {:?}, {:?})",
+        value,
+        name,
+        set
+    );
+}
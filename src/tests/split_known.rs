@@ -0,0 +1,100 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = {
+    let (known, unknown) = TestFlags::A.with_unknown(1 << 3).split_known();
+    assert!(known.bits() == 1 && unknown == 1 << 3);
+};
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), 0, 0, TestFlags::split_known);
+    case(
+        TestFlags::ABC,
+        1 | 1 << 1 | 1 << 2,
+        0,
+        TestFlags::split_known,
+    );
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        1,
+        1 << 3 | 1 << 7,
+        TestFlags::split_known,
+    );
+    case(
+        TestFlags::from_bits_retain(u8::MAX),
+        1 | 1 << 1 | 1 << 2,
+        !(1 | 1 << 1 | 1 << 2),
+        TestFlags::split_known,
+    );
+
+    // Every bit is known when there's an unnamed flag like `const _ = !0`
+    case(
+        TestExternal::from_bits_retain(u8::MAX),
+        u8::MAX,
+        0,
+        TestExternal::split_known,
+    );
+
+    case(
+        TestEmpty::from_bits_retain(1 << 3),
+        0,
+        1 << 3,
+        TestEmpty::split_known,
+    );
+}
+
+#[test]
+fn roundtrip() {
+    for bits in 0..=u8::MAX {
+        let value = TestFlags::from_bits_retain(bits);
+
+        let (known, unknown) = value.split_known();
+        assert!(!known.contains_unknown_bits(), "{:?}", value);
+        assert_eq!(0, known.bits() & unknown, "{:?}", value);
+        assert_eq!(value, known.with_unknown(unknown), "{:?}", value);
+
+        let (known, unknown) = Flags::split_known(value);
+        assert_eq!(value, Flags::with_unknown(known, unknown), "{:?}", value);
+
+        let value = TestExternal::from_bits_retain(bits);
+        assert_eq!((value, 0), value.split_known());
+    }
+}
+
+#[test]
+fn with_unknown() {
+    assert_eq!(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 7),
+        TestFlags::A.with_unknown(1 << 7)
+    );
+
+    // Known bits can be set too, and bits that are already set are kept
+    assert_eq!(
+        TestFlags::A | TestFlags::B,
+        TestFlags::A.with_unknown(1 | 1 << 1)
+    );
+    assert_eq!(
+        TestFlags::A | TestFlags::B,
+        Flags::with_unknown(TestFlags::A, 1 << 1)
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    known: T::Bits,
+    unknown: T::Bits,
+    inherent: impl FnOnce(T) -> (T, T::Bits),
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    let (inherent_known, inherent_unknown) = inherent(value);
+    assert_eq!(known, inherent_known.bits(), "{:?}.split_known()", value);
+    assert_eq!(unknown, inherent_unknown, "{:?}.split_known()", value);
+
+    let (trait_known, trait_unknown) = Flags::split_known(value);
+    assert_eq!(known, trait_known.bits(), "Flags::split_known({:?})", value);
+    assert_eq!(unknown, trait_unknown, "Flags::split_known({:?})", value);
+}
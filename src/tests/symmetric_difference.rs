@@ -2,6 +2,13 @@ use super::*;
 
 use crate::Flags;
 
+const _: () = assert!(
+    TestFlags::ABC
+        .symmetric_difference(TestFlags::from_bits_retain(1 | 1 << 3))
+        .bits()
+        == 1 << 1 | 1 << 2 | 1 << 3
+);
+
 #[test]
 fn cases() {
     case(
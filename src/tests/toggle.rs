@@ -0,0 +1,101 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::A, 1),
+            (TestFlags::ABC, 1 | 1 << 1 | 1 << 2),
+            (TestFlags::from_bits_retain(1 << 3), 1 << 3),
+        ],
+        TestFlags::toggle,
+    );
+
+    // Each bit in a multi-bit flag is flipped independently
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::ABC, 1 << 1 | 1 << 2 | 1 << 3),
+            (TestFlags::A | TestFlags::B, 1 << 1 | 1 << 3),
+            (TestFlags::from_bits_retain(1 << 3 | 1 << 4), 1 | 1 << 4),
+        ],
+        TestFlags::toggle,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[(TestOverlapping::BC, 1 | 1 << 2)],
+        TestOverlapping::toggle,
+    );
+}
+
+#[test]
+fn double_toggle() {
+    for bits in 0..=u8::MAX {
+        let value = TestFlags::from_bits_retain(bits);
+
+        for other in [
+            TestFlags::A,
+            TestFlags::ABC,
+            TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        ] {
+            let mut toggled = value;
+            toggled.toggle(other);
+            toggled.toggle(other);
+
+            assert_eq!(value, toggled, "{:?}.toggle({:?}) twice", value, other);
+            assert_eq!(
+                value,
+                value
+                    .symmetric_difference(other)
+                    .symmetric_difference(other)
+            );
+        }
+    }
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits)],
+    mut inherent: impl FnMut(&mut T, T),
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                inherent(&mut value, *input);
+                value
+            }
+            .bits(),
+            "{:?}.toggle({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                Flags::toggle(&mut value, *input);
+                value
+            }
+            .bits(),
+            "Flags::toggle({:?}, {:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::symmetric_difference(value, *input).bits(),
+            "Flags::symmetric_difference({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
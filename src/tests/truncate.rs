@@ -0,0 +1,64 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    // Values without unknown bits are unchanged
+    case(0, TestFlags::empty(), TestFlags::truncate);
+    case(1 | 1 << 1, TestFlags::A | TestFlags::B, TestFlags::truncate);
+    case(1 | 1 << 1 | 1 << 2, TestFlags::ABC, TestFlags::truncate);
+
+    case(0, TestFlags::from_bits_retain(1 << 3), TestFlags::truncate);
+    case(
+        1 | 1 << 2,
+        TestFlags::from_bits_retain(1 | 1 << 2 | 1 << 7),
+        TestFlags::truncate,
+    );
+
+    case(0, TestZero::from_bits_retain(1), TestZero::truncate);
+
+    // `const _ = !0` makes every bit known
+    case(
+        1 << 3 | 1 << 7,
+        TestExternal::from_bits_retain(1 << 3 | 1 << 7),
+        TestExternal::truncate,
+    );
+    case(
+        u8::MAX,
+        TestExternalFull::from_bits_retain(u8::MAX),
+        TestExternalFull::truncate,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: T::Bits,
+    value: T,
+    mut inherent: impl FnMut(&mut T),
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    assert_eq!(
+        expected,
+        {
+            let mut value = value;
+            inherent(&mut value);
+            value
+        }
+        .bits(),
+        "{:?}.truncate()",
+        value
+    );
+    assert_eq!(
+        expected,
+        {
+            let mut value = value;
+            Flags::truncate(&mut value);
+            value
+        }
+        .bits(),
+        "Flags::truncate({:?})",
+        value
+    );
+}
@@ -2,6 +2,8 @@ use super::*;
 
 use crate::Flags;
 
+const _: () = assert!(TestFlags::A.union(TestFlags::B).bits() == 1 | 1 << 1);
+
 #[test]
 fn cases() {
     case(
@@ -0,0 +1,29 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case::<TestFlags>(0, &[]);
+    case(1, &[TestFlags::A]);
+    case(1 | 1 << 1, &[TestFlags::A, TestFlags::B, TestFlags::A]);
+    case(
+        1 | 1 << 1 | 1 << 2 | 1 << 3,
+        &[TestFlags::ABC, TestFlags::from_bits_retain(1 << 3)],
+    );
+
+    case(1 << 5, &[TestExternal::from_bits_retain(1 << 5)]);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(expected: T::Bits, inputs: &[T])
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    assert_eq!(
+        expected,
+        T::union_all(inputs.iter().copied()).bits(),
+        "Flags::union_all({:?})",
+        inputs
+    );
+}
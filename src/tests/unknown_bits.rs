@@ -0,0 +1,44 @@
+use super::*;
+
+use crate::Flags;
+
+const _: () = assert!(TestFlags::from_bits_retain(1 | 1 << 3).unknown_bits() == 1 << 3);
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty(), TestFlags::unknown_bits);
+    case(0, TestFlags::ABC, TestFlags::unknown_bits);
+    case(
+        1 << 3,
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::unknown_bits,
+    );
+    case(
+        1 << 3 | 1 << 7,
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        TestFlags::unknown_bits,
+    );
+
+    case(
+        0,
+        TestExternal::from_bits_retain(u8::MAX),
+        TestExternal::unknown_bits,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(
+    expected: T::Bits,
+    value: T,
+    inherent: impl FnOnce(&T) -> T::Bits,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(expected, inherent(&value), "{:?}.unknown_bits()", value);
+    assert_eq!(
+        expected,
+        Flags::unknown_bits(&value),
+        "Flags::unknown_bits({:?})",
+        value
+    );
+}
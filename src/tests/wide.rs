@@ -0,0 +1,229 @@
+use crate::{
+    parser::{self, ParseErrorKind, ParseHex, WriteHex},
+    wide::WideBits,
+    Bits, Flag, Flags,
+};
+
+// A flags type with a flag in the lowest and highest bits, and in the bits either side of each word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Wide<const N: usize>(WideBits<N>);
+
+impl<const N: usize> Wide<N> {
+    const LOW: Self = Wide(WideBits::bit(0));
+    const WORD: Self = Wide(WideBits::bit(63).union(WideBits::bit(64)));
+    const HIGH: Self = Wide(WideBits::bit(WideBits::<N>::BITS - 1));
+}
+
+impl<const N: usize> Flags for Wide<N> {
+    const FLAGS: &'static [Flag<Self>] = &[
+        Flag::new("LOW", Self::LOW),
+        Flag::new("WORD", Self::WORD),
+        Flag::new("HIGH", Self::HIGH),
+    ];
+
+    type Bits = WideBits<N>;
+
+    fn bits(&self) -> WideBits<N> {
+        self.0
+    }
+
+    fn from_bits_retain(bits: WideBits<N>) -> Self {
+        Wide(bits)
+    }
+}
+
+bitflags! {
+    // The same flags as `Wide<3>`, generated with the `custom_bits` option
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[bitflags(custom_bits, display, from_str)]
+    struct Generated: WideBits<3> {
+        const LOW = WideBits::bit(0);
+        const WORD = WideBits::bit(63).union(WideBits::bit(64));
+        const HIGH = WideBits::bit(191);
+        const LOW_HIGH = Self::LOW.bits().union(Self::HIGH.bits());
+    }
+}
+
+const _: () = assert!(WideBits::<3>::bit(130).union(WideBits::bit(1)).count_ones() == 2);
+
+#[test]
+#[allow(clippy::op_ref)]
+fn bits() {
+    assert_eq!(128, WideBits::<2>::BITS);
+    assert_eq!(256, WideBits::<4>::BITS);
+
+    assert_eq!([1 << 2, 0, 0], WideBits::<3>::bit(2).words());
+    assert_eq!([0, 1, 0], WideBits::<3>::bit(64).words());
+    assert_eq!([0, 0, 1 << 63], WideBits::<3>::bit(191).words());
+
+    assert!(WideBits::<3>::EMPTY.is_empty());
+    assert!(!WideBits::<3>::bit(130).is_empty());
+    assert_eq!(WideBits::EMPTY, WideBits::<3>::default());
+    assert_eq!(192, WideBits::<3>::ALL.count_ones());
+
+    let a = WideBits::from_words([0b1100, 1, u64::MAX]);
+    let b = WideBits::from_words([0b1010, 0, 1]);
+
+    assert_eq!([0b1000, 0, 1], (a & b).words());
+    assert_eq!([0b1110, 1, u64::MAX], (a | b).words());
+    assert_eq!([0b0110, 1, u64::MAX - 1], (a ^ b).words());
+    assert_eq!([!0b1100, !1, 0], (!a).words());
+
+    // Operators are the same through references
+    assert_eq!(a & b, &a & &b);
+    assert_eq!(a | b, &a | &b);
+    assert_eq!(a ^ b, &a ^ &b);
+    assert_eq!(!a, !&a);
+    assert_eq!(a | b, a.union(b));
+
+    assert_eq!(
+        "WideBits(0x10000000000000000)",
+        format!("{:?}", WideBits::<2>::bit(64))
+    );
+}
+
+#[test]
+#[should_panic]
+fn bit_out_of_range() {
+    let _ = WideBits::<2>::bit(128);
+}
+
+#[test]
+fn hex() {
+    fn write<const N: usize>(bits: WideBits<N>) -> String {
+        let mut hex = String::new();
+        bits.write_hex(&mut hex).unwrap();
+        hex
+    }
+
+    assert_eq!("0", write(WideBits::<3>::EMPTY));
+    assert_eq!("1", write(WideBits::<3>::bit(0)));
+    assert_eq!("10000000000000000", write(WideBits::<3>::bit(64)));
+    assert_eq!(
+        "1000000000000000000000000f",
+        write(WideBits::<3>::from_words([0xf, 1 << 36, 0]))
+    );
+    assert_eq!("f".repeat(64), write(WideBits::<4>::ALL));
+
+    for hex in ["0", "1", "10000000000000000", "1000000000000000000000000f"] {
+        assert_eq!(hex, write(WideBits::<3>::parse_hex(hex).unwrap()));
+    }
+
+    // Leading zeros are ignored, even past the width
+    assert_eq!(
+        WideBits::<2>::bit(64),
+        WideBits::parse_hex(&format!("{}10000000000000000", "0".repeat(32))).unwrap()
+    );
+    assert_eq!(
+        WideBits::<4>::ALL,
+        WideBits::parse_hex(&"F".repeat(64)).unwrap()
+    );
+
+    assert_eq!(
+        ParseErrorKind::OverflowingHexFlag,
+        WideBits::<2>::parse_hex(&format!("1{}", "0".repeat(32)))
+            .unwrap_err()
+            .kind()
+    );
+    for invalid in ["", "+1", "-1", "g", "1 0", "0x1"] {
+        assert_eq!(
+            ParseErrorKind::InvalidHexFlag,
+            WideBits::<2>::parse_hex(invalid).unwrap_err().kind(),
+            "{:?}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn flags() {
+    case::<2>();
+    case::<3>();
+    case::<4>();
+}
+
+#[track_caller]
+fn case<const N: usize>() {
+    let unknown = Wide::<N>::from_bits_retain(WideBits::bit(100));
+    let value = Wide::LOW.union(Wide::HIGH).union(unknown);
+
+    assert!(value.contains(Wide::HIGH), "{}", N);
+    assert!(!value.contains(Wide::WORD), "{}", N);
+    assert!(value.contains_unknown_bits(), "{}", N);
+    assert_eq!(
+        Wide::LOW.union(Wide::HIGH),
+        Wide::from_bits_truncate(value.bits()),
+        "{}",
+        N
+    );
+    assert_eq!(Wide::WORD, value.complement(), "{}", N);
+    assert_eq!(4, Wide::<N>::all().bits().count_ones(), "{}", N);
+
+    let mut inserted = value;
+    inserted.insert(Wide::WORD);
+    inserted.remove(unknown);
+    assert!(inserted.is_all(), "{}", N);
+
+    assert_eq!(
+        vec!["LOW", "HIGH"],
+        value.iter_names().map(|(name, _)| name).collect::<Vec<_>>(),
+        "{}",
+        N
+    );
+    assert_eq!(3, value.iter().count(), "{}", N);
+
+    // Unknown bits are formatted and parsed as hex
+    let mut text = String::new();
+    parser::to_writer(&value, &mut text).unwrap();
+
+    assert_eq!(
+        format!("LOW | HIGH | 0x{}", format_args!("1{}", "0".repeat(25))),
+        text,
+        "{}",
+        N
+    );
+    assert_eq!(value, parser::from_str::<Wide<N>>(&text).unwrap(), "{}", N);
+    assert_eq!(
+        ParseErrorKind::InvalidNamedFlag,
+        parser::from_str::<Wide<N>>("LOW | MIDDLE")
+            .unwrap_err()
+            .kind(),
+        "{}",
+        N
+    );
+}
+
+#[test]
+fn generated() {
+    let unknown = Generated::from_bits_retain(WideBits::bit(100));
+    let value = Generated::LOW | Generated::HIGH | unknown;
+
+    assert!(value.contains(Generated::LOW_HIGH));
+    assert!(!value.intersects(Generated::WORD));
+    assert_eq!(Generated::WORD, !value);
+    assert_eq!(
+        Generated::LOW_HIGH,
+        Generated::from_bits_truncate(value.bits())
+    );
+    assert_eq!(None, Generated::from_bits(value.bits()));
+    assert_eq!(4, Generated::all().bits().count_ones());
+    assert_eq!(Generated::LOW_HIGH, Generated::all() - Generated::WORD);
+
+    assert_eq!(
+        vec!["LOW", "HIGH"],
+        value.iter_names().map(|(name, _)| name).collect::<Vec<_>>()
+    );
+
+    // The generated flags type behaves the same as the manual one
+    assert_eq!(
+        Wide::<3>::LOW.union(Wide::HIGH).bits(),
+        Generated::LOW_HIGH.bits()
+    );
+
+    let text = value.to_string();
+    assert_eq!(
+        format!("LOW | HIGH | 0x{}", format_args!("1{}", "0".repeat(25))),
+        text
+    );
+    assert_eq!(value, text.parse::<Generated>().unwrap());
+}
@@ -61,6 +61,28 @@ impl<B> Flag<B> {
     }
 }
 
+impl<B: Flags> Flag<B> {
+    /**
+    Whether the flag is composed of other defined flags.
+
+    A flag is composite if it contains the bits of a different, non-empty named flag,
+    like `ABC` containing `A`. Unnamed flags, like `const _ = !0`, are never composite.
+    */
+    pub fn is_composite(&self) -> bool {
+        if self.is_unnamed() {
+            return false;
+        }
+
+        let bits = self.value.bits();
+
+        B::FLAGS.iter().any(|other| {
+            let other = other.value.bits();
+
+            other != B::Bits::EMPTY && other != bits && bits & other == other
+        })
+    }
+}
+
 /**
 A set of defined flags using a bits type as storage.
 
@@ -127,11 +149,51 @@ bitflags! {
 
 assert_eq!(3, defined_flags::<MyFlags>());
 ```
+
+All the set operations available on flags types generated by the [`bitflags`](macro.bitflags.html)
+macro are also available through `Flags`, so they can be used in generic code too:
+
+```
+# use bitflags::{bitflags, Flags};
+fn enable<F: Flags>(current: &mut F, extra: F) {
+    current.insert(extra);
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    struct MyFlags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+let mut flags = MyFlags::A;
+enable(&mut flags, MyFlags::B);
+
+assert_eq!(MyFlags::A | MyFlags::B, flags);
+```
+
+The methods generated by the `bitflags` macro are `const`, so they can't call into this trait,
+but they're tested to behave the same as the methods provided here.
 */
 pub trait Flags: Sized + 'static {
     /// The set of defined flags.
     const FLAGS: &'static [Flag<Self>];
 
+    /// The text an empty flags value is formatted as.
+    ///
+    /// This is empty by default, so an empty flags value is formatted as an empty string.
+    /// The parser accepts this text in place of a flag, so the text roundtrips. Generated flags
+    /// types set it with the `empty = ".."` option.
+    const EMPTY_NAME: &'static str = "";
+
+    /// Other names that defined flags can be parsed from.
+    ///
+    /// Each alias is a flag with the alias as its name and the value of the flag it's an alias of.
+    /// Aliases are never formatted. This is empty by default. Generated flags types set it with
+    /// `#[bitflags(alias = "..")]` options on their flags.
+    const ALIASES: &'static [Flag<Self>] = &[];
+
     /// The underlying bits type.
     type Bits: Bits;
 
@@ -151,6 +213,20 @@ pub trait Flags: Sized + 'static {
         Self::from_bits_retain(truncated)
     }
 
+    /// Get a flags value with the bits of all named flags set.
+    ///
+    /// This is the same as [`Flags::all`], except the bits of unnamed flags, like `const _ = !0`,
+    /// aren't included unless they're also part of a named flag.
+    fn all_named() -> Self {
+        let mut named = Self::Bits::EMPTY;
+
+        for flag in Self::FLAGS.iter().filter(|flag| flag.is_named()) {
+            named = named | flag.value().bits();
+        }
+
+        Self::from_bits_retain(named)
+    }
+
     /// Get the underlying bits value.
     ///
     /// The returned value is exactly the bits set in this flags value.
@@ -177,6 +253,19 @@ pub trait Flags: Sized + 'static {
     /// Convert from a bits value exactly.
     fn from_bits_retain(bits: Self::Bits) -> Self;
 
+    /// Convert from a bits value, returning any unknown bits as an error.
+    ///
+    /// This method is like [`Flags::from_bits`], except the error reports which bits weren't known.
+    fn from_bits_strict(bits: Self::Bits) -> Result<Self, UnknownBits<Self::Bits>> {
+        let unknown = bits & !Self::all().bits();
+
+        if unknown == Self::Bits::EMPTY {
+            Ok(Self::from_bits_retain(bits))
+        } else {
+            Err(UnknownBits::new(unknown))
+        }
+    }
+
     /// Get a flags value with the bits of a flag with the given name set.
     ///
     /// This method will return `None` if `name` is empty or doesn't
@@ -187,7 +276,7 @@ pub trait Flags: Sized + 'static {
             return None;
         }
 
-        for flag in Self::FLAGS {
+        for flag in Self::FLAGS.iter().chain(Self::ALIASES) {
             if flag.name() == name {
                 return Some(Self::from_bits_retain(flag.value().bits()));
             }
@@ -196,6 +285,41 @@ pub trait Flags: Sized + 'static {
         None
     }
 
+    /// Whether all the bits of a flag with the given name are set in this flags value.
+    ///
+    /// This method will return `None` if `name` is empty or doesn't
+    /// correspond to any named flag.
+    fn get_by_name(&self, name: &str) -> Option<bool> {
+        Self::from_name(name).map(|flag| self.contains(flag))
+    }
+
+    /// Call [`Flags::set`] with the bits of a flag with the given name.
+    ///
+    /// This method will return an error if `name` is empty or doesn't
+    /// correspond to any named flag.
+    fn set_by_name(&mut self, name: &str, value: bool) -> Result<(), ParseError> {
+        let flag = Self::from_name(name).ok_or_else(|| ParseError::invalid_named_flag(name))?;
+
+        self.set(flag, value);
+
+        Ok(())
+    }
+
+    /// Get a flags value with the bits of a flag with the given name set,
+    /// matching the name without regard to ASCII case.
+    ///
+    /// If `name` exactly matches a named flag or alias then that flag is used. Otherwise, this
+    /// method will return `None` unless the flags and aliases `name` matches when ignoring case
+    /// all have the same value.
+    fn from_name_ignore_case(name: &str) -> Option<Self> {
+        // Prefer an exact match so flags that only differ by case can still be used
+        if let Some(flags) = Self::from_name(name) {
+            return Some(flags);
+        }
+
+        crate::parser::match_ignore_case(name).ok().flatten()
+    }
+
     /// Yield a set of contained flags values.
     ///
     /// Each yielded flags value will correspond to a defined named flag. Any unknown bits
@@ -212,6 +336,14 @@ pub trait Flags: Sized + 'static {
         iter::IterNames::new(self)
     }
 
+    /// Yield a set of named flags values that aren't contained in this flags value.
+    ///
+    /// This method is like [`Flags::iter_names`], except it yields defined flags that aren't set.
+    /// Composite flags, like `ABC` when `A`, `B`, and `C` are also defined, won't be yielded.
+    fn iter_missing(&self) -> iter::IterMissing<Self> {
+        iter::IterMissing::new(self)
+    }
+
     /// Whether all bits in this flags value are unset.
     fn is_empty(&self) -> bool {
         self.bits() == Self::Bits::EMPTY
@@ -224,6 +356,38 @@ pub trait Flags: Sized + 'static {
         Self::all().bits() | self.bits() == self.bits()
     }
 
+    /// Whether any unknown bits are set in this flags value.
+    ///
+    /// If the flags type defines an unnamed flag like `const _ = !0` then every bit is known,
+    /// so this method will always return `false`.
+    fn contains_unknown_bits(&self) -> bool {
+        self.unknown_bits() != Self::Bits::EMPTY
+    }
+
+    /// Get the bits in this flags value that don't correspond to any defined flag.
+    fn unknown_bits(&self) -> Self::Bits {
+        self.bits() & !Self::all().bits()
+    }
+
+    /// Split this flags value into its known bits, as a flags value, and its unknown bits.
+    ///
+    /// The known bits are the same as [`Flags::from_bits_truncate`], and the unknown bits are the same as
+    /// [`Flags::unknown_bits`]. They're returned as a bits value so they can't be mistaken for valid flags.
+    /// Use [`Flags::with_unknown`] to combine them again.
+    fn split_known(self) -> (Self, Self::Bits) {
+        let unknown = self.unknown_bits();
+
+        (Self::from_bits_truncate(self.bits()), unknown)
+    }
+
+    /// Set the given bits in this flags value as-is, like unknown bits from [`Flags::split_known`].
+    ///
+    /// This is the same as `self.union(Self::from_bits_retain(bits))`.
+    #[must_use]
+    fn with_unknown(self, bits: Self::Bits) -> Self {
+        Self::from_bits_retain(self.bits() | bits)
+    }
+
     /// Whether any set bits in a source flags value are also set in a target flags value.
     fn intersects(&self, other: Self) -> bool
     where
@@ -240,6 +404,46 @@ pub trait Flags: Sized + 'static {
         self.bits() & other.bits() == other.bits()
     }
 
+    /// Whether all set bits in this flags value are also set in `other`.
+    ///
+    /// The empty flags value is a subset of every flags value, including itself.
+    fn is_subset(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.bits() & other.bits() == self.bits()
+    }
+
+    /// Whether all set bits in `other` are also set in this flags value.
+    ///
+    /// Every flags value is a superset of the empty flags value.
+    fn is_superset(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.bits() & other.bits() == other.bits()
+    }
+
+    /// Whether no set bits in this flags value are also set in `other`.
+    ///
+    /// The empty flags value is disjoint from every flags value, including itself.
+    fn is_disjoint(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.bits() & other.bits() == Self::Bits::EMPTY
+    }
+
+    /// Whether all set bits in `other` are also set whenever all set bits in this flags value are.
+    ///
+    /// This is the same as `self.contains(other)`.
+    fn implies(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.bits() & other.bits() == other.bits()
+    }
+
     /// The bitwise or (`|`) of the bits in two flags values.
     fn insert(&mut self, other: Self)
     where
@@ -259,7 +463,78 @@ pub trait Flags: Sized + 'static {
         *self = Self::from_bits_retain(self.bits()).difference(other);
     }
 
+    /// Call [`Flags::insert`], returning whether this flags value changed.
+    ///
+    /// This method returns `true` if any bit set in `other` was unset in this flags value,
+    /// even if other bits in `other` were already set. Use `!self.intersects(other)` before
+    /// inserting to check whether all the bits were unset instead.
+    fn insert_changed(&mut self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        let changed = self.bits() & other.bits() != other.bits();
+        self.insert(other);
+        changed
+    }
+
+    /// Call [`Flags::remove`], returning whether this flags value changed.
+    ///
+    /// This method returns `true` if any bit set in `other` was set in this flags value,
+    /// even if other bits in `other` were already unset. Use `self.contains(other)` before
+    /// removing to check whether all the bits were set instead.
+    fn remove_changed(&mut self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        let changed = self.bits() & other.bits() != Self::Bits::EMPTY;
+        self.remove(other);
+        changed
+    }
+
+    /// Replace this flags value with `new`, returning the previous flags value.
+    fn replace(&mut self, new: Self) -> Self
+    where
+        Self: Sized,
+    {
+        core::mem::replace(self, new)
+    }
+
+    /// Call [`Flags::insert`], returning the previous flags value.
+    ///
+    /// The `fetch_` methods mirror the methods of `AtomicFlags`, which also return the previous flags value.
+    fn fetch_insert(&mut self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        let previous = Self::from_bits_retain(self.bits());
+        self.insert(other);
+        previous
+    }
+
+    /// Call [`Flags::remove`], returning the previous flags value.
+    fn fetch_remove(&mut self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        let previous = Self::from_bits_retain(self.bits());
+        self.remove(other);
+        previous
+    }
+
+    /// Call [`Flags::toggle`], returning the previous flags value.
+    fn fetch_toggle(&mut self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        let previous = Self::from_bits_retain(self.bits());
+        self.toggle(other);
+        previous
+    }
+
     /// The bitwise exclusive-or (`^`) of the bits in two flags values.
+    ///
+    /// Each bit set in `other` is flipped independently, including all the bits of multi-bit
+    /// flags and any unknown bits. Use [`Flags::symmetric_difference`] to toggle bits by value.
     fn toggle(&mut self, other: Self)
     where
         Self: Sized,
@@ -267,7 +542,22 @@ pub trait Flags: Sized + 'static {
         *self = Self::from_bits_retain(self.bits()).symmetric_difference(other);
     }
 
+    /// Flip the bits of this flags value that are set in `mask`, leaving any others as-is.
+    ///
+    /// The bits within `mask` end up the same as in `self.complement_of(mask)`, like an in-place
+    /// form of [`Flags::complement_of`].
+    /// Any unknown bits in `mask` are flipped too, without truncating them.
+    fn invert_within(&mut self, mask: Self)
+    where
+        Self: Sized,
+    {
+        *self = Self::from_bits_retain(self.bits() ^ mask.bits());
+    }
+
     /// Call [`Flags::insert`] when `value` is `true` or [`Flags::remove`] when `value` is `false`.
+    ///
+    /// Like [`Flags::insert`] and [`Flags::remove`], any unknown bits in `other` will be set
+    /// or unset as-is, without truncating them.
     fn set(&mut self, other: Self, value: bool)
     where
         Self: Sized,
@@ -279,6 +569,38 @@ pub trait Flags: Sized + 'static {
         }
     }
 
+    /// Unset all bits in this flags value.
+    fn clear(&mut self) {
+        *self = Self::empty();
+    }
+
+    /// Unset any unknown bits in this flags value.
+    ///
+    /// This is equivalent to `*self = Self::from_bits_truncate(self.bits())`.
+    fn truncate(&mut self) {
+        *self = Self::from_bits_truncate(self.bits());
+    }
+
+    /// Unset the bits of any contained named flags that don't match a predicate.
+    ///
+    /// The predicate is called once for each flag yielded by [`Flags::iter_names`].
+    /// Any unknown bits, or bits not corresponding to a named flag, are left as-is.
+    fn retain(&mut self, mut f: impl FnMut(Self) -> bool) {
+        let mut retained = Self::Bits::EMPTY;
+
+        let mut iter = self.iter_names();
+        for (_, flag) in &mut iter {
+            let bits = flag.bits();
+
+            if f(flag) {
+                retained = retained | bits;
+            }
+        }
+
+        // Keep any bits that weren't yielded as named flags
+        *self = Self::from_bits_retain(retained | iter.remaining().bits());
+    }
+
     /// The bitwise and (`&`) of the bits in two flags values.
     #[must_use]
     fn intersection(self, other: Self) -> Self {
@@ -311,10 +633,275 @@ pub trait Flags: Sized + 'static {
     fn complement(self) -> Self {
         Self::from_bits_truncate(!self.bits())
     }
+
+    /// The complement of a flags value relative to `mask`, so the bits set in `mask` but not in this flags value.
+    ///
+    /// This is the same as `mask.difference(self)`. Unlike [`Flags::complement`], the result isn't
+    /// truncated, so any unknown bits in `mask` are kept.
+    #[must_use]
+    fn complement_of(self, mask: Self) -> Self {
+        mask.difference(self)
+    }
+
+    /// The bitwise or (`|`) of the bits in each flags value.
+    ///
+    /// If the iterator is empty then this method will return [`Flags::empty`].
+    fn union_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        let mut bits = Self::Bits::EMPTY;
+
+        for flags in iter {
+            bits = bits | flags.bits();
+        }
+
+        Self::from_bits_retain(bits)
+    }
+
+    /// The bitwise and (`&`) of the bits in each flags value.
+    ///
+    /// If the iterator is empty then this method will return [`Flags::all`].
+    fn intersection_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        let mut iter = iter.into_iter();
+
+        let mut bits = match iter.next() {
+            Some(flags) => flags.bits(),
+            None => return Self::all(),
+        };
+
+        for flags in iter {
+            bits = bits & flags.bits();
+        }
+
+        Self::from_bits_retain(bits)
+    }
+
+    /// The bits that are set in `new` but not `old`, and set in `old` but not `new`.
+    ///
+    /// Any unknown bits are compared in the same way as the bits of defined flags.
+    fn diff(old: Self, new: Self) -> Changes<Self> {
+        let (old, new) = (old.bits(), new.bits());
+
+        Changes {
+            added: Self::from_bits_retain(new & !old),
+            removed: Self::from_bits_retain(old & !new),
+        }
+    }
 }
 
+/**
+The flags added and removed between two flags values, returned by [`Flags::diff`].
+
+The changes are formatted with each added flag prefixed by `+` and each removed flag prefixed
+by `-`, like `+B -C`. Any unknown bits are formatted as a hex number, like `+0x80`. If nothing
+changed then the formatted text is empty.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Changes<B> {
+    added: B,
+    removed: B,
+}
+
+impl<B> Changes<B> {
+    /// Get the bits set in the new flags value but not the old one.
+    pub const fn added(&self) -> &B {
+        &self.added
+    }
+
+    /// Get the bits set in the old flags value but not the new one.
+    pub const fn removed(&self) -> &B {
+        &self.removed
+    }
+}
+
+impl<B: Flags> Changes<B> {
+    /// Whether the old and new flags values are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Yield the names of added flags.
+    ///
+    /// This is the same as calling [`Flags::iter_names`] on [`Changes::added`].
+    pub fn added_names(&self) -> iter::IterNames<B> {
+        self.added.iter_names()
+    }
+
+    /// Yield the names of removed flags.
+    ///
+    /// This is the same as calling [`Flags::iter_names`] on [`Changes::removed`].
+    pub fn removed_names(&self) -> iter::IterNames<B> {
+        self.removed.iter_names()
+    }
+}
+
+impl<B: Flags> fmt::Display for Changes<B>
+where
+    B::Bits: WriteHex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+
+        for (prefix, flags) in [("+", &self.added), ("-", &self.removed)] {
+            let mut iter = flags.iter_names();
+            for (name, _) in &mut iter {
+                if !first {
+                    f.write_str(" ")?;
+                }
+
+                first = false;
+                write!(f, "{}{}", prefix, name)?;
+            }
+
+            // Write any bits that don't correspond to a named flag as a hex number
+            let remaining = iter.remaining().bits();
+            if remaining != B::Bits::EMPTY {
+                if !first {
+                    f.write_str(" ")?;
+                }
+
+                first = false;
+                write!(f, "{}0x", prefix)?;
+                remaining.write_hex(&mut *f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**
+An error returned when converting a bits value with unknown bits set into a flags value.
+
+The error is formatted with the unknown bits as hex and their positions, like
+`unknown bits: 0x88 (bits 3, 7)`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownBits<B> {
+    bits: B,
+}
+
+impl<B> UnknownBits<B> {
+    /// Report a bits value containing unknown bits.
+    pub const fn new(bits: B) -> Self {
+        UnknownBits { bits }
+    }
+
+    /// Get the unknown bits.
+    pub const fn bits(&self) -> &B {
+        &self.bits
+    }
+}
+
+fn fmt_unknown_bits(bits: u128, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if bits == 0 {
+        return Ok(());
+    }
+
+    let plural = bits.count_ones() > 1;
+    f.write_str(if plural { " (bits " } else { " (bit " })?;
+
+    for (i, position) in iter::IterUnknownBits::__private_const_new(bits, 16).enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+
+        write!(f, "{}", position)?;
+    }
+
+    f.write_str(")")
+}
+
+#[cfg(feature = "std")]
+impl<B: fmt::Debug> std::error::Error for UnknownBits<B> where UnknownBits<B>: fmt::Display {}
+
 /**
 A bits type that can be used as storage for a flags type.
+
+This trait is implemented for all the primitive integer types. Custom bits types can be used in the
+[`bitflags`](macro.bitflags.html) macro with the `custom_bits` option. The methods it generates for
+them call into their trait impls, so most of those methods aren't `const`. Custom bits types also
+need to implement [`ParseHex`](crate::parser::ParseHex) and [`WriteHex`](crate::parser::WriteHex),
+so the flags type can be formatted and parsed with the [`parser`](crate::parser) module:
+
+```
+use core::{fmt, ops::{BitAnd, BitOr, BitXor, Not}};
+
+use bitflags::{
+    bitflags,
+    parser::{self, ParseError, ParseHex, WriteHex},
+    Bits,
+};
+
+// A big-endian integer, like one read directly from the wire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+struct U16Be(u16);
+
+impl Bits for U16Be {
+    const EMPTY: Self = U16Be(0);
+    const ALL: Self = U16Be(u16::MAX);
+}
+
+impl BitAnd for U16Be {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        U16Be(self.0 & other.0)
+    }
+}
+
+impl BitOr for U16Be {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        U16Be(self.0 | other.0)
+    }
+}
+
+impl BitXor for U16Be {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        U16Be(self.0 ^ other.0)
+    }
+}
+
+impl Not for U16Be {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        U16Be(!self.0)
+    }
+}
+
+impl ParseHex for U16Be {
+    fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        u16::parse_hex(input).map(|bits| U16Be(bits.to_be()))
+    }
+}
+
+impl WriteHex for U16Be {
+    fn write_hex<W: fmt::Write>(&self, writer: W) -> fmt::Result {
+        u16::from_be(self.0).write_hex(writer)
+    }
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(custom_bits)]
+    struct MyFlags: U16Be {
+        const A = U16Be(1u16.to_be());
+        const B = U16Be((1u16 << 1).to_be());
+    }
+}
+
+let flags = MyFlags::A | MyFlags::from_bits_retain(U16Be((1u16 << 8).to_be()));
+
+let mut text = String::new();
+parser::to_writer(&flags, &mut text).unwrap();
+
+assert_eq!("A | 0x100", text);
+assert_eq!(flags, parser::from_str::<MyFlags>(&text).unwrap());
+```
 */
 pub trait Bits:
     Clone
@@ -334,10 +921,14 @@ pub trait Bits:
     const ALL: Self;
 }
 
-// Not re-exported: prevent custom `Bits` impls being used in the `bitflags!` macro,
-// or they may fail to compile based on crate features
+// Not re-exported: prevent custom `Bits` impls being used in the `bitflags!` macro without
+// the `custom_bits` option, or they may fail to compile based on crate features
 pub trait Primitive {}
 
+// Not re-exported: called by the `bitflags!` macro with the bits type of flags types
+// that don't use the `custom_bits` option
+pub fn assert_primitive<B: Primitive>() {}
+
 macro_rules! impl_bits {
     ($($u:ty, $i:ty,)*) => {
         $(
@@ -353,13 +944,17 @@ macro_rules! impl_bits {
 
             impl ParseHex for $u {
                 fn parse_hex(input: &str) -> Result<Self, ParseError> {
-                    <$u>::from_str_radix(input, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+                    <$u>::from_str_radix(input, 16).map_err(ParseError::from_hex_error)
                 }
             }
 
             impl ParseHex for $i {
                 fn parse_hex(input: &str) -> Result<Self, ParseError> {
-                    <$i>::from_str_radix(input, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+                    // Signed values are written as their raw bits, so `-1i8` is written as `ff`
+                    // Parse them through the unsigned type so they roundtrip
+                    <$u>::from_str_radix(input, 16)
+                        .map(|bits| bits as $i)
+                        .map_err(ParseError::from_hex_error)
                 }
             }
 
@@ -375,6 +970,20 @@ macro_rules! impl_bits {
                 }
             }
 
+            impl fmt::Display for UnknownBits<$u> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "unknown bits: {:#x}", self.bits)?;
+                    fmt_unknown_bits(self.bits as u128, f)
+                }
+            }
+
+            impl fmt::Display for UnknownBits<$i> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "unknown bits: {:#x}", self.bits)?;
+                    fmt_unknown_bits(self.bits as $u as u128, f)
+                }
+            }
+
             impl Primitive for $i {}
             impl Primitive for $u {}
         )*
@@ -393,9 +1002,6 @@ impl_bits! {
 /// A trait for referencing the `bitflags`-owned internal type
 /// without exposing it publicly.
 pub trait PublicFlags {
-    /// The type of the underlying storage.
-    type Primitive: Primitive;
-
     /// The type of the internal field on the generated flags type.
     type Internal;
 }
@@ -426,5 +1032,5 @@ impl<B: Flags> ImplementedByBitFlagsMacro for B {}
 pub trait ImplementedByBitFlagsMacro {}
 
 pub(crate) mod __private {
-    pub use super::{ImplementedByBitFlagsMacro, PublicFlags};
+    pub use super::{assert_primitive, ImplementedByBitFlagsMacro, PublicFlags};
 }
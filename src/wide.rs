@@ -0,0 +1,300 @@
+/*!
+Flags types with more bits than the largest primitive integer.
+
+A [`WideBits`] stores `N` `u64` words, so a flags type using `WideBits<3>` has 192 bits. It can be
+used as the bits type of a flags type generated by the [`bitflags`](crate::bitflags) macro with the
+`custom_bits` option. The operators of `WideBits` can't be called in a `const` context, so most of the
+generated methods aren't `const`, but `bits` is, along with `WideBits::union`, so composite flags can
+still be defined:
+
+```
+use bitflags::{bitflags, wide::WideBits};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(custom_bits, display, from_str)]
+    struct Caps: WideBits<3> {
+        const READ = WideBits::bit(0);
+        const WRITE = WideBits::bit(1);
+        const ADMIN = WideBits::bit(180);
+        const READ_WRITE = Self::READ.bits().union(Self::WRITE.bits());
+    }
+}
+
+let caps = Caps::READ | Caps::ADMIN;
+
+assert!(caps.contains(Caps::ADMIN));
+assert_eq!(2, caps.iter().count());
+
+assert_eq!("READ | ADMIN", caps.to_string());
+assert_eq!(caps, "READ | ADMIN".parse().unwrap());
+```
+
+See the docs for the `custom_bits` option on the [`bitflags`](crate::bitflags) macro for what isn't
+supported. The [`Flags`](crate::Flags) trait can also be implemented manually for a flags type with
+wide bits:
+
+```
+use bitflags::{wide::WideBits, Flag, Flags};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Caps(WideBits<3>);
+
+impl Caps {
+    const READ: Self = Caps(WideBits::bit(0));
+    const ADMIN: Self = Caps(WideBits::bit(180));
+}
+
+impl Flags for Caps {
+    const FLAGS: &'static [Flag<Self>] = &[
+        Flag::new("READ", Caps::READ),
+        Flag::new("ADMIN", Caps::ADMIN),
+    ];
+
+    type Bits = WideBits<3>;
+
+    fn bits(&self) -> WideBits<3> {
+        self.0
+    }
+
+    fn from_bits_retain(bits: WideBits<3>) -> Self {
+        Caps(bits)
+    }
+}
+
+assert!(Caps::READ.union(Caps::ADMIN).contains(Caps::ADMIN));
+```
+
+`WideBits` is `Copy`, but copying many words isn't free, so its operators are also implemented
+for references. With the `serde` feature, it's serialized as a tuple of its words, from the least
+significant word up.
+*/
+
+use core::{
+    fmt,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+use crate::{
+    parser::{ParseError, ParseErrorKind, ParseHex, WriteHex},
+    Bits,
+};
+
+/**
+A bits type made of `N` `u64` words, with `64 * N` bits.
+
+Bit `0` is the least significant bit of the first word, and bit `64 * N - 1` is the most
+significant bit of the last word.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WideBits<const N: usize>([u64; N]);
+
+impl<const N: usize> WideBits<N> {
+    /// The number of bits, which is `64 * N`.
+    pub const BITS: u32 = 64 * N as u32;
+
+    /// Convert from words, from the least significant word up.
+    pub const fn from_words(words: [u64; N]) -> Self {
+        WideBits(words)
+    }
+
+    /// Get the words, from the least significant word up.
+    pub const fn words(&self) -> [u64; N] {
+        self.0
+    }
+
+    /// Get a value with only the bit at `index` set, where `0` is the lowest bit.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index` is out of range, which fails compilation in a `const` context.
+    pub const fn bit(index: u32) -> Self {
+        let mut words = [0; N];
+        words[index as usize / 64] = 1 << (index % 64);
+
+        WideBits(words)
+    }
+
+    /// The bitwise or (`|`) of two values.
+    ///
+    /// This method is `const`, so it can be used to define composite flags.
+    pub const fn union(self, other: Self) -> Self {
+        let mut words = self.0;
+
+        let mut i = 0;
+        while i < N {
+            words[i] |= other.0[i];
+            i += 1;
+        }
+
+        WideBits(words)
+    }
+
+    /// Whether no bits are set.
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
+
+    /// The number of set bits.
+    pub const fn count_ones(&self) -> u32 {
+        let mut count = 0;
+
+        let mut i = 0;
+        while i < N {
+            count += self.0[i].count_ones();
+            i += 1;
+        }
+
+        count
+    }
+
+    fn map(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let mut words = self.0;
+
+        for (word, other) in words.iter_mut().zip(other.0.iter()) {
+            *word = f(*word, *other);
+        }
+
+        WideBits(words)
+    }
+}
+
+impl<const N: usize> Default for WideBits<N> {
+    fn default() -> Self {
+        WideBits([0; N])
+    }
+}
+
+impl<const N: usize> fmt::Debug for WideBits<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WideBits(0x")?;
+        self.write_hex(&mut *f)?;
+        f.write_str(")")
+    }
+}
+
+impl<const N: usize> Bits for WideBits<N> {
+    const EMPTY: Self = WideBits([0; N]);
+    const ALL: Self = WideBits([u64::MAX; N]);
+}
+
+impl<const N: usize> BitAnd for WideBits<N> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.map(&other, |a, b| a & b)
+    }
+}
+
+impl<const N: usize> BitAnd for &WideBits<N> {
+    type Output = WideBits<N>;
+
+    fn bitand(self, other: Self) -> WideBits<N> {
+        self.map(other, |a, b| a & b)
+    }
+}
+
+impl<const N: usize> BitOr for WideBits<N> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.map(&other, |a, b| a | b)
+    }
+}
+
+impl<const N: usize> BitOr for &WideBits<N> {
+    type Output = WideBits<N>;
+
+    fn bitor(self, other: Self) -> WideBits<N> {
+        self.map(other, |a, b| a | b)
+    }
+}
+
+impl<const N: usize> BitXor for WideBits<N> {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        self.map(&other, |a, b| a ^ b)
+    }
+}
+
+impl<const N: usize> BitXor for &WideBits<N> {
+    type Output = WideBits<N>;
+
+    fn bitxor(self, other: Self) -> WideBits<N> {
+        self.map(other, |a, b| a ^ b)
+    }
+}
+
+impl<const N: usize> Not for WideBits<N> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        !&self
+    }
+}
+
+impl<const N: usize> Not for &WideBits<N> {
+    type Output = WideBits<N>;
+
+    fn not(self) -> WideBits<N> {
+        self.map(self, |a, _| !a)
+    }
+}
+
+impl<const N: usize> WriteHex for WideBits<N> {
+    fn write_hex<W: fmt::Write>(&self, mut writer: W) -> fmt::Result {
+        // Skip words that are all zeros, so the output doesn't have leading zeros
+        let mut words = self.0.iter().rev().skip_while(|word| **word == 0);
+
+        match words.next() {
+            Some(word) => write!(writer, "{:x}", word)?,
+            None => return writer.write_str("0"),
+        }
+
+        for word in words {
+            write!(writer, "{:016x}", word)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> ParseHex for WideBits<N> {
+    fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        // Only accept hex digits, so signs like `+` aren't accepted when parsing each word
+        if input.is_empty() || !input.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseError::from_kind(ParseErrorKind::InvalidHexFlag));
+        }
+
+        let digits = input.trim_start_matches('0');
+        if digits.len() > N * 16 {
+            return Err(ParseError::from_kind(ParseErrorKind::OverflowingHexFlag));
+        }
+
+        // Parse each word from the least significant digits up
+        let mut words = [0; N];
+        let mut end = digits.len();
+        for word in words.iter_mut() {
+            if end == 0 {
+                break;
+            }
+
+            let start = end.saturating_sub(16);
+            *word =
+                u64::from_str_radix(&digits[start..end], 16).map_err(ParseError::from_hex_error)?;
+            end = start;
+        }
+
+        Ok(WideBits(words))
+    }
+}
@@ -0,0 +1,13 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Flags: u8 {
+        const READ = 1;
+
+        // `READ` is already the name of a flag
+        #[bitflags(alias = "READ")]
+        const WRITE = 1 << 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Flags: u8 {
+        const A;
+        const B;
+        const C;
+        const D;
+        const E;
+        const F;
+        const G;
+        const H;
+        const I;
+    }
+}
+
+fn main() {}
@@ -21,8 +21,7 @@ use std::{
 
 use bitflags::{bitflags, Bits, parser::{ParseError, WriteHex, ParseHex}};
 
-// Ideally we'd actually want this to work, but currently need something like `num`'s `Zero`
-// With some design work it could be made possible
+// Custom bits types need the `custom_bits` option, since the generated methods are `const`
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct MyInt(u8);
 
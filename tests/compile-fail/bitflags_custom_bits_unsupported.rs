@@ -0,0 +1,10 @@
+use bitflags::{bitflags, wide::WideBits};
+
+bitflags! {
+    #[bitflags(custom_bits, exhaustive)]
+    pub struct Flags: WideBits<2> {
+        const A = WideBits::bit(0);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Default)]
+    #[bitflags(default = Self::A)]
+    pub struct Flags: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {}
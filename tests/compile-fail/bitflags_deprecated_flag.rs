@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Flags: u32 {
+        #[deprecated = "Use B instead"]
+        const A = 0b00000001;
+        const B = 0b00000010;
+    }
+}
+
+fn main() {
+    let _ = Flags::A;
+}
@@ -0,0 +1,11 @@
+use bitflags::bitflags;
+
+bitflags! {
+    // `NONE` is already the name of a flag
+    #[bitflags(empty = "NONE")]
+    pub struct Flags: u8 {
+        const NONE = 1;
+    }
+}
+
+fn main() {}
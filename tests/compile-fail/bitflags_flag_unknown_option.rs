@@ -0,0 +1,10 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Flags: u8 {
+        #[bitflags(unknown)]
+        const READ = 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+mod caps {
+    use bitflags::bitflags;
+
+    bitflags! {
+        pub struct Caps: u32 {
+            const READ = 1;
+            pub(self) const WRITE = 1 << 1;
+        }
+    }
+}
+
+fn main() {
+    let _ = caps::Caps::READ;
+    let _ = caps::Caps::WRITE;
+}
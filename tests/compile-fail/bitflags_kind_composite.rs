@@ -0,0 +1,12 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(kind = FlagKind)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const AB = Self::A.bits() | Self::B.bits();
+    }
+}
+
+fn main() {}
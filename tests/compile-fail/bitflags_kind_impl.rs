@@ -0,0 +1,12 @@
+use bitflags::bitflags;
+
+pub struct Flags(u8);
+
+bitflags! {
+    #[bitflags(kind = FlagKind)]
+    impl Flags: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(exhaustive)]
+    pub struct Register: u8 {
+        const ENABLE = 1;
+        const MODE = 0b0000_0110;
+
+        // Missing bit 3
+        const _ = 0b1111_0000;
+    }
+}
+
+fn main() {}
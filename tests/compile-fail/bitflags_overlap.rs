@@ -0,0 +1,20 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(deny_overlap)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 1;
+    }
+}
+
+bitflags! {
+    #[bitflags(deny_overlap)]
+    pub struct Fields: u8 {
+        const MODE = 0b11;
+        const LOW = 0b01;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Flags: u8 {
+        // `|` separates flags, so this name can't be parsed
+        #[bitflags(rename = "read | write")]
+        const READ_WRITE = 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Base: u8 {
+        const ACK = 1;
+        const SYN = 1 << 1;
+    }
+
+    #[bitflags(deny_overlap)]
+    pub struct Data: u8 {
+        use Base;
+
+        // Reuses the bit of `SYN`
+        const COMPRESSED = 1 << 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(PartialEq, PartialOrd)]
+    #[bitflags(subset_ord)]
+    pub struct Flags: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {}
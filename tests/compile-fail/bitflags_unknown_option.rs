@@ -0,0 +1,10 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(display, not_an_option)]
+    pub struct Flags: u32 {
+        const A = 0b00000001;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use bitflags::{bitflags, const_assert_flags};
+
+bitflags! {
+    #[bitflags(inherent_methods)]
+    pub struct Flags: u32 {
+        const CHUNKED = 1;
+        // Someone forgot that compressed data is always chunked
+        const COMPRESSED = 1 << 1;
+    }
+}
+
+const_assert_flags!(Flags::COMPRESSED.implies(Flags::CHUNKED));
+
+fn main() {}
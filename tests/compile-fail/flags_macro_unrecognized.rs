@@ -0,0 +1,14 @@
+use bitflags::{bitflags, flags};
+
+bitflags! {
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+const DEFAULT: Flags = flags!(Flags, "A | D");
+
+fn main() {
+    let _ = DEFAULT;
+}
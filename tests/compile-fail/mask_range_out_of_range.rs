@@ -0,0 +1,15 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(inherent_methods)]
+    pub struct Flags: u8 {
+        const LOW = 1;
+    }
+}
+
+// There are only 8 bits in a `u8`
+const HIGH: Flags = Flags::mask_range(4..=8);
+
+fn main() {
+    let _ = HIGH;
+}
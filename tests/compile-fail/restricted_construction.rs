@@ -0,0 +1,26 @@
+mod caps {
+    use bitflags::bitflags;
+
+    bitflags! {
+        pub(crate) struct Inner: u32 {
+            const READ = 1;
+            const WRITE = 1 << 1;
+        }
+    }
+
+    pub struct Caps(Inner);
+
+    impl Caps {
+        pub fn can_write(&self) -> bool {
+            self.0.contains(Inner::WRITE)
+        }
+    }
+}
+
+fn main() {
+    // The wrapper can be named, but not constructed
+    let _: Option<caps::Caps> = None;
+
+    let _ = caps::Caps(caps::Inner::READ);
+    let _ = caps::Caps::from_bits_retain(1 << 7);
+}
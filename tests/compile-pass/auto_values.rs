@@ -0,0 +1,65 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Auto: u8 {
+        const A;
+        const B;
+        const C;
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Mixed: i8 {
+        const A;
+        const B = 1 << 2;
+        #[cfg(any())]
+        const CONFIGURED_OUT;
+        #[deprecated]
+        const C;
+        const BC = Self::B.bits() | 1 << 1;
+        const D;
+        const _ = 1 << 4;
+        const E;
+    }
+
+    pub struct Full: u8 {
+        const A;
+        const B;
+        const C;
+        const D;
+        const E;
+        const F;
+        const G;
+        const H;
+    }
+}
+
+pub struct Manual(u16);
+
+bitflags! {
+    impl Manual: u16 {
+        const A = 1;
+        const B;
+    }
+}
+
+fn main() {
+    assert_eq!(1, Auto::A.bits());
+    assert_eq!(1 << 1, Auto::B.bits());
+    assert_eq!(1 << 2, Auto::C.bits());
+    assert_eq!(Some(Auto::B), Auto::from_name("B"));
+
+    // Values skip bits taken by any flag declared before them
+    assert_eq!(1, Mixed::A.bits());
+    assert_eq!(1 << 1, Mixed::from_name("C").unwrap().bits());
+    assert_eq!(1 << 3, Mixed::D.bits());
+    assert_eq!(1 << 5, Mixed::E.bits());
+    assert_eq!(0b0011_1111, Mixed::all().bits());
+
+    assert_eq!(u8::MAX, Full::all().bits());
+    assert_eq!(1 << 7, Full::H.bits());
+
+    assert_eq!(1 << 1, Manual::B.bits());
+}
@@ -0,0 +1,146 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+// A numbered flag for every bit of the bits type
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Wide: u128 {
+        const F0;
+        const F1;
+        const F2;
+        const F3;
+        const F4;
+        const F5;
+        const F6;
+        const F7;
+        const F8;
+        const F9;
+        const F10;
+        const F11;
+        const F12;
+        const F13;
+        const F14;
+        const F15;
+        const F16;
+        const F17;
+        const F18;
+        const F19;
+        const F20;
+        const F21;
+        const F22;
+        const F23;
+        const F24;
+        const F25;
+        const F26;
+        const F27;
+        const F28;
+        const F29;
+        const F30;
+        const F31;
+        const F32;
+        const F33;
+        const F34;
+        const F35;
+        const F36;
+        const F37;
+        const F38;
+        const F39;
+        const F40;
+        const F41;
+        const F42;
+        const F43;
+        const F44;
+        const F45;
+        const F46;
+        const F47;
+        const F48;
+        const F49;
+        const F50;
+        const F51;
+        const F52;
+        const F53;
+        const F54;
+        const F55;
+        const F56;
+        const F57;
+        const F58;
+        const F59;
+        const F60;
+        const F61;
+        const F62;
+        const F63;
+        const F64;
+        const F65;
+        const F66;
+        const F67;
+        const F68;
+        const F69;
+        const F70;
+        const F71;
+        const F72;
+        const F73;
+        const F74;
+        const F75;
+        const F76;
+        const F77;
+        const F78;
+        const F79;
+        const F80;
+        const F81;
+        const F82;
+        const F83;
+        const F84;
+        const F85;
+        const F86;
+        const F87;
+        const F88;
+        const F89;
+        const F90;
+        const F91;
+        const F92;
+        const F93;
+        const F94;
+        const F95;
+        const F96;
+        const F97;
+        const F98;
+        const F99;
+        const F100;
+        const F101;
+        const F102;
+        const F103;
+        const F104;
+        const F105;
+        const F106;
+        const F107;
+        const F108;
+        const F109;
+        const F110;
+        const F111;
+        const F112;
+        const F113;
+        const F114;
+        const F115;
+        const F116;
+        const F117;
+        const F118;
+        const F119;
+        const F120;
+        const F121;
+        const F122;
+        const F123;
+        const F124;
+        const F125;
+        const F126;
+        const F127;
+    }
+}
+
+fn main() {
+    assert_eq!(1, Wide::F0.bits());
+    assert_eq!(1 << 64, Wide::F64.bits());
+    assert_eq!(1 << 127, Wide::F127.bits());
+    assert_eq!(u128::MAX, Wide::all().bits());
+    assert_eq!(128, <Wide as bitflags::Flags>::FLAGS.len());
+}
@@ -0,0 +1,53 @@
+#![deny(warnings)]
+
+use core::convert::TryFrom;
+
+use bitflags::{bitflags, Flags, UnknownBits};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(bits_convert)]
+    pub struct Strict: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 5;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(bits_convert = retain)]
+    pub struct Retain: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(bits_convert)]
+    impl Manual: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {
+    // `TryFrom` rejects exactly the values `from_bits` rejects
+    for bits in 0..=u8::MAX {
+        assert_eq!(Strict::from_bits(bits), Strict::try_from(bits).ok());
+        assert_eq!(Strict::from_bits_strict(bits), Strict::try_from(bits));
+
+        let retained: Retain = bits.into();
+        assert_eq!(Retain::from_bits_retain(bits), retained);
+        assert_eq!(bits, u8::from(retained));
+    }
+
+    assert_eq!(Ok(Strict::A | Strict::C), Strict::try_from(1 | 1 << 5));
+    assert_eq!(Err(UnknownBits::new(1 << 7)), Strict::try_from(1 | 1 << 7));
+
+    assert_eq!(1 << 1, u8::from(Strict::B));
+    let bits: u8 = (Strict::A | Strict::from_bits_retain(1 << 7)).into();
+    assert_eq!(1 | 1 << 7, bits);
+
+    assert_eq!(1, u8::from(Manual::A));
+    assert!(Manual::try_from(1 << 1).is_err());
+}
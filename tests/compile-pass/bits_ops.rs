@@ -0,0 +1,61 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(bits_ops, inherent_methods)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(bits_ops)]
+    impl Manual: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    // Raw operands with undefined bits behave like `from_bits_retain`
+    for raw in [0, 1, 1 << 1 | 1 << 3, 1 << 7, u8::MAX] {
+        for flags in [Flags::empty(), Flags::A, Flags::A | Flags::C, Flags::from_bits_retain(1 << 6)] {
+            let retained = Flags::from_bits_retain(raw);
+
+            assert_eq!(flags | retained, flags | raw);
+            assert_eq!(flags & retained, flags & raw);
+            assert_eq!(flags ^ retained, flags ^ raw);
+
+            assert_eq!(retained | flags, raw | flags);
+            assert_eq!(retained & flags, raw & flags);
+            assert_eq!(retained ^ flags, raw ^ flags);
+
+            let mut assigned = flags;
+            assigned |= raw;
+            assert_eq!(flags | retained, assigned);
+
+            let mut assigned = flags;
+            assigned &= raw;
+            assert_eq!(flags & retained, assigned);
+
+            let mut assigned = flags;
+            assigned ^= raw;
+            assert_eq!(flags ^ retained, assigned);
+        }
+    }
+
+    // Unknown bits aren't truncated
+    assert_eq!(1 | 1 << 7, (Flags::A | 1 << 7).bits());
+    assert_eq!(1 << 7, (Flags::from_bits_retain(0xf0) & 0x8f).bits());
+    assert_eq!(1 << 1 | 1 << 7, (1 << 7 ^ Flags::B).bits());
+    assert!((Flags::A | 1 << 7).contains_unknown_bits());
+
+    assert_eq!((Manual::A | 1 << 1).bits(), (Manual::A | Manual::B).bits());
+    assert_eq!((1 << 4 & Manual::B).bits(), 0);
+}
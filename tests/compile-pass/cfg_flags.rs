@@ -0,0 +1,33 @@
+use bitflags::{bitflags, parser, Flags as _};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Flags: u32 {
+        const A = 1;
+
+        // `any()` is never active, so this flag is never defined
+        #[cfg(any())]
+        const EPOLL = 1 << 4;
+
+        #[cfg_attr(all(), doc = "Always documented")]
+        #[cfg_attr(any(), deprecated)]
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    assert_eq!(Flags::A | Flags::B, Flags::all());
+    assert_eq!(2, Flags::FLAGS.len());
+    assert!(Flags::FLAGS.iter().all(|flag| flag.name() != "EPOLL"));
+
+    assert_eq!(Some(Flags::B), Flags::from_name("B"));
+    assert_eq!(None, Flags::from_name("EPOLL"));
+    assert!(parser::from_str::<Flags>("A | EPOLL").is_err());
+
+    // The bits of a flag that isn't defined are unknown
+    assert_eq!(Flags::A, Flags::from_bits_truncate(1 | 1 << 4));
+    assert_eq!(
+        "Flags(A | 0x10)",
+        format!("{:?}", Flags::from_bits_retain(1 | 1 << 4))
+    );
+}
@@ -0,0 +1,44 @@
+#![deny(warnings)]
+
+use bitflags::{bitflags, const_assert_flags};
+
+bitflags! {
+    #[bitflags(inherent_methods)]
+    pub struct Flags8: u8 {
+        const CHUNKED = 1;
+        const COMPRESSED = Self::CHUNKED.bits() | 1 << 1;
+        const ENCRYPTED = 1 << 7;
+    }
+
+    #[bitflags(inherent_methods)]
+    pub struct Flags32: i32 {
+        const CHUNKED = 1;
+        const COMPRESSED = Self::CHUNKED.bits() | 1 << 1;
+        const ENCRYPTED = i32::MIN;
+    }
+
+    #[bitflags(inherent_methods)]
+    pub struct Flags128: u128 {
+        const CHUNKED = 1;
+        const COMPRESSED = Self::CHUNKED.bits() | 1 << 1;
+        const ENCRYPTED = 1 << 127;
+    }
+}
+
+const_assert_flags!(Flags8::COMPRESSED.implies(Flags8::CHUNKED));
+const_assert_flags!(Flags8::COMPRESSED.contains(Flags8::CHUNKED));
+const_assert_flags!(Flags8::COMPRESSED.is_disjoint(Flags8::ENCRYPTED));
+const_assert_flags!(!Flags8::CHUNKED.implies(Flags8::COMPRESSED),);
+
+const_assert_flags!(Flags32::COMPRESSED.implies(Flags32::CHUNKED));
+const_assert_flags!(Flags32::COMPRESSED.is_disjoint(Flags32::ENCRYPTED));
+const_assert_flags!(Flags32::all().implies(Flags32::ENCRYPTED));
+
+const_assert_flags!(Flags128::COMPRESSED.implies(Flags128::CHUNKED));
+const_assert_flags!(Flags128::COMPRESSED.is_disjoint(Flags128::ENCRYPTED));
+const_assert_flags!(Flags128::all().implies(Flags128::ENCRYPTED));
+
+fn main() {
+    // Assertions can also be made inside functions
+    const_assert_flags!(Flags8::ENCRYPTED.intersects(Flags8::all()));
+}
@@ -0,0 +1,46 @@
+use bitflags::bitflags;
+
+macro_rules! const_set_ops {
+    ($($Flags:ident: $T:ty,)*) => {
+        $(
+            bitflags! {
+                pub struct $Flags: $T {
+                    const A = 1;
+                    const B = 1 << 1;
+                    const C = 1 << 2;
+
+                    const AB = Self::A.union(Self::B).bits();
+                    const BC = Self::B.union(Self::C).bits();
+                }
+            }
+
+            const _: () = {
+                const AC: $Flags = $Flags::AB.symmetric_difference($Flags::BC);
+
+                assert!(AC.bits() == 1 | 1 << 2);
+                assert!($Flags::AB.difference($Flags::A).bits() == $Flags::B.bits());
+                assert!($Flags::AB.intersection($Flags::BC).bits() == 1 << 1);
+                assert!($Flags::A.complement().bits() == $Flags::BC.bits());
+                assert!($Flags::AB.contains($Flags::A));
+                assert!(!$Flags::A.intersects($Flags::BC));
+            };
+        )*
+    };
+}
+
+const_set_ops! {
+    FlagsU8: u8,
+    FlagsI8: i8,
+    FlagsU16: u16,
+    FlagsI16: i16,
+    FlagsU32: u32,
+    FlagsI32: i32,
+    FlagsU64: u64,
+    FlagsI64: i64,
+    FlagsU128: u128,
+    FlagsI128: i128,
+    FlagsUsize: usize,
+    FlagsIsize: isize,
+}
+
+fn main() {}
@@ -0,0 +1,129 @@
+#![deny(warnings)]
+
+use core::{
+    fmt,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+use bitflags::{
+    bitflags,
+    parser::{ParseError, ParseHex, WriteHex},
+    Bits, Flags,
+};
+
+// A big-endian integer, which has no `const` operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct U16Be(u16);
+
+impl U16Be {
+    const fn new(bits: u16) -> Self {
+        U16Be(bits.to_be())
+    }
+}
+
+impl Bits for U16Be {
+    const EMPTY: Self = U16Be(0);
+    const ALL: Self = U16Be(u16::MAX);
+}
+
+impl BitAnd for U16Be {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        U16Be(self.0 & other.0)
+    }
+}
+
+impl BitOr for U16Be {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        U16Be(self.0 | other.0)
+    }
+}
+
+impl BitXor for U16Be {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        U16Be(self.0 ^ other.0)
+    }
+}
+
+impl Not for U16Be {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        U16Be(!self.0)
+    }
+}
+
+impl ParseHex for U16Be {
+    fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        u16::parse_hex(input).map(U16Be::new)
+    }
+}
+
+impl WriteHex for U16Be {
+    fn write_hex<W: fmt::Write>(&self, writer: W) -> fmt::Result {
+        u16::from_be(self.0).write_hex(writer)
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[bitflags(custom_bits, display, from_str, bits_ops)]
+    pub struct Header: U16Be {
+        const A = U16Be::new(1);
+        const B = U16Be::new(1 << 1);
+        const C = U16Be::new(1 << 2);
+        const _ = U16Be::new(1 << 15);
+    }
+}
+
+pub struct Manual(U16Be);
+
+bitflags! {
+    #[bitflags(custom_bits, debug, default)]
+    impl Manual: U16Be {
+        const A = U16Be::new(1);
+        const B = U16Be::new(1 << 1);
+    }
+}
+
+// Methods that don't use the operators of the bits type are still `const`
+const EMPTY: Header = Header::empty();
+const BITS: U16Be = Header::A.bits();
+const RETAINED: Header = Header::from_bits_retain(U16Be::new(1 << 8));
+
+fn main() {
+    let ab = Header::A | Header::B;
+
+    assert_eq!(U16Be::new(0b11), ab.bits());
+    assert_eq!(U16Be::new(0b1000_0000_0000_0111), Header::all().bits());
+    assert!(ab.contains(Header::B));
+    assert!(!ab.intersects(Header::C));
+    assert_eq!(Header::A, ab - Header::B);
+    assert_eq!(Header::C | Header::from_bits_retain(U16Be::new(1 << 15)), !ab);
+    assert_eq!(None, Header::from_bits(U16Be::new(1 << 8)));
+    assert_eq!(Header::A, Header::from_bits_truncate(U16Be::new(1 | 1 << 8)));
+    assert_eq!(ab, Header::A | U16Be::new(1 << 1));
+
+    assert!(EMPTY.is_empty());
+    assert_eq!(Header::A.bits(), BITS);
+    assert!(Flags::is_empty(&Header::default()));
+
+    assert_eq!(vec![Header::A, Header::B], ab.iter().collect::<Vec<_>>());
+    assert!(Flags::is_superset(&ab, Header::A));
+
+    assert_eq!("A | 0x100", (Header::A | RETAINED).to_string());
+    assert_eq!(Header::A | RETAINED, "A | 0x100".parse().unwrap());
+    assert_eq!("Header(A | B)", format!("{:?}", ab));
+
+    let mut manual = Manual::default();
+    manual.insert(Manual::A);
+    manual.toggle(Manual::all());
+
+    assert_eq!(Manual::B.bits(), manual.bits());
+    assert_eq!("Manual(B)", format!("{:?}", manual));
+}
@@ -0,0 +1,43 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(default)]
+    pub struct Empty: u8 {
+        const A = 1;
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(default = Self::A.union(Self::C), display)]
+    pub struct Baseline: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(default = Self::A | Self::B)]
+    pub struct Operators: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(default = Self::B)]
+    impl Manual: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    assert_eq!(Empty::empty(), Empty::default());
+    assert_eq!(Baseline::A | Baseline::C, Baseline::default());
+    assert_eq!(Operators::A | Operators::B, Operators::default());
+    assert_eq!(Manual::B.bits(), Manual::default().bits());
+}
@@ -0,0 +1,46 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(deny_overlap)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+
+        // Composites are made entirely of other flags
+        const AB = Self::A.bits() | Self::B.bits();
+        const ABC = 0b0000_0111;
+
+        // Flags without any bits can't overlap
+        const NONE = 0;
+
+        // Unnamed flags are masks, so they're allowed to overlap
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    #[bitflags(deny_overlap)]
+    pub struct Fields: u32 {
+        const MODE = 0b11;
+        const ENABLE = 1 << 2;
+
+        #[cfg(any())]
+        const ENABLE_AGAIN = 1 << 2;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(deny_overlap)]
+    impl Manual: u8 {
+        const A;
+        const B;
+        const AB = Self::A.bits() | Self::B.bits();
+    }
+}
+
+fn main() {}
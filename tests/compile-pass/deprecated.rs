@@ -4,6 +4,8 @@
 extern crate bitflags;
 
 bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(display, from_str)]
     pub struct Flags: u32 {
         #[deprecated = "Use something else"]
         const A = 0b00000001;
@@ -11,4 +13,14 @@ bitflags! {
     }
 }
 
-fn main() {}
+fn main() {
+    // Deprecated flags are still part of the flags type, without referencing them directly
+    assert_eq!(0b00000011, Flags::all().bits());
+    assert_eq!(0b00000001, Flags::from_name("A").unwrap().bits());
+
+    let flags: Flags = "A | B".parse().unwrap();
+
+    assert_eq!(Flags::all(), flags);
+    assert_eq!("A | B", flags.to_string());
+    assert_eq!("Flags(A | B)", format!("{:?}", flags));
+}
@@ -0,0 +1,25 @@
+use bitflags::{bitflags, flags};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(display, from_str, empty = "none")]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+const EMPTY: Flags = flags!(Flags, "none");
+
+fn main() {
+    assert_eq!("none", Flags::empty().to_string());
+    assert_eq!("Flags(none)", format!("{:?}", Flags::empty()));
+    assert_eq!("A | B", (Flags::A | Flags::B).to_string());
+
+    assert_eq!(Flags::empty(), "none".parse().unwrap());
+    assert_eq!(Flags::empty(), "".parse().unwrap());
+    assert_eq!(Flags::A, "A | none".parse().unwrap());
+    assert_eq!(Flags::empty(), EMPTY);
+
+    assert!("NONE".parse::<Flags>().is_err());
+}
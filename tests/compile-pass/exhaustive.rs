@@ -0,0 +1,36 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(exhaustive)]
+    pub struct Register: u8 {
+        const ENABLE = 1;
+        const MODE = 0b0000_0110;
+
+        // Reserved bits
+        const _ = 0b1111_1000;
+    }
+}
+
+bitflags! {
+    #[bitflags(exhaustive, deny_overlap)]
+    pub struct Signed: i8 {
+        const A = 0b0111_1111;
+        const SIGN = i8::MIN;
+    }
+}
+
+pub struct Manual(u16);
+
+bitflags! {
+    #[bitflags(exhaustive)]
+    impl Manual: u16 {
+        const LOW = 0x00ff;
+        const HIGH = 0xff00;
+    }
+}
+
+fn main() {
+    assert_eq!(u8::MAX, Register::all().bits());
+    assert_eq!(-1, Signed::all().bits());
+    assert_eq!(u16::MAX, Manual::all().bits());
+}
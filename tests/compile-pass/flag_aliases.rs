@@ -0,0 +1,36 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(kind = AccessKind, from_str)]
+    pub struct Access: u8 {
+        const READ;
+
+        /// Renamed from `WRITE`
+        #[bitflags(alias = "WRITE")]
+        #[deprecated]
+        const MODIFY;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    impl Manual: u8 {
+        #[bitflags(alias = "B")]
+        const A = 1;
+    }
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let modify = Access::MODIFY;
+
+    assert_eq!(modify, "WRITE".parse().unwrap());
+    assert_eq!(1, Manual::from_name("B").unwrap().bits());
+
+    #[allow(deprecated)]
+    let _ = AccessKind::MODIFY;
+}
@@ -0,0 +1,37 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(kind = AccessKind, display, from_str)]
+    pub struct Access: u8 {
+        #[bitflags(rename = "allow-read")]
+        const READ;
+
+        #[bitflags(rename = "allow-write", alias = "OLD_WRITE")]
+        #[deprecated]
+        const WRITE;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    impl Manual: u8 {
+        #[bitflags(rename = "a-flag")]
+        const A = 1;
+    }
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let write = Access::WRITE;
+
+    assert_eq!(write, "allow-write".parse().unwrap());
+    assert_eq!(write, "OLD_WRITE".parse().unwrap());
+    assert_eq!("allow-read", Access::READ.to_string());
+    assert_eq!(1, Manual::from_name("a-flag").unwrap().bits());
+
+    let _ = AccessKind::READ;
+}
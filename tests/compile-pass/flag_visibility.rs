@@ -0,0 +1,38 @@
+mod caps {
+    use bitflags::bitflags;
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[bitflags(display, from_str)]
+        pub struct Caps: u32 {
+            pub const READ = 1;
+            pub(crate) const WRITE = 1 << 1;
+            #[doc = "Only visible in this module"]
+            pub(self) const EXEC;
+            const ALL = Self::READ.bits() | Self::WRITE.bits() | Self::EXEC.bits();
+        }
+    }
+
+    pub struct Manual(u8);
+
+    bitflags! {
+        impl Manual: u8 {
+            pub(in crate::caps) const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    pub fn exec() -> Caps {
+        Caps::EXEC
+    }
+}
+
+fn main() {
+    use caps::Caps;
+
+    // Restricted flags are still formatted and parsed by name
+    assert_eq!("READ | WRITE", (Caps::READ | Caps::WRITE).to_string());
+    assert_eq!(caps::exec(), "EXEC".parse().unwrap());
+    assert_eq!(Caps::ALL, Caps::all());
+    assert_eq!(3, caps::Manual::all().bits());
+}
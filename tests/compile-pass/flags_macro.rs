@@ -0,0 +1,51 @@
+#![deny(warnings)]
+
+use bitflags::{bitflags, flags};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+        const ABC = Self::A.bits() | Self::B.bits() | Self::C.bits();
+        #[bitflags(rename = "high")]
+        const HIGH = 1 << 7;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Wide: i128 {
+        const A = 1;
+        const MIN = i128::MIN;
+    }
+}
+
+const DEFAULT: Flags = flags!(Flags, "A | B");
+const EMPTY: Flags = flags!(Flags, "");
+const BLANK: Flags = flags!(Flags, " \t ");
+const UNNAMED: Flags = flags!(Flags, "C | 0x10 | 0X20");
+const COMPOSITE: Flags = flags!(Flags, "ABC|high");
+const WIDE: Wide = flags!(Wide, "A | 0x80000000000000000000000000000000");
+
+fn main() {
+    assert_eq!(Flags::A | Flags::B, DEFAULT);
+    assert_eq!(Flags::empty(), EMPTY);
+    assert_eq!(Flags::empty(), BLANK);
+    assert_eq!(Flags::C | Flags::from_bits_retain(0x30), UNNAMED);
+    assert_eq!(Flags::ABC | Flags::HIGH, COMPOSITE);
+    assert_eq!(Wide::A | Wide::MIN, WIDE);
+
+    // The same text parses to the same value at runtime
+    for (text, value) in [
+        ("A | B", DEFAULT),
+        ("", EMPTY),
+        (" \t ", BLANK),
+        ("C | 0x10 | 0X20", UNNAMED),
+        ("ABC|high", COMPOSITE),
+    ] {
+        assert_eq!(value, bitflags::parser::from_str::<Flags>(text).unwrap());
+    }
+
+    // Usable as an expression outside of a const too
+    assert_eq!(Flags::C, flags!(Flags, "C",));
+}
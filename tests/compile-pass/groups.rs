@@ -0,0 +1,65 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(groups(READ, WRITE, USER), display)]
+    pub struct Mode: u16 {
+        const R_USER = 1;
+        const W_USER = 1 << 1;
+        const R_GROUP = 1 << 2;
+        const W_GROUP = 1 << 3;
+        const R_OTHER = 1 << 4;
+        const W_OTHER = 1 << 5;
+
+        const READ = Self::R_USER.bits() | Self::R_GROUP.bits() | Self::R_OTHER.bits();
+        const WRITE = Self::W_USER.bits() | Self::W_GROUP.bits() | Self::W_OTHER.bits();
+        const USER = Self::R_USER.bits() | Self::W_USER.bits();
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(groups(LOW,))]
+    impl Manual: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const LOW = 0b11;
+    }
+}
+
+fn main() {
+    let names: Vec<_> = Mode::GROUPS.iter().map(|group| group.name()).collect();
+    assert_eq!(vec!["READ", "WRITE", "USER"], names);
+    assert_eq!(Mode::READ, *Mode::GROUPS[0].value());
+
+    // The value intersects multiple overlapping groups
+    let mode = Mode::R_USER | Mode::W_USER | Mode::R_OTHER | Mode::from_bits_retain(1 << 15);
+    let groups: Vec<_> = mode.groups().collect();
+    assert_eq!(
+        vec![
+            ("READ", Mode::R_USER | Mode::R_OTHER),
+            ("WRITE", Mode::W_USER),
+            ("USER", Mode::R_USER | Mode::W_USER),
+        ],
+        groups
+    );
+
+    // Groups that don't intersect the value are still yielded
+    let groups: Vec<_> = Mode::R_GROUP.groups().collect();
+    assert_eq!(
+        vec![
+            ("READ", Mode::R_GROUP),
+            ("WRITE", Mode::empty()),
+            ("USER", Mode::empty()),
+        ],
+        groups
+    );
+
+    // Formatting is unchanged
+    assert_eq!("R_USER | W_USER | R_OTHER | 0x8000", mode.to_string());
+
+    assert_eq!(1, Manual::A.groups().count());
+}
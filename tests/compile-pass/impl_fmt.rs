@@ -11,5 +11,8 @@ fn main() {
     // bug #267 (https://github.com/bitflags/bitflags/issues/267)
     let flags = Flags::from_bits_retain(0b11);
     assert_eq!(format!("{:?}", flags), "Flags(TWO | 0x1)");
-    assert_eq!(format!("{:#?}", flags), "Flags(\n    TWO | 0x1,\n)");
+    assert_eq!(
+        format!("{:#?}", flags),
+        "Flags(\n    {\n        bits: 0x3,\n        TWO: true,\n    },\n)"
+    );
 }
@@ -0,0 +1,44 @@
+use bitflags::bitflags;
+
+// A type like the ones generated by `bindgen`
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Mode(pub u32);
+
+bitflags! {
+    #[bitflags(debug, display)]
+    #[bitflags(from_str)]
+    impl Mode: u32 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const EXEC;
+    }
+}
+
+// Options are independent of each other
+#[derive(Debug)]
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(display)]
+    impl Manual: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {
+    let mode = Mode::READ | Mode::EXEC | Mode::from_bits_retain(1 << 8);
+
+    assert_eq!("Mode(READ | EXEC | 0x100)", format!("{:?}", mode));
+    assert_eq!("Mode(0x0)", format!("{:?}", Mode::empty()));
+    assert_eq!(
+        "Mode(\n    {\n        bits: 0x5,\n        READ: true,\n        WRITE: false,\n        EXEC: true,\n    },\n)",
+        format!("{:#?}", Mode::READ | Mode::EXEC)
+    );
+
+    assert_eq!("READ | EXEC | 0x100", mode.to_string());
+    assert!(mode == "READ | EXEC | 0x100".parse().unwrap());
+
+    assert_eq!("Manual(1)", format!("{:?}", Manual::A));
+    assert_eq!("A", Manual::A.to_string());
+}
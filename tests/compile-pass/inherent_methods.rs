@@ -0,0 +1,68 @@
+#![deny(warnings)]
+
+use bitflags::{bitflags, Flags};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Defaults: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(inherent_methods)]
+    pub struct Inherent: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+// Without the `inherent_methods` option, methods like these can be defined without conflicting
+impl Defaults {
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_subset(&self, other: Self) -> bool {
+        other.contains(*self)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::empty();
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    impl Manual: u8 {
+        const A = 1;
+    }
+}
+
+impl Manual {
+    pub fn first(&self) -> Option<Self> {
+        self.iter().next()
+    }
+}
+
+const COUNT: u32 = Inherent::all().count();
+const SUBSET: bool = Inherent::A.is_subset(Inherent::all());
+
+fn main() {
+    let mut flags = Defaults::A | Defaults::B;
+
+    assert_eq!(2, flags.count());
+    assert!(Defaults::A.is_subset(flags));
+
+    // The rest of the API is still available through the `Flags` trait
+    assert!(Flags::is_superset(&flags, Defaults::A));
+
+    flags.clear();
+    assert!(flags.is_empty());
+
+    assert_eq!(1, Manual::A.first().unwrap().bits());
+
+    assert_eq!(2, COUNT);
+    assert!(SUBSET);
+}
@@ -0,0 +1,63 @@
+#![deny(warnings)]
+
+use std::convert::TryFrom;
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(transparent)]
+    #[bitflags(kind = EventKind, display)]
+    pub struct Events: u8 {
+        /// The source is readable
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const CLOSE;
+
+        #[cfg(any())]
+        const NEVER = 1 << 3;
+
+        #[deprecated]
+        const OLD = 1 << 4;
+
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    #[bitflags(kind = Empty)]
+    struct NoFlags: u8 {}
+}
+
+fn describe(kind: EventKind) -> &'static str {
+    #[allow(deprecated)]
+    match kind {
+        EventKind::READ => "read",
+        EventKind::WRITE => "write",
+        EventKind::CLOSE => "close",
+        EventKind::OLD => "old",
+    }
+}
+
+fn main() {
+    assert_eq!(Events::READ, Events::from(EventKind::READ));
+    assert_eq!(Events::CLOSE, Events::from(EventKind::CLOSE));
+
+    assert_eq!(Ok(EventKind::WRITE), EventKind::try_from(Events::WRITE));
+    assert_eq!(Err(Events::empty()), EventKind::try_from(Events::empty()));
+    assert_eq!(
+        Err(Events::READ | Events::WRITE),
+        EventKind::try_from(Events::READ | Events::WRITE)
+    );
+    assert_eq!(
+        Err(Events::from_bits_retain(1 << 7)),
+        EventKind::try_from(Events::from_bits_retain(1 << 7))
+    );
+
+    let events = Events::READ | Events::CLOSE | Events::from_bits_retain(1 << 7);
+    let kinds: Vec<_> = events.iter_kinds().map(describe).collect();
+    assert_eq!(vec!["read", "close"], kinds);
+
+    assert_eq!(0, NoFlags::all().iter_kinds().count());
+    let _ = Empty::try_from(NoFlags::empty());
+}
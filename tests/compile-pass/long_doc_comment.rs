@@ -0,0 +1,423 @@
+use bitflags::bitflags;
+
+// Every line of a doc comment is a separate attribute, so this checks that
+// attributes on the flags type don't run into the recursion limit
+bitflags! {
+    /// Line 1 of a long doc comment on the flags type.
+    /// Line 2 of a long doc comment on the flags type.
+    /// Line 3 of a long doc comment on the flags type.
+    /// Line 4 of a long doc comment on the flags type.
+    /// Line 5 of a long doc comment on the flags type.
+    /// Line 6 of a long doc comment on the flags type.
+    /// Line 7 of a long doc comment on the flags type.
+    /// Line 8 of a long doc comment on the flags type.
+    /// Line 9 of a long doc comment on the flags type.
+    /// Line 10 of a long doc comment on the flags type.
+    /// Line 11 of a long doc comment on the flags type.
+    /// Line 12 of a long doc comment on the flags type.
+    /// Line 13 of a long doc comment on the flags type.
+    /// Line 14 of a long doc comment on the flags type.
+    /// Line 15 of a long doc comment on the flags type.
+    /// Line 16 of a long doc comment on the flags type.
+    /// Line 17 of a long doc comment on the flags type.
+    /// Line 18 of a long doc comment on the flags type.
+    /// Line 19 of a long doc comment on the flags type.
+    /// Line 20 of a long doc comment on the flags type.
+    /// Line 21 of a long doc comment on the flags type.
+    /// Line 22 of a long doc comment on the flags type.
+    /// Line 23 of a long doc comment on the flags type.
+    /// Line 24 of a long doc comment on the flags type.
+    /// Line 25 of a long doc comment on the flags type.
+    /// Line 26 of a long doc comment on the flags type.
+    /// Line 27 of a long doc comment on the flags type.
+    /// Line 28 of a long doc comment on the flags type.
+    /// Line 29 of a long doc comment on the flags type.
+    /// Line 30 of a long doc comment on the flags type.
+    /// Line 31 of a long doc comment on the flags type.
+    /// Line 32 of a long doc comment on the flags type.
+    /// Line 33 of a long doc comment on the flags type.
+    /// Line 34 of a long doc comment on the flags type.
+    /// Line 35 of a long doc comment on the flags type.
+    /// Line 36 of a long doc comment on the flags type.
+    /// Line 37 of a long doc comment on the flags type.
+    /// Line 38 of a long doc comment on the flags type.
+    /// Line 39 of a long doc comment on the flags type.
+    /// Line 40 of a long doc comment on the flags type.
+    /// Line 41 of a long doc comment on the flags type.
+    /// Line 42 of a long doc comment on the flags type.
+    /// Line 43 of a long doc comment on the flags type.
+    /// Line 44 of a long doc comment on the flags type.
+    /// Line 45 of a long doc comment on the flags type.
+    /// Line 46 of a long doc comment on the flags type.
+    /// Line 47 of a long doc comment on the flags type.
+    /// Line 48 of a long doc comment on the flags type.
+    /// Line 49 of a long doc comment on the flags type.
+    /// Line 50 of a long doc comment on the flags type.
+    /// Line 51 of a long doc comment on the flags type.
+    /// Line 52 of a long doc comment on the flags type.
+    /// Line 53 of a long doc comment on the flags type.
+    /// Line 54 of a long doc comment on the flags type.
+    /// Line 55 of a long doc comment on the flags type.
+    /// Line 56 of a long doc comment on the flags type.
+    /// Line 57 of a long doc comment on the flags type.
+    /// Line 58 of a long doc comment on the flags type.
+    /// Line 59 of a long doc comment on the flags type.
+    /// Line 60 of a long doc comment on the flags type.
+    /// Line 61 of a long doc comment on the flags type.
+    /// Line 62 of a long doc comment on the flags type.
+    /// Line 63 of a long doc comment on the flags type.
+    /// Line 64 of a long doc comment on the flags type.
+    /// Line 65 of a long doc comment on the flags type.
+    /// Line 66 of a long doc comment on the flags type.
+    /// Line 67 of a long doc comment on the flags type.
+    /// Line 68 of a long doc comment on the flags type.
+    /// Line 69 of a long doc comment on the flags type.
+    /// Line 70 of a long doc comment on the flags type.
+    /// Line 71 of a long doc comment on the flags type.
+    /// Line 72 of a long doc comment on the flags type.
+    /// Line 73 of a long doc comment on the flags type.
+    /// Line 74 of a long doc comment on the flags type.
+    /// Line 75 of a long doc comment on the flags type.
+    /// Line 76 of a long doc comment on the flags type.
+    /// Line 77 of a long doc comment on the flags type.
+    /// Line 78 of a long doc comment on the flags type.
+    /// Line 79 of a long doc comment on the flags type.
+    /// Line 80 of a long doc comment on the flags type.
+    /// Line 81 of a long doc comment on the flags type.
+    /// Line 82 of a long doc comment on the flags type.
+    /// Line 83 of a long doc comment on the flags type.
+    /// Line 84 of a long doc comment on the flags type.
+    /// Line 85 of a long doc comment on the flags type.
+    /// Line 86 of a long doc comment on the flags type.
+    /// Line 87 of a long doc comment on the flags type.
+    /// Line 88 of a long doc comment on the flags type.
+    /// Line 89 of a long doc comment on the flags type.
+    /// Line 90 of a long doc comment on the flags type.
+    /// Line 91 of a long doc comment on the flags type.
+    /// Line 92 of a long doc comment on the flags type.
+    /// Line 93 of a long doc comment on the flags type.
+    /// Line 94 of a long doc comment on the flags type.
+    /// Line 95 of a long doc comment on the flags type.
+    /// Line 96 of a long doc comment on the flags type.
+    /// Line 97 of a long doc comment on the flags type.
+    /// Line 98 of a long doc comment on the flags type.
+    /// Line 99 of a long doc comment on the flags type.
+    /// Line 100 of a long doc comment on the flags type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(display)]
+    /// Line 101 of a long doc comment on the flags type.
+    /// Line 102 of a long doc comment on the flags type.
+    /// Line 103 of a long doc comment on the flags type.
+    /// Line 104 of a long doc comment on the flags type.
+    /// Line 105 of a long doc comment on the flags type.
+    /// Line 106 of a long doc comment on the flags type.
+    /// Line 107 of a long doc comment on the flags type.
+    /// Line 108 of a long doc comment on the flags type.
+    /// Line 109 of a long doc comment on the flags type.
+    /// Line 110 of a long doc comment on the flags type.
+    /// Line 111 of a long doc comment on the flags type.
+    /// Line 112 of a long doc comment on the flags type.
+    /// Line 113 of a long doc comment on the flags type.
+    /// Line 114 of a long doc comment on the flags type.
+    /// Line 115 of a long doc comment on the flags type.
+    /// Line 116 of a long doc comment on the flags type.
+    /// Line 117 of a long doc comment on the flags type.
+    /// Line 118 of a long doc comment on the flags type.
+    /// Line 119 of a long doc comment on the flags type.
+    /// Line 120 of a long doc comment on the flags type.
+    /// Line 121 of a long doc comment on the flags type.
+    /// Line 122 of a long doc comment on the flags type.
+    /// Line 123 of a long doc comment on the flags type.
+    /// Line 124 of a long doc comment on the flags type.
+    /// Line 125 of a long doc comment on the flags type.
+    /// Line 126 of a long doc comment on the flags type.
+    /// Line 127 of a long doc comment on the flags type.
+    /// Line 128 of a long doc comment on the flags type.
+    /// Line 129 of a long doc comment on the flags type.
+    /// Line 130 of a long doc comment on the flags type.
+    /// Line 131 of a long doc comment on the flags type.
+    /// Line 132 of a long doc comment on the flags type.
+    /// Line 133 of a long doc comment on the flags type.
+    /// Line 134 of a long doc comment on the flags type.
+    /// Line 135 of a long doc comment on the flags type.
+    /// Line 136 of a long doc comment on the flags type.
+    /// Line 137 of a long doc comment on the flags type.
+    /// Line 138 of a long doc comment on the flags type.
+    /// Line 139 of a long doc comment on the flags type.
+    /// Line 140 of a long doc comment on the flags type.
+    /// Line 141 of a long doc comment on the flags type.
+    /// Line 142 of a long doc comment on the flags type.
+    /// Line 143 of a long doc comment on the flags type.
+    /// Line 144 of a long doc comment on the flags type.
+    /// Line 145 of a long doc comment on the flags type.
+    /// Line 146 of a long doc comment on the flags type.
+    /// Line 147 of a long doc comment on the flags type.
+    /// Line 148 of a long doc comment on the flags type.
+    /// Line 149 of a long doc comment on the flags type.
+    /// Line 150 of a long doc comment on the flags type.
+    /// Line 151 of a long doc comment on the flags type.
+    /// Line 152 of a long doc comment on the flags type.
+    /// Line 153 of a long doc comment on the flags type.
+    /// Line 154 of a long doc comment on the flags type.
+    /// Line 155 of a long doc comment on the flags type.
+    /// Line 156 of a long doc comment on the flags type.
+    /// Line 157 of a long doc comment on the flags type.
+    /// Line 158 of a long doc comment on the flags type.
+    /// Line 159 of a long doc comment on the flags type.
+    /// Line 160 of a long doc comment on the flags type.
+    /// Line 161 of a long doc comment on the flags type.
+    /// Line 162 of a long doc comment on the flags type.
+    /// Line 163 of a long doc comment on the flags type.
+    /// Line 164 of a long doc comment on the flags type.
+    /// Line 165 of a long doc comment on the flags type.
+    /// Line 166 of a long doc comment on the flags type.
+    /// Line 167 of a long doc comment on the flags type.
+    /// Line 168 of a long doc comment on the flags type.
+    /// Line 169 of a long doc comment on the flags type.
+    /// Line 170 of a long doc comment on the flags type.
+    /// Line 171 of a long doc comment on the flags type.
+    /// Line 172 of a long doc comment on the flags type.
+    /// Line 173 of a long doc comment on the flags type.
+    /// Line 174 of a long doc comment on the flags type.
+    /// Line 175 of a long doc comment on the flags type.
+    /// Line 176 of a long doc comment on the flags type.
+    /// Line 177 of a long doc comment on the flags type.
+    /// Line 178 of a long doc comment on the flags type.
+    /// Line 179 of a long doc comment on the flags type.
+    /// Line 180 of a long doc comment on the flags type.
+    /// Line 181 of a long doc comment on the flags type.
+    /// Line 182 of a long doc comment on the flags type.
+    /// Line 183 of a long doc comment on the flags type.
+    /// Line 184 of a long doc comment on the flags type.
+    /// Line 185 of a long doc comment on the flags type.
+    /// Line 186 of a long doc comment on the flags type.
+    /// Line 187 of a long doc comment on the flags type.
+    /// Line 188 of a long doc comment on the flags type.
+    /// Line 189 of a long doc comment on the flags type.
+    /// Line 190 of a long doc comment on the flags type.
+    /// Line 191 of a long doc comment on the flags type.
+    /// Line 192 of a long doc comment on the flags type.
+    /// Line 193 of a long doc comment on the flags type.
+    /// Line 194 of a long doc comment on the flags type.
+    /// Line 195 of a long doc comment on the flags type.
+    /// Line 196 of a long doc comment on the flags type.
+    /// Line 197 of a long doc comment on the flags type.
+    /// Line 198 of a long doc comment on the flags type.
+    /// Line 199 of a long doc comment on the flags type.
+    /// Line 200 of a long doc comment on the flags type.
+    #[bitflags(from_str)]
+    pub struct Flags: u32 {
+        // Attributes on each flag don't run into the recursion limit either
+        /// Line 1 of a long doc comment on a flag.
+        /// Line 2 of a long doc comment on a flag.
+        /// Line 3 of a long doc comment on a flag.
+        /// Line 4 of a long doc comment on a flag.
+        /// Line 5 of a long doc comment on a flag.
+        /// Line 6 of a long doc comment on a flag.
+        /// Line 7 of a long doc comment on a flag.
+        /// Line 8 of a long doc comment on a flag.
+        /// Line 9 of a long doc comment on a flag.
+        /// Line 10 of a long doc comment on a flag.
+        /// Line 11 of a long doc comment on a flag.
+        /// Line 12 of a long doc comment on a flag.
+        /// Line 13 of a long doc comment on a flag.
+        /// Line 14 of a long doc comment on a flag.
+        /// Line 15 of a long doc comment on a flag.
+        /// Line 16 of a long doc comment on a flag.
+        /// Line 17 of a long doc comment on a flag.
+        /// Line 18 of a long doc comment on a flag.
+        /// Line 19 of a long doc comment on a flag.
+        /// Line 20 of a long doc comment on a flag.
+        /// Line 21 of a long doc comment on a flag.
+        /// Line 22 of a long doc comment on a flag.
+        /// Line 23 of a long doc comment on a flag.
+        /// Line 24 of a long doc comment on a flag.
+        /// Line 25 of a long doc comment on a flag.
+        /// Line 26 of a long doc comment on a flag.
+        /// Line 27 of a long doc comment on a flag.
+        /// Line 28 of a long doc comment on a flag.
+        /// Line 29 of a long doc comment on a flag.
+        /// Line 30 of a long doc comment on a flag.
+        /// Line 31 of a long doc comment on a flag.
+        /// Line 32 of a long doc comment on a flag.
+        /// Line 33 of a long doc comment on a flag.
+        /// Line 34 of a long doc comment on a flag.
+        /// Line 35 of a long doc comment on a flag.
+        /// Line 36 of a long doc comment on a flag.
+        /// Line 37 of a long doc comment on a flag.
+        /// Line 38 of a long doc comment on a flag.
+        /// Line 39 of a long doc comment on a flag.
+        /// Line 40 of a long doc comment on a flag.
+        /// Line 41 of a long doc comment on a flag.
+        /// Line 42 of a long doc comment on a flag.
+        /// Line 43 of a long doc comment on a flag.
+        /// Line 44 of a long doc comment on a flag.
+        /// Line 45 of a long doc comment on a flag.
+        /// Line 46 of a long doc comment on a flag.
+        /// Line 47 of a long doc comment on a flag.
+        /// Line 48 of a long doc comment on a flag.
+        /// Line 49 of a long doc comment on a flag.
+        /// Line 50 of a long doc comment on a flag.
+        /// Line 51 of a long doc comment on a flag.
+        /// Line 52 of a long doc comment on a flag.
+        /// Line 53 of a long doc comment on a flag.
+        /// Line 54 of a long doc comment on a flag.
+        /// Line 55 of a long doc comment on a flag.
+        /// Line 56 of a long doc comment on a flag.
+        /// Line 57 of a long doc comment on a flag.
+        /// Line 58 of a long doc comment on a flag.
+        /// Line 59 of a long doc comment on a flag.
+        /// Line 60 of a long doc comment on a flag.
+        /// Line 61 of a long doc comment on a flag.
+        /// Line 62 of a long doc comment on a flag.
+        /// Line 63 of a long doc comment on a flag.
+        /// Line 64 of a long doc comment on a flag.
+        /// Line 65 of a long doc comment on a flag.
+        /// Line 66 of a long doc comment on a flag.
+        /// Line 67 of a long doc comment on a flag.
+        /// Line 68 of a long doc comment on a flag.
+        /// Line 69 of a long doc comment on a flag.
+        /// Line 70 of a long doc comment on a flag.
+        /// Line 71 of a long doc comment on a flag.
+        /// Line 72 of a long doc comment on a flag.
+        /// Line 73 of a long doc comment on a flag.
+        /// Line 74 of a long doc comment on a flag.
+        /// Line 75 of a long doc comment on a flag.
+        /// Line 76 of a long doc comment on a flag.
+        /// Line 77 of a long doc comment on a flag.
+        /// Line 78 of a long doc comment on a flag.
+        /// Line 79 of a long doc comment on a flag.
+        /// Line 80 of a long doc comment on a flag.
+        /// Line 81 of a long doc comment on a flag.
+        /// Line 82 of a long doc comment on a flag.
+        /// Line 83 of a long doc comment on a flag.
+        /// Line 84 of a long doc comment on a flag.
+        /// Line 85 of a long doc comment on a flag.
+        /// Line 86 of a long doc comment on a flag.
+        /// Line 87 of a long doc comment on a flag.
+        /// Line 88 of a long doc comment on a flag.
+        /// Line 89 of a long doc comment on a flag.
+        /// Line 90 of a long doc comment on a flag.
+        /// Line 91 of a long doc comment on a flag.
+        /// Line 92 of a long doc comment on a flag.
+        /// Line 93 of a long doc comment on a flag.
+        /// Line 94 of a long doc comment on a flag.
+        /// Line 95 of a long doc comment on a flag.
+        /// Line 96 of a long doc comment on a flag.
+        /// Line 97 of a long doc comment on a flag.
+        /// Line 98 of a long doc comment on a flag.
+        /// Line 99 of a long doc comment on a flag.
+        /// Line 100 of a long doc comment on a flag.
+        #[bitflags(alias = "FIRST")]
+        /// Line 101 of a long doc comment on a flag.
+        /// Line 102 of a long doc comment on a flag.
+        /// Line 103 of a long doc comment on a flag.
+        /// Line 104 of a long doc comment on a flag.
+        /// Line 105 of a long doc comment on a flag.
+        /// Line 106 of a long doc comment on a flag.
+        /// Line 107 of a long doc comment on a flag.
+        /// Line 108 of a long doc comment on a flag.
+        /// Line 109 of a long doc comment on a flag.
+        /// Line 110 of a long doc comment on a flag.
+        /// Line 111 of a long doc comment on a flag.
+        /// Line 112 of a long doc comment on a flag.
+        /// Line 113 of a long doc comment on a flag.
+        /// Line 114 of a long doc comment on a flag.
+        /// Line 115 of a long doc comment on a flag.
+        /// Line 116 of a long doc comment on a flag.
+        /// Line 117 of a long doc comment on a flag.
+        /// Line 118 of a long doc comment on a flag.
+        /// Line 119 of a long doc comment on a flag.
+        /// Line 120 of a long doc comment on a flag.
+        /// Line 121 of a long doc comment on a flag.
+        /// Line 122 of a long doc comment on a flag.
+        /// Line 123 of a long doc comment on a flag.
+        /// Line 124 of a long doc comment on a flag.
+        /// Line 125 of a long doc comment on a flag.
+        /// Line 126 of a long doc comment on a flag.
+        /// Line 127 of a long doc comment on a flag.
+        /// Line 128 of a long doc comment on a flag.
+        /// Line 129 of a long doc comment on a flag.
+        /// Line 130 of a long doc comment on a flag.
+        /// Line 131 of a long doc comment on a flag.
+        /// Line 132 of a long doc comment on a flag.
+        /// Line 133 of a long doc comment on a flag.
+        /// Line 134 of a long doc comment on a flag.
+        /// Line 135 of a long doc comment on a flag.
+        /// Line 136 of a long doc comment on a flag.
+        /// Line 137 of a long doc comment on a flag.
+        /// Line 138 of a long doc comment on a flag.
+        /// Line 139 of a long doc comment on a flag.
+        /// Line 140 of a long doc comment on a flag.
+        /// Line 141 of a long doc comment on a flag.
+        /// Line 142 of a long doc comment on a flag.
+        /// Line 143 of a long doc comment on a flag.
+        /// Line 144 of a long doc comment on a flag.
+        /// Line 145 of a long doc comment on a flag.
+        /// Line 146 of a long doc comment on a flag.
+        /// Line 147 of a long doc comment on a flag.
+        /// Line 148 of a long doc comment on a flag.
+        /// Line 149 of a long doc comment on a flag.
+        /// Line 150 of a long doc comment on a flag.
+        /// Line 151 of a long doc comment on a flag.
+        /// Line 152 of a long doc comment on a flag.
+        /// Line 153 of a long doc comment on a flag.
+        /// Line 154 of a long doc comment on a flag.
+        /// Line 155 of a long doc comment on a flag.
+        /// Line 156 of a long doc comment on a flag.
+        /// Line 157 of a long doc comment on a flag.
+        /// Line 158 of a long doc comment on a flag.
+        /// Line 159 of a long doc comment on a flag.
+        /// Line 160 of a long doc comment on a flag.
+        /// Line 161 of a long doc comment on a flag.
+        /// Line 162 of a long doc comment on a flag.
+        /// Line 163 of a long doc comment on a flag.
+        /// Line 164 of a long doc comment on a flag.
+        /// Line 165 of a long doc comment on a flag.
+        /// Line 166 of a long doc comment on a flag.
+        /// Line 167 of a long doc comment on a flag.
+        /// Line 168 of a long doc comment on a flag.
+        /// Line 169 of a long doc comment on a flag.
+        /// Line 170 of a long doc comment on a flag.
+        /// Line 171 of a long doc comment on a flag.
+        /// Line 172 of a long doc comment on a flag.
+        /// Line 173 of a long doc comment on a flag.
+        /// Line 174 of a long doc comment on a flag.
+        /// Line 175 of a long doc comment on a flag.
+        /// Line 176 of a long doc comment on a flag.
+        /// Line 177 of a long doc comment on a flag.
+        /// Line 178 of a long doc comment on a flag.
+        /// Line 179 of a long doc comment on a flag.
+        /// Line 180 of a long doc comment on a flag.
+        /// Line 181 of a long doc comment on a flag.
+        /// Line 182 of a long doc comment on a flag.
+        /// Line 183 of a long doc comment on a flag.
+        /// Line 184 of a long doc comment on a flag.
+        /// Line 185 of a long doc comment on a flag.
+        /// Line 186 of a long doc comment on a flag.
+        /// Line 187 of a long doc comment on a flag.
+        /// Line 188 of a long doc comment on a flag.
+        /// Line 189 of a long doc comment on a flag.
+        /// Line 190 of a long doc comment on a flag.
+        /// Line 191 of a long doc comment on a flag.
+        /// Line 192 of a long doc comment on a flag.
+        /// Line 193 of a long doc comment on a flag.
+        /// Line 194 of a long doc comment on a flag.
+        /// Line 195 of a long doc comment on a flag.
+        /// Line 196 of a long doc comment on a flag.
+        /// Line 197 of a long doc comment on a flag.
+        /// Line 198 of a long doc comment on a flag.
+        /// Line 199 of a long doc comment on a flag.
+        /// Line 200 of a long doc comment on a flag.
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    let flags = Flags::A | Flags::B;
+
+    assert_eq!("A | B", flags.to_string());
+    assert_eq!(flags, "A | B".parse().unwrap());
+    assert_eq!(flags, "FIRST | B".parse().unwrap());
+}
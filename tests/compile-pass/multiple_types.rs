@@ -0,0 +1,35 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Caps: u32, WireCaps: u8 {
+        const A = 0b00000001;
+        const B = 0b00000010;
+        const C;
+        const AB = Self::A.bits() | Self::B.bits();
+    }
+
+    pub struct Other: u16 {
+        const A = 1;
+    }
+}
+
+bitflags! {
+    struct Trailing: i64, TrailingNarrow: i8, {
+        const A = 1;
+    }
+}
+
+fn main() {
+    assert_eq!(0b111u32, Caps::all().bits());
+    assert_eq!(0b111u8, WireCaps::all().bits());
+    assert_eq!("Caps(A | C)", format!("{:?}", Caps::A | Caps::C));
+    assert_eq!("WireCaps(A | C)", format!("{:?}", WireCaps::A | WireCaps::C));
+
+    let wire = WireCaps::AB;
+    assert_eq!(Caps::AB, Caps::from_bits_retain(wire.bits().into()));
+
+    assert_eq!(1, Other::A.bits());
+    assert_eq!(1, Trailing::A.bits());
+    assert_eq!(1, TrailingNarrow::A.bits());
+}
@@ -0,0 +1,74 @@
+#![deny(warnings)]
+
+use std::{fmt, ops};
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    #[bitflags(no_ops, from_str)]
+    pub struct Access: u8 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const ADMIN = 1 << 7;
+    }
+}
+
+// `Debug` isn't derived, so it can redact flags
+impl fmt::Debug for Access {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let visible = self.difference(Access::ADMIN);
+
+        f.write_str("Access(")?;
+        bitflags::parser::to_writer(&visible, &mut *f)?;
+        f.write_str(")")
+    }
+}
+
+// `no_ops` means the operators can be implemented manually
+impl ops::BitOr for Access {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl ops::Not for Access {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Access::READ
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    #[bitflags(no_ops)]
+    impl Manual: u8 {
+        const A = 1;
+    }
+}
+
+impl ops::BitOr for Manual {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+fn main() {
+    let access = Access::READ | Access::ADMIN;
+
+    assert_eq!("Access(READ)", format!("{:?}", access));
+    assert_eq!(Access::READ, !access);
+
+    assert!(access.contains(Access::ADMIN));
+    assert_eq!(2, access.iter().count());
+    assert!(access == "READ | ADMIN".parse().unwrap());
+    assert_eq!(Access::all(), Access::READ.union(Access::WRITE).union(Access::ADMIN));
+
+    assert_eq!(1, (Manual::A | Manual::A).bits());
+}
@@ -0,0 +1,59 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Base: u8 {
+        const ACK = 1;
+        const SYN = 1 << 1;
+    }
+
+    // The base flags are declared once and imported by each flags type that includes them
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(deny_overlap)]
+    pub struct Connect: u8 {
+        use Base;
+        const KEEP_ALIVE = 1 << 2;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(display, from_str, inherent_methods)]
+    pub struct Data: u8 {
+        use Base;
+        const COMPRESSED;
+        const ENCRYPTED;
+    }
+}
+
+pub struct Manual(u8);
+
+bitflags! {
+    impl Manual: u8 {
+        use Base;
+        const CLOSE;
+    }
+}
+
+fn main() {
+    let ack = Data::from_bits_retain(Base::ACK.bits());
+    let syn = Data::from_bits_retain(Base::SYN.bits());
+
+    // Flags are numbered after the imported bits
+    assert_eq!(1 << 2, Data::COMPRESSED.bits());
+    assert_eq!(1 << 3, Data::ENCRYPTED.bits());
+    assert_eq!(1 << 2, Manual::CLOSE.bits());
+
+    assert_eq!(syn | Data::ENCRYPTED, "SYN | ENCRYPTED".parse().unwrap());
+    assert_eq!(syn, bitflags::parser::from_str_ignore_case::<Data>("syn").unwrap());
+    assert_eq!("COMPRESSED | ACK", (ack | Data::COMPRESSED).to_string());
+    assert_eq!(
+        "Connect(KEEP_ALIVE | SYN)",
+        format!("{:?}", Connect::KEEP_ALIVE | Connect::from_bits_retain(Base::SYN.bits()))
+    );
+
+    assert_eq!(Some(syn), Data::from_name("SYN"));
+    assert_eq!(0b1111, Data::all().bits());
+    assert_eq!(0b1111, Data::all_named().bits());
+    assert_eq!(0b0111, Manual::all().bits());
+}
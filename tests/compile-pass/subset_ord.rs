@@ -0,0 +1,59 @@
+#![deny(warnings)]
+
+use core::cmp::Ordering;
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(subset_ord)]
+    pub struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const EXEC = 1 << 2;
+    }
+}
+
+pub struct Manual(u8);
+
+impl PartialEq for Manual {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+bitflags! {
+    #[bitflags(subset_ord)]
+    impl Manual: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    let (read, write) = (Permissions::READ, Permissions::WRITE);
+
+    assert_eq!(Some(Ordering::Equal), read.partial_cmp(&read));
+    assert_eq!(Some(Ordering::Less), read.partial_cmp(&(read | write)));
+    assert_eq!(Some(Ordering::Greater), (read | write).partial_cmp(&read));
+    assert!(read < read | write);
+    assert!(read <= read);
+    assert!(Permissions::all() >= read | write);
+
+    // The empty value is a subset of everything
+    assert!(Permissions::empty() < read);
+
+    // Values that aren't subsets of each other are incomparable
+    assert_eq!(None, read.partial_cmp(&write));
+    assert_eq!(None, (read | write).partial_cmp(&Permissions::EXEC));
+    assert!(!(read < write) && !(read > write) && !(read <= write) && !(read >= write));
+
+    // Unknown bits are compared like any other bits
+    let unknown = Permissions::from_bits_retain(1 << 7);
+    assert!(read < read | unknown);
+    assert_eq!(None, Permissions::all().partial_cmp(&unknown));
+    assert!(Permissions::all() < Permissions::all() | unknown);
+
+    assert!(Manual::A < Manual::A | Manual::B);
+    assert_eq!(None, Manual::A.partial_cmp(&Manual::B));
+}
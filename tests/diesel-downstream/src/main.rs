@@ -0,0 +1,110 @@
+//! A crate storing flags types in SQLite through `diesel` with the `diesel` feature enabled
+
+use bitflags::bitflags;
+use diesel::{
+    deserialize::FromSqlRow, expression::AsExpression, prelude::*, sql_types::*,
+    sqlite::SqliteConnection,
+};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, AsExpression, FromSqlRow)]
+    #[diesel(sql_type = Integer)]
+    struct Color: i32 {
+        const RED = 0x1;
+        const GREEN = 0x2;
+        const BLUE = 0x4;
+        const ALPHA = i32::MIN;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, AsExpression, FromSqlRow)]
+    #[diesel(sql_type = BigInt)]
+    struct Wide: i64 {
+        const A = 0x1;
+        const B = i64::MIN;
+    }
+}
+
+diesel::table! {
+    flags (id) {
+        id -> Integer,
+        color -> Integer,
+        wide -> BigInt,
+        maybe -> Nullable<Integer>,
+    }
+}
+
+fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+    diesel::sql_query(
+        "CREATE TABLE flags (id INTEGER PRIMARY KEY NOT NULL, color INTEGER NOT NULL, wide BIGINT NOT NULL, maybe INTEGER)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+
+    conn
+}
+
+fn roundtrip() {
+    let mut conn = connect();
+
+    let values = [
+        (Color::empty(), Wide::empty(), None),
+        (Color::RED | Color::BLUE, Wide::A, Some(Color::GREEN)),
+        (Color::all(), Wide::all(), Some(Color::all())),
+        (
+            Color::from_bits_retain(0xf0),
+            Wide::from_bits_retain(0xf0),
+            Some(Color::from_bits_retain(0xf0)),
+        ),
+    ];
+
+    for (id, (color, wide, maybe)) in values.iter().enumerate() {
+        diesel::insert_into(flags::table)
+            .values((
+                flags::id.eq(id as i32),
+                flags::color.eq(color),
+                flags::wide.eq(wide),
+                flags::maybe.eq(maybe),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+
+        let decoded: (Color, Wide, Option<Color>) = flags::table
+            .filter(flags::id.eq(id as i32))
+            .select((flags::color, flags::wide, flags::maybe))
+            .first(&mut conn)
+            .unwrap();
+
+        assert_eq!((color, wide, maybe), (&decoded.0, &decoded.1, &decoded.2));
+
+        let raw: (i32, i64) = flags::table
+            .filter(flags::id.eq(id as i32))
+            .select((flags::color, flags::wide))
+            .first(&mut conn)
+            .unwrap();
+
+        assert_eq!((color.bits(), wide.bits()), raw);
+    }
+}
+
+fn null() {
+    let mut conn = connect();
+
+    diesel::sql_query("INSERT INTO flags (id, color, wide, maybe) VALUES (0, 1, 1, NULL)")
+        .execute(&mut conn)
+        .unwrap();
+
+    let result = flags::table
+        .select(flags::maybe.assume_not_null())
+        .first::<Color>(&mut conn);
+
+    assert!(result.is_err());
+}
+
+fn main() {
+    roundtrip();
+    null();
+}
@@ -0,0 +1,102 @@
+//! A crate converting flags types to and from Python objects with the `pyo3` feature enabled
+
+use bitflags::bitflags;
+use pyo3::{
+    exceptions::{PyOverflowError, PyTypeError, PyValueError},
+    prelude::*,
+    types::PyString,
+};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Color: u32 {
+        const RED = 0x1;
+        const GREEN = 0x2;
+        const BLUE = 0x4;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Wide: u128 {
+        const A = 0x1;
+        const B = 1 << 127;
+    }
+}
+
+fn roundtrip(py: Python<'_>) {
+    for color in [
+        Color::empty(),
+        Color::RED | Color::BLUE,
+        Color::all(),
+        Color::from_bits_retain(0xf0),
+    ] {
+        let bits = color.bits();
+        let ob = color.into_pyobject(py).unwrap();
+
+        assert_eq!(bits, ob.extract::<u32>().unwrap());
+        assert_eq!(
+            Color::from_bits_retain(bits),
+            ob.extract::<Color>().unwrap()
+        );
+    }
+
+    let ob = Wide::all().into_pyobject(py).unwrap();
+
+    assert_eq!(Wide::all().bits(), ob.extract::<u128>().unwrap());
+    assert_eq!(Wide::all(), ob.extract::<Wide>().unwrap());
+}
+
+fn from_str(py: Python<'_>) {
+    let ob = PyString::new(py, "RED | BLUE");
+
+    assert_eq!(Color::RED | Color::BLUE, ob.extract::<Color>().unwrap());
+
+    let ob = bitflags::pyo3::to_py_string(py, &(Color::GREEN | Color::from_bits_retain(0xf0)));
+
+    assert_eq!("GREEN | 0xf0", ob.to_str().unwrap());
+    assert_eq!(
+        Color::GREEN | Color::from_bits_retain(0xf0),
+        ob.extract::<Color>().unwrap()
+    );
+}
+
+fn invalid(py: Python<'_>) {
+    let err = PyString::new(py, "RED | PURPLE")
+        .extract::<Color>()
+        .unwrap_err();
+
+    assert!(err.is_instance_of::<PyValueError>(py));
+    assert_eq!(
+        "ValueError: unrecognized named flag `PURPLE`, expected one of: RED, GREEN, BLUE",
+        err.to_string()
+    );
+
+    let err = PyString::new(py, "RED |").extract::<Color>().unwrap_err();
+
+    assert!(err.is_instance_of::<PyValueError>(py));
+
+    let err = (-1i32)
+        .into_pyobject(py)
+        .unwrap()
+        .extract::<Color>()
+        .unwrap_err();
+
+    assert!(err.is_instance_of::<PyOverflowError>(py));
+
+    let err = 1.5f64
+        .into_pyobject(py)
+        .unwrap()
+        .extract::<Color>()
+        .unwrap_err();
+
+    assert!(err.is_instance_of::<PyTypeError>(py));
+}
+
+fn main() {
+    Python::with_gil(|py| {
+        roundtrip(py);
+        from_str(py);
+        invalid(py);
+    });
+}
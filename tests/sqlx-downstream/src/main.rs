@@ -0,0 +1,97 @@
+//! A crate storing flags types in SQLite through `sqlx` with the `sqlx` feature enabled
+
+use bitflags::bitflags;
+use sqlx::{sqlite::SqliteConnection, Connection, Sqlite, Type, TypeInfo};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, sqlx::Type)]
+    #[sqlx(transparent)]
+    struct Color: u32 {
+        const RED = 0x1;
+        const GREEN = 0x2;
+        const BLUE = 0x4;
+        const ALPHA = 0x8000_0000;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, sqlx::Type)]
+    #[sqlx(transparent)]
+    struct Wide: i64 {
+        const A = 0x1;
+        const B = i64::MIN;
+    }
+}
+
+fn type_info() {
+    assert_eq!(
+        <u32 as Type<Sqlite>>::type_info().name(),
+        <Color as Type<Sqlite>>::type_info().name()
+    );
+    assert_eq!(
+        <i64 as Type<Sqlite>>::type_info().name(),
+        <Wide as Type<Sqlite>>::type_info().name()
+    );
+}
+
+async fn roundtrip() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query("CREATE TABLE flags (color INTEGER NOT NULL, wide INTEGER NOT NULL)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let values = [
+        (Color::empty(), Wide::empty()),
+        (Color::RED | Color::BLUE, Wide::A),
+        (Color::all(), Wide::all()),
+        (Color::from_bits_retain(0xf0), Wide::from_bits_retain(0xf0)),
+    ];
+
+    for (color, wide) in &values {
+        sqlx::query("DELETE FROM flags")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO flags (color, wide) VALUES (?, ?)")
+            .bind(color)
+            .bind(wide)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let (decoded_color, decoded_wide): (Color, Wide) =
+            sqlx::query_as("SELECT color, wide FROM flags")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+
+        assert_eq!(color, &decoded_color);
+        assert_eq!(wide, &decoded_wide);
+
+        let (raw_color, raw_wide): (i64, i64) = sqlx::query_as("SELECT color, wide FROM flags")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+
+        assert_eq!(color.bits() as i64, raw_color);
+        assert_eq!(wide.bits(), raw_wide);
+    }
+}
+
+async fn out_of_range() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+    let result: Result<Color, _> = sqlx::query_scalar("SELECT -1").fetch_one(&mut conn).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    type_info();
+    roundtrip().await;
+    out_of_range().await;
+}
@@ -0,0 +1,18 @@
+//! A crate using `bitflags!` with the `zerocopy` feature enabled, but without depending on `zerocopy`
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Flags: u32 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    let flags = Flags::A | Flags::B;
+
+    assert_eq!(Flags::from_bits_retain(0b11), flags);
+    println!("{:?}", flags);
+}